@@ -94,6 +94,49 @@ impl BitVec16 {
         self.bits()
     }
 
+    /// Returns the bit pattern zero-extended to a u32
+    ///
+    /// Useful when the 16 bits have to live in a 32-bit field, for alignment
+    /// or when packing several values together.
+    ///
+    /// # Returns
+    /// The u32 value with the bit pattern in its lower 16 bits
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0xFFFF);
+    /// assert_eq!(bitvec.to_u32_pattern(), 0x0000_FFFF);
+    /// ```
+    #[inline]
+    pub const fn to_u32_pattern(&self) -> u32 {
+        self.bits() as u32
+    }
+
+    /// Creates a new BitVec16 from the lower 16 bits of a u32 value
+    ///
+    /// # Arguments
+    /// * `value` - The u32 value to convert to BitVec16
+    ///
+    /// # Returns
+    /// The BitVec16 with the bit pattern of `value`, or `None` if any of the
+    /// upper 16 bits of `value` is set
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// assert_eq!(BitVec16::from_u32_pattern(42), Some(BitVec16::from_u16(42)));
+    /// assert_eq!(BitVec16::from_u32_pattern(0x0001_0000), None);
+    /// ```
+    #[inline]
+    pub const fn from_u32_pattern(value: u32) -> Option<Self> {
+        if value > u16::MAX as u32 {
+            None
+        } else {
+            Some(Self::from_u16(value as u16))
+        }
+    }
+
     /// Returns the value of the bit at the specified index
     ///
     /// This method checks if the bit at the given position is set.
@@ -106,7 +149,7 @@ impl BitVec16 {
     /// `true` if the bit is set, `false` otherwise
     ///
     /// # Panics
-    /// This method will panic if the index is out of bounds (> 15)
+    /// This method panics if index >= 16, use `get_bit` for a checked access
     ///
     /// # Example
     /// ```
@@ -117,9 +160,38 @@ impl BitVec16 {
     /// ```
     #[inline]
     pub const fn bit(&self, index: usize) -> bool {
+        assert!(index < 16, "bit index out of bounds");
         self.bits() & (1 << index) != 0
     }
 
+    /// Returns the value of the bit at the specified index, if any
+    ///
+    /// This is the bounds-checked alternative to `bit`.
+    ///
+    /// # Arguments
+    /// * `index` - The bit position to check
+    ///
+    /// # Returns
+    /// `Some(true)` if the bit is set, `Some(false)` if it is not, and `None`
+    /// if the index is out of bounds (>= 16)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0b0000_0000_0000_0001);
+    /// assert_eq!(bitvec.get_bit(0), Some(true));
+    /// assert_eq!(bitvec.get_bit(1), Some(false));
+    /// assert_eq!(bitvec.get_bit(16), None);
+    /// ```
+    #[inline]
+    pub const fn get_bit(&self, index: usize) -> Option<bool> {
+        if index < 16 {
+            Some(self.bit(index))
+        } else {
+            None
+        }
+    }
+
     /// Returns an iterator over all bits in this BitVec16
     ///
     /// The iterator yields each bit as a boolean value, starting from
@@ -185,6 +257,213 @@ impl BitVec16 {
             .enumerate()
             .filter_map(|(i, b)| if !b { Some(i) } else { None })
     }
+
+    /// Returns an iterator over every window of consecutive bits, wrapping around
+    ///
+    /// The iterator yields 16 windows, one per starting position (0-15). The
+    /// window starting at position `i` holds bits `i..i + size` shifted down to
+    /// the lowest bits of the result. Windows that run past bit 15 wrap around
+    /// to bit 0, so the bit vector is treated as a cycle.
+    ///
+    /// # Arguments
+    /// * `size` - The number of bits in each window (0-16)
+    ///
+    /// # Returns
+    /// An iterator that yields the 16 windows as u16 values
+    ///
+    /// # Panics
+    /// This method will panic if the size is greater than 16
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0b1000_0000_0000_0011);
+    /// let windows: Vec<u16> = bitvec.sliding_window(3).collect();
+    /// assert_eq!(windows[0], 0b011);
+    /// assert_eq!(windows[13], 0b100);
+    /// assert_eq!(windows[15], 0b111);
+    /// ```
+    pub fn sliding_window(&self, size: usize) -> impl Iterator<Item = u16> {
+        assert!(size <= 16, "The window size must be at most 16 bits");
+
+        let bits = self.bits();
+        let mask = (((1u32 << size) - 1) & 0xFFFF) as u16;
+        (0..16).map(move |start| bits.rotate_right(start) & mask)
+    }
+
+    /// Returns the number of bits that are set
+    ///
+    /// # Returns
+    /// The number of set bits (0-16)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0b1000_0000_0000_1101);
+    /// assert_eq!(bitvec.count_ones(), 4);
+    /// ```
+    #[inline]
+    pub const fn count_ones(&self) -> u32 {
+        self.bits().count_ones()
+    }
+
+    /// Returns the parity of the bit vector
+    ///
+    /// # Returns
+    /// `true` if the number of set bits is odd, `false` if it is even
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// assert!(BitVec16::from_u16(0b0000_0000_0000_0111).parity());
+    /// assert!(!BitVec16::from_u16(0b0000_0000_0000_0011).parity());
+    /// ```
+    #[inline]
+    pub const fn parity(&self) -> bool {
+        self.count_ones() % 2 == 1
+    }
+
+    /// Returns the number of unset bits above the highest set bit
+    ///
+    /// # Returns
+    /// The number of leading zeros, 16 if no bit is set
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0b0000_0000_0000_1101);
+    /// assert_eq!(bitvec.leading_zeros(), 12);
+    /// ```
+    #[inline]
+    pub const fn leading_zeros(&self) -> u32 {
+        self.bits().leading_zeros()
+    }
+
+    /// Returns the number of unset bits below the lowest set bit
+    ///
+    /// # Returns
+    /// The number of trailing zeros, 16 if no bit is set
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0b0000_0000_0000_1100);
+    /// assert_eq!(bitvec.trailing_zeros(), 2);
+    /// ```
+    #[inline]
+    pub const fn trailing_zeros(&self) -> u32 {
+        self.bits().trailing_zeros()
+    }
+
+    /// Returns the number of consecutive set bits starting from bit 15
+    ///
+    /// # Returns
+    /// The number of leading ones, 16 if all bits are set
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0b1110_0000_0000_0001);
+    /// assert_eq!(bitvec.count_leading_ones(), 3);
+    /// ```
+    #[inline]
+    pub const fn count_leading_ones(&self) -> u32 {
+        (!self.bits()).leading_zeros()
+    }
+
+    /// Returns the number of consecutive set bits starting from bit 0
+    ///
+    /// # Returns
+    /// The number of trailing ones, 16 if all bits are set
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0b1000_0000_0000_0111);
+    /// assert_eq!(bitvec.count_trailing_ones(), 3);
+    /// ```
+    #[inline]
+    pub const fn count_trailing_ones(&self) -> u32 {
+        (!self.bits()).trailing_zeros()
+    }
+
+    /// Returns the number of runs of consecutive set and unset bits
+    ///
+    /// A run is a maximal sequence of consecutive bits with the same value.
+    /// The bits are scanned from bit 0 to bit 15 and the sequence does not
+    /// wrap around.
+    ///
+    /// # Returns
+    /// A tuple `(ones_runs, zeros_runs)` with the number of runs of set bits
+    /// and the number of runs of unset bits
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0b1000_0000_0000_1101);
+    /// assert_eq!(bitvec.count_runs(), (3, 2));
+    /// assert_eq!(BitVec16::from_u16(0).count_runs(), (0, 1));
+    /// ```
+    pub const fn count_runs(&self) -> (u32, u32) {
+        let mut ones_runs = 0;
+        let mut zeros_runs = 0;
+        let mut index = 0;
+        while index < 16 {
+            let bit = self.bit(index);
+            if index == 0 || bit != self.bit(index - 1) {
+                if bit {
+                    ones_runs += 1;
+                } else {
+                    zeros_runs += 1;
+                }
+            }
+            index += 1;
+        }
+        (ones_runs, zeros_runs)
+    }
+
+    /// Returns the length of the longest run of consecutive set bits
+    ///
+    /// # Returns
+    /// The length of the longest run of ones, 0 if no bit is set
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0b0111_0000_0000_1101);
+    /// assert_eq!(bitvec.max_consecutive_ones(), 3);
+    /// ```
+    pub const fn max_consecutive_ones(&self) -> u32 {
+        Self::longest_run(self.bits())
+    }
+
+    /// Returns the length of the longest run of consecutive unset bits
+    ///
+    /// # Returns
+    /// The length of the longest run of zeros, 0 if all bits are set
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let bitvec = BitVec16::from_u16(0b1000_0000_0000_1101);
+    /// assert_eq!(bitvec.max_consecutive_zeros(), 11);
+    /// ```
+    pub const fn max_consecutive_zeros(&self) -> u32 {
+        Self::longest_run(!self.bits())
+    }
+
+    /// Returns the length of the longest run of set bits in `value`
+    ///
+    /// Each `value & (value << 1)` step shortens every run by one bit, so the
+    /// number of steps until the value is cleared is the longest run.
+    const fn longest_run(mut value: u16) -> u32 {
+        let mut length = 0;
+        while value != 0 {
+            value &= value << 1;
+            length += 1;
+        }
+        length
+    }
 }
 
 /// Builder for constructing BitVec16 instances
@@ -264,6 +543,61 @@ impl BitVec16Builder {
         Self { vec }
     }
 
+    /// Merges another builder into this one with a bitwise OR
+    ///
+    /// The resulting builder has every bit set that is set in either builder.
+    /// This is useful when several sources contribute bits to the same vector.
+    ///
+    /// # Arguments
+    /// * `other` - The builder whose bits are merged in
+    ///
+    /// # Returns
+    /// A new BitVec16Builder with the union of both bit patterns
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16Builder;
+    /// let other = BitVec16Builder::new().set_index(1).set_index(2);
+    /// let bitvec = BitVec16Builder::new()
+    ///     .set_index(0)
+    ///     .set_index(1)
+    ///     .merge_or(&other)
+    ///     .build();
+    /// assert_eq!(bitvec.inner(), 0b0000_0000_0000_0111);
+    /// ```
+    #[inline]
+    pub const fn merge_or(self, other: &BitVec16Builder) -> Self {
+        let vec = self.vec.union(other.vec);
+        Self { vec }
+    }
+
+    /// Merges another builder into this one with a bitwise AND
+    ///
+    /// The resulting builder keeps only the bits that are set in both builders.
+    ///
+    /// # Arguments
+    /// * `other` - The builder whose bits are intersected with
+    ///
+    /// # Returns
+    /// A new BitVec16Builder with the intersection of both bit patterns
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16Builder;
+    /// let other = BitVec16Builder::new().set_index(1).set_index(2);
+    /// let bitvec = BitVec16Builder::new()
+    ///     .set_index(0)
+    ///     .set_index(1)
+    ///     .merge_and(&other)
+    ///     .build();
+    /// assert_eq!(bitvec.inner(), 0b0000_0000_0000_0010);
+    /// ```
+    #[inline]
+    pub const fn merge_and(self, other: &BitVec16Builder) -> Self {
+        let vec = self.vec.intersection(other.vec);
+        Self { vec }
+    }
+
     /// Finalizes the builder and returns the constructed BitVec16
     ///
     /// This method consumes the builder and returns the final BitVec16
@@ -347,6 +681,26 @@ impl Iterator for BitVec16Iter {
 
 impl ExactSizeIterator for BitVec16Iter {}
 
+impl From<[bool; 16]> for BitVec16 {
+    /// Converts an array of 16 booleans to a `BitVec16`
+    ///
+    /// This conversion delegates to [`BitVec16::from_vec`].
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// let mut bits = [false; 16];
+    /// bits[0] = true;
+    /// bits[3] = true;
+    /// let bitvec = BitVec16::from(bits);
+    /// assert_eq!(bitvec.inner(), 0b1001);
+    /// ```
+    #[inline]
+    fn from(bits: [bool; 16]) -> Self {
+        Self::from_vec(bits)
+    }
+}
+
 impl Index<usize> for BitVec16 {
     type Output = bool;
 
@@ -418,6 +772,22 @@ mod tests {
         assert!(bitvec.bit(15));
     }
 
+    #[test]
+    #[should_panic(expected = "bit index out of bounds")]
+    fn test_bit_out_of_bounds() {
+        BitVec16::from_u16(VAL).bit(16);
+    }
+
+    #[test]
+    fn test_get_bit() {
+        let bitvec = BitVec16::from_u16(VAL);
+        assert_eq!(bitvec.get_bit(0), Some(true));
+        assert_eq!(bitvec.get_bit(1), Some(false));
+        assert_eq!(bitvec.get_bit(15), Some(true));
+        assert_eq!(bitvec.get_bit(16), None);
+        assert_eq!(bitvec.get_bit(usize::MAX), None);
+    }
+
     #[test]
     fn test_iter_bits() {
         let bitvec = BitVec16::from_u16(VAL);
@@ -490,4 +860,191 @@ mod tests {
         ]);
         assert_eq!(bitvec.inner(), 0b1000_0000_0000_1101);
     }
+
+    #[test]
+    fn test_from_bool_array() {
+        let mut bits = [false; 16];
+        bits[0] = true;
+        bits[2] = true;
+        bits[3] = true;
+        bits[15] = true;
+        let bitvec: BitVec16 = bits.into();
+        assert_eq!(bitvec.inner(), 0b1000_0000_0000_1101);
+        assert_eq!(bitvec, BitVec16::from_vec(bits));
+    }
+
+    #[test]
+    fn test_leading_trailing_zeros() {
+        let bitvec = BitVec16::from_u16(VAL);
+        assert_eq!(bitvec.leading_zeros(), 0);
+        assert_eq!(bitvec.trailing_zeros(), 0);
+
+        let bitvec = BitVec16::from_u16(0b0000_0001_1000_0000);
+        assert_eq!(bitvec.leading_zeros(), 7);
+        assert_eq!(bitvec.trailing_zeros(), 7);
+
+        let bitvec = BitVec16::from_u16(0);
+        assert_eq!(bitvec.leading_zeros(), 16);
+        assert_eq!(bitvec.trailing_zeros(), 16);
+    }
+
+    #[test]
+    fn test_count_leading_trailing_ones() {
+        let bitvec = BitVec16::from_u16(VAL);
+        assert_eq!(bitvec.count_leading_ones(), 1);
+        assert_eq!(bitvec.count_trailing_ones(), 1);
+
+        let bitvec = BitVec16::from_u16(0b1111_0000_0011_1111);
+        assert_eq!(bitvec.count_leading_ones(), 4);
+        assert_eq!(bitvec.count_trailing_ones(), 6);
+
+        let bitvec = BitVec16::from_u16(u16::MAX);
+        assert_eq!(bitvec.count_leading_ones(), 16);
+        assert_eq!(bitvec.count_trailing_ones(), 16);
+
+        let bitvec = BitVec16::from_u16(0);
+        assert_eq!(bitvec.count_leading_ones(), 0);
+        assert_eq!(bitvec.count_trailing_ones(), 0);
+    }
+
+    #[test]
+    fn test_count_ones() {
+        assert_eq!(BitVec16::from_u16(VAL).count_ones(), 4);
+        assert_eq!(BitVec16::from_u16(0).count_ones(), 0);
+        assert_eq!(BitVec16::from_u16(u16::MAX).count_ones(), 16);
+    }
+
+    #[test]
+    fn test_parity() {
+        assert!(!BitVec16::from_u16(VAL).parity());
+        assert!(!BitVec16::from_u16(0).parity());
+        assert!(BitVec16::from_u16(0b1000_0000_0000_0000).parity());
+        assert!(!BitVec16::from_u16(u16::MAX).parity());
+    }
+
+    #[test]
+    fn test_sliding_window() {
+        let bitvec = BitVec16::from_u16(0b1000_0000_0000_0011);
+        let windows = bitvec.sliding_window(2).collect::<Vec<_>>();
+        assert_eq!(windows.len(), 16);
+        assert_eq!(windows[0], 0b11);
+        assert_eq!(windows[1], 0b01);
+        assert_eq!(windows[14], 0b10);
+        assert_eq!(windows[15], 0b11);
+
+        assert!(bitvec.sliding_window(0).all(|window| window == 0));
+        assert!(BitVec16::from_u16(VAL)
+            .sliding_window(16)
+            .enumerate()
+            .all(|(start, window)| window == VAL.rotate_right(start as u32)));
+    }
+
+    #[test]
+    #[should_panic(expected = "The window size must be at most 16 bits")]
+    fn test_sliding_window_too_large() {
+        let _ = BitVec16::from_u16(VAL).sliding_window(17);
+    }
+
+    #[test]
+    fn test_count_runs() {
+        let bitvec = BitVec16::from_u16(VAL);
+        assert_eq!(bitvec.count_runs(), (3, 2));
+
+        assert_eq!(BitVec16::from_u16(0).count_runs(), (0, 1));
+        assert_eq!(BitVec16::from_u16(u16::MAX).count_runs(), (1, 0));
+        assert_eq!(BitVec16::from_u16(0x5555).count_runs(), (8, 8));
+        assert_eq!(
+            BitVec16::from_u16(0b0000_1101_0101_1010).count_runs(),
+            (5, 6)
+        );
+    }
+
+    #[test]
+    fn test_max_consecutive_ones() {
+        assert_eq!(BitVec16::from_u16(VAL).max_consecutive_ones(), 2);
+        assert_eq!(BitVec16::from_u16(0).max_consecutive_ones(), 0);
+        assert_eq!(BitVec16::from_u16(u16::MAX).max_consecutive_ones(), 16);
+        assert_eq!(BitVec16::from_u16(0x5555).max_consecutive_ones(), 1);
+        assert_eq!(
+            BitVec16::from_u16(0b1111_0000_0000_0111).max_consecutive_ones(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_max_consecutive_zeros() {
+        assert_eq!(BitVec16::from_u16(VAL).max_consecutive_zeros(), 11);
+        assert_eq!(BitVec16::from_u16(0).max_consecutive_zeros(), 16);
+        assert_eq!(BitVec16::from_u16(u16::MAX).max_consecutive_zeros(), 0);
+        assert_eq!(BitVec16::from_u16(0x5555).max_consecutive_zeros(), 1);
+        assert_eq!(
+            BitVec16::from_u16(0b0000_0001_1000_0000).max_consecutive_zeros(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = BitVec16::from_u16(0b0000_0000_0000_1100);
+        let b = BitVec16::from_u16(0b0000_0000_0000_1010);
+        assert_eq!(a.union(b).inner(), 0b0000_0000_0000_1110);
+        assert_eq!(a.intersection(b).inner(), 0b0000_0000_0000_1000);
+        assert_eq!(a.difference(b).inner(), 0b0000_0000_0000_0100);
+        assert_eq!(b.difference(a).inner(), 0b0000_0000_0000_0010);
+    }
+
+    #[test]
+    fn test_builder_merge_or() {
+        let other = BitVec16Builder::new().set_index(3).set_index(15);
+        let bitvec = BitVec16Builder::new()
+            .set_index(0)
+            .set_index(3)
+            .merge_or(&other)
+            .build();
+        assert_eq!(bitvec.inner(), 0b1000_0000_0000_1001);
+
+        let empty = BitVec16Builder::new();
+        let bitvec = BitVec16Builder::new().set_index(4).merge_or(&empty).build();
+        assert_eq!(bitvec.inner(), 0b0000_0000_0001_0000);
+    }
+
+    #[test]
+    fn test_builder_merge_and() {
+        let other = BitVec16Builder::new().set_index(3).set_index(15);
+        let bitvec = BitVec16Builder::new()
+            .set_index(0)
+            .set_index(3)
+            .merge_and(&other)
+            .build();
+        assert_eq!(bitvec.inner(), 0b0000_0000_0000_1000);
+
+        const MERGED: BitVec16 = BitVec16Builder::new()
+            .set_index(1)
+            .merge_and(&BitVec16Builder::new().set_index(2))
+            .build();
+        assert_eq!(MERGED.inner(), 0);
+    }
+
+    #[test]
+    fn test_u32_pattern() {
+        for value in [0u16, 1, 42, 0b1010_1010_1010_1010, 0x8000, 0xFFFF] {
+            let bitvec = BitVec16::from_u16(value);
+            assert_eq!(bitvec.to_u32_pattern(), value as u32);
+            assert_eq!(
+                BitVec16::from_u32_pattern(bitvec.to_u32_pattern()),
+                Some(bitvec)
+            );
+        }
+
+        assert_eq!(
+            BitVec16::from_u32_pattern(0x0000_FFFF),
+            Some(BitVec16::from_u16(0xFFFF))
+        );
+        assert_eq!(BitVec16::from_u32_pattern(0x0001_0000), None);
+        assert_eq!(BitVec16::from_u32_pattern(0x8000_0001), None);
+        assert_eq!(BitVec16::from_u32_pattern(u32::MAX), None);
+
+        const PATTERN: Option<BitVec16> = BitVec16::from_u32_pattern(0b101);
+        assert_eq!(PATTERN, Some(BitVec16::from_u16(0b101)));
+    }
 }
@@ -7,8 +7,10 @@
 pub mod bitvec16;
 pub mod u4vec16;
 pub mod u4x2;
+pub mod u4x2seq16;
 
 // Re-export the main types for convenience
 pub use bitvec16::{BitVec16, BitVec16Builder};
 pub use u4vec16::U4Vec16;
 pub use u4x2::U4x2;
+pub use u4x2seq16::U4x2Seq16;
@@ -120,7 +120,7 @@ impl U4Vec16 {
     /// The 4-bit value (0-15) at the specified position
     ///
     /// # Panics
-    /// This method will panic if the index is out of bounds (> 15)
+    /// This method panics if index >= 16, use `get` for a checked access
     ///
     /// # Example
     /// ```
@@ -131,10 +131,38 @@ impl U4Vec16 {
     /// ```
     #[inline]
     pub const fn item(&self, index: usize) -> u8 {
+        assert!(index < 16, "item index out of bounds");
         let val = self.bits() >> (Self::ITEM_SIZE * index);
         (val & Self::ITEM_MASK) as u8
     }
 
+    /// Returns the 4-bit item at the specified index, if any
+    ///
+    /// This is the bounds-checked alternative to `item`.
+    ///
+    /// # Arguments
+    /// * `index` - The item position to access
+    ///
+    /// # Returns
+    /// `Some` with the 4-bit value (0-15) at the specified position, or `None`
+    /// if the index is out of bounds (>= 16)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// let vec = U4Vec16::from_u64(0x1234567890ABCDEF);
+    /// assert_eq!(vec.get(0), Some(0x0F));
+    /// assert_eq!(vec.get(16), None);
+    /// ```
+    #[inline]
+    pub const fn get(&self, index: usize) -> Option<u8> {
+        if index < 16 {
+            Some(self.item(index))
+        } else {
+            None
+        }
+    }
+
     /// Resets the 4-bit item at the specified index
     ///
     /// This method resets the 4-bit item at the specified index to 0.
@@ -201,6 +229,232 @@ impl U4Vec16 {
     pub fn iter_items(&self) -> U4Vec16Iter {
         U4Vec16Iter::new(*self)
     }
+
+    /// Counts the items equal to a given value
+    ///
+    /// # Arguments
+    /// * `value` - The 4-bit value to look for (0-15)
+    ///
+    /// # Returns
+    /// The number of items equal to `value` (0-16)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// let vec = U4Vec16::from_u64(0x0000_0000_0002_0102);
+    /// assert_eq!(vec.count_value(2), 2);
+    /// assert_eq!(vec.count_value(0), 13);
+    /// ```
+    #[inline]
+    pub const fn count_value(&self, value: u8) -> usize {
+        let mut count = 0;
+        let mut index = 0;
+        while index < Self::CAPACITY {
+            if self.item(index) == value {
+                count += 1;
+            }
+            index += 1;
+        }
+        count
+    }
+
+    /// Returns an iterator over the positions of the items equal to a given value
+    ///
+    /// # Arguments
+    /// * `value` - The 4-bit value to look for (0-15)
+    ///
+    /// # Returns
+    /// An iterator that yields the indices (0-15) of the matching items in
+    /// ascending order
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// let vec = U4Vec16::from_u64(0x0000_0000_0002_0102);
+    /// assert_eq!(vec.positions_of(2).collect::<Vec<_>>(), vec![0, 4]);
+    /// ```
+    #[inline]
+    pub fn positions_of(&self, value: u8) -> impl Iterator<Item = usize> {
+        self.iter_items()
+            .enumerate()
+            .filter_map(move |(index, item)| (item == value).then_some(index))
+    }
+
+    /// Returns the index of the highest non-zero item
+    ///
+    /// # Returns
+    /// The position (0-15) of the most significant non-zero item, or `None`
+    /// if all items are zero
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// let vec = U4Vec16::from_u64(0x0000_0000_0030_0100);
+    /// assert_eq!(vec.highest_set_nibble_index(), Some(5));
+    /// assert_eq!(U4Vec16::from_u64(0).highest_set_nibble_index(), None);
+    /// ```
+    #[inline]
+    pub const fn highest_set_nibble_index(&self) -> Option<usize> {
+        match self.bits() {
+            0 => None,
+            bits => Some((63 - bits.leading_zeros() as usize) / Self::ITEM_SIZE),
+        }
+    }
+
+    /// Returns the index of the lowest non-zero item
+    ///
+    /// # Returns
+    /// The position (0-15) of the least significant non-zero item, or `None`
+    /// if all items are zero
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// let vec = U4Vec16::from_u64(0x0000_0000_0030_0100);
+    /// assert_eq!(vec.lowest_set_nibble_index(), Some(2));
+    /// assert_eq!(U4Vec16::from_u64(0).lowest_set_nibble_index(), None);
+    /// ```
+    #[inline]
+    pub const fn lowest_set_nibble_index(&self) -> Option<usize> {
+        match self.bits() {
+            0 => None,
+            bits => Some(bits.trailing_zeros() as usize / Self::ITEM_SIZE),
+        }
+    }
+
+    /// Returns the XOR of all 16 items
+    ///
+    /// This is a simple integrity check for transmitted data: flipping any
+    /// single bit of the vector changes the checksum.
+    ///
+    /// # Returns
+    /// The XOR of all items (0-15)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// let vec = U4Vec16::from_u64(0x0000_0000_0000_0531);
+    /// assert_eq!(vec.checksum(), 0x5 ^ 0x3 ^ 0x1);
+    /// ```
+    #[inline]
+    pub const fn checksum(&self) -> u8 {
+        let mut checksum = 0;
+        let mut index = 0;
+        while index < Self::CAPACITY {
+            checksum ^= self.item(index);
+            index += 1;
+        }
+        checksum
+    }
+
+    /// Returns the sum of all 16 items
+    ///
+    /// # Returns
+    /// The sum of all items (at most 16 * 15 = 240)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// let vec = U4Vec16::from_u64(0x0000_0000_0000_0531);
+    /// assert_eq!(vec.sum_nibbles(), 9);
+    /// ```
+    #[inline]
+    pub const fn sum_nibbles(&self) -> u16 {
+        let mut sum = 0;
+        let mut index = 0;
+        while index < Self::CAPACITY {
+            sum += self.item(index) as u16;
+            index += 1;
+        }
+        sum
+    }
+
+    /// Merges two U4Vec16 instances item by item, keeping the larger value
+    ///
+    /// Each item of the result is `max(self[i], other[i])`, so items that are
+    /// zero in one vector take the value of the other one.
+    ///
+    /// # Arguments
+    /// * `other` - The U4Vec16 to merge with
+    ///
+    /// # Returns
+    /// A new U4Vec16 holding the item-wise maximum of both vectors
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// let a = U4Vec16::from_u64(0x0000_0000_0000_0301);
+    /// let b = U4Vec16::from_u64(0x0000_0000_0001_0502);
+    /// assert_eq!(a.merge_max(b).inner(), 0x0000_0000_0001_0502);
+    /// ```
+    #[inline]
+    pub const fn merge_max(&self, other: U4Vec16) -> U4Vec16 {
+        let mut merged = *self;
+        let mut index = 0;
+        while index < Self::CAPACITY {
+            if other.item(index) > merged.item(index) {
+                merged = merged.set_item(index, other.item(index));
+            }
+            index += 1;
+        }
+        merged
+    }
+
+    /// Maps every item through a 16-entry lookup table
+    ///
+    /// Each item is replaced by `table[item]`, as the `pshufb` SIMD
+    /// instruction does for 4-bit data. Only the low 4 bits of the table
+    /// entries are kept.
+    ///
+    /// # Arguments
+    /// * `table` - The value to use for each of the 16 possible items
+    ///
+    /// # Returns
+    /// A new U4Vec16 holding the mapped items
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// let vec = U4Vec16::from_u64(0x0000_0000_0000_3210);
+    /// let table = [0, 2, 4, 6, 8, 10, 12, 14, 0, 0, 0, 0, 0, 0, 0, 0];
+    /// assert_eq!(vec.apply_lookup(&table).inner(), 0x0000_0000_0000_6420);
+    /// ```
+    pub fn apply_lookup(&self, table: &[u8; 16]) -> U4Vec16 {
+        self.iter_items()
+            .enumerate()
+            .fold(U4Vec16Builder::new(), |builder, (index, item)| {
+                builder.set_item(index, table[item as usize])
+            })
+            .build()
+    }
+
+    /// Maps every item through a 16-entry lookup table in a `const` context
+    ///
+    /// This is the `const` counterpart of `apply_lookup`, usable to build
+    /// constants at compile time.
+    ///
+    /// # Arguments
+    /// * `table` - The value to use for each of the 16 possible items
+    ///
+    /// # Returns
+    /// A new U4Vec16 holding the mapped items
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// const VEC: U4Vec16 = U4Vec16::from_u64(0x0000_0000_0000_00F1)
+    ///     .apply_lookup_const([0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9]);
+    /// assert_eq!(VEC.inner(), 0x0000_0000_0000_0091);
+    /// ```
+    pub const fn apply_lookup_const(&self, table: [u8; 16]) -> U4Vec16 {
+        let mut mapped = Self::from_u64(0);
+        let mut index = 0;
+        while index < Self::CAPACITY {
+            mapped = mapped.set_item(index, table[self.item(index) as usize]);
+            index += 1;
+        }
+        mapped
+    }
 }
 
 /// Builder for constructing U4Vec16 instances
@@ -231,6 +485,7 @@ impl U4Vec16 {
 /// The builder pattern allows for efficient construction of U4Vec16 instances
 /// without intermediate allocations. Each `set_item` call returns a new builder
 /// instance, allowing for method chaining.
+#[derive(Debug, Clone, Copy)]
 pub struct U4Vec16Builder {
     /// The U4Vec16 being constructed
     vec: U4Vec16,
@@ -261,6 +516,56 @@ impl U4Vec16Builder {
         }
     }
 
+    /// Creates a new U4Vec16Builder starting from an existing U4Vec16
+    ///
+    /// This is useful to modify a copy of an existing vector with `set_item`.
+    ///
+    /// # Arguments
+    /// * `vec` - The U4Vec16 used as the initial content of the builder
+    ///
+    /// # Returns
+    /// A new U4Vec16Builder instance holding the items of `vec`
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_bitflags::{u4vec16::U4Vec16Builder, U4Vec16};
+    ///
+    /// let vec = U4Vec16Builder::from_existing(U4Vec16::from_u64(0x21))
+    ///     .set_item(2, 3)
+    ///     .build();
+    /// assert_eq!(vec.inner(), 0x321);
+    /// ```
+    #[inline]
+    pub const fn from_existing(vec: U4Vec16) -> Self {
+        Self { vec }
+    }
+
+    /// Merges another builder into this one, keeping the larger item at each position
+    ///
+    /// This is useful to combine two partially built vectors, for example two
+    /// partial chord structures.
+    ///
+    /// # Arguments
+    /// * `other` - The builder to merge with
+    ///
+    /// # Returns
+    /// A new U4Vec16Builder instance holding the item-wise maximum of both builders
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_bitflags::u4vec16::U4Vec16Builder;
+    ///
+    /// let left = U4Vec16Builder::new().set_item(0, 1).set_item(2, 1);
+    /// let right = U4Vec16Builder::new().set_item(0, 1).set_item(4, 1);
+    /// assert_eq!(left.merge_with(right).build().inner(), 0x10101);
+    /// ```
+    #[inline]
+    pub const fn merge_with(self, other: U4Vec16Builder) -> Self {
+        Self {
+            vec: self.vec.merge_max(other.vec),
+        }
+    }
+
     /// Sets a 4-bit item at the specified index
     ///
     /// This method sets the 4-bit item at the given index to the specified value.
@@ -410,6 +715,25 @@ impl Iterator for U4Vec16Iter {
 
 impl ExactSizeIterator for U4Vec16Iter {}
 
+impl From<[u8; 16]> for U4Vec16 {
+    /// Converts an array of 16 items to a `U4Vec16`
+    ///
+    /// This conversion delegates to [`U4Vec16::from_vec`].
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4Vec16;
+    /// let mut items = [0u8; 16];
+    /// items[1] = 0xA;
+    /// let vec = U4Vec16::from(items);
+    /// assert_eq!(vec.inner(), 0xA0);
+    /// ```
+    #[inline]
+    fn from(items: [u8; 16]) -> Self {
+        Self::from_vec(items)
+    }
+}
+
 impl Index<usize> for U4Vec16 {
     /// The output type when indexing into U4Vec16
     type Output = u8;
@@ -494,6 +818,22 @@ mod tests {
         assert_eq!(vec.item(15), 0b1111);
     }
 
+    #[test]
+    #[should_panic(expected = "item index out of bounds")]
+    fn test_item_out_of_bounds() {
+        U4Vec16::from_u64(VAL).item(16);
+    }
+
+    #[test]
+    fn test_get() {
+        let vec = U4Vec16::from_u64(42);
+        assert_eq!(vec.get(0), Some(0b1010));
+        assert_eq!(vec.get(1), Some(0b0010));
+        assert_eq!(vec.get(15), Some(0));
+        assert_eq!(vec.get(16), None);
+        assert_eq!(vec.get(usize::MAX), None);
+    }
+
     /// Tests that the iterator correctly yields all 16 items in sequence
     ///
     /// This test verifies that iter_items() produces the expected sequence
@@ -815,4 +1155,151 @@ mod tests {
             assert_eq!(vec.item(i), 0);
         }
     }
+
+    /// Tests that converting from an array matches from_vec
+    #[test]
+    fn test_from_u8_array() {
+        let items = [10, 11, 14, 15, 0, 0, 0, 0, 0, 0, 0, 0, 10, 11, 14, 15];
+        let vec: U4Vec16 = items.into();
+        assert_eq!(vec.inner(), VAL);
+        assert_eq!(vec, U4Vec16::from_vec(items));
+    }
+
+    /// Tests the positions of the highest and lowest non-zero items
+    #[test]
+    fn test_set_nibble_indices() {
+        let vec = U4Vec16::from_u64(VAL);
+        assert_eq!(vec.highest_set_nibble_index(), Some(15));
+        assert_eq!(vec.lowest_set_nibble_index(), Some(0));
+
+        // Only the highest bit of item 7 and the lowest bit of item 3
+        let vec = U4Vec16::from_u64(0x8000_1000);
+        assert_eq!(vec.highest_set_nibble_index(), Some(7));
+        assert_eq!(vec.lowest_set_nibble_index(), Some(3));
+
+        let vec = U4Vec16::from_u64(0);
+        assert_eq!(vec.highest_set_nibble_index(), None);
+        assert_eq!(vec.lowest_set_nibble_index(), None);
+    }
+
+    #[test]
+    fn test_merge_max() {
+        let a = U4Vec16::from([1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15]);
+        let b = U4Vec16::from([2, 0, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7]);
+        let merged = a.merge_max(b);
+        assert_eq!(
+            merged.iter_items().collect::<Vec<_>>(),
+            vec![2, 0, 3, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15]
+        );
+        assert_eq!(merged, b.merge_max(a));
+        assert_eq!(a.merge_max(U4Vec16::from_u64(0)), a);
+    }
+
+    #[test]
+    fn test_builder_from_existing() {
+        let vec = U4Vec16::from_u64(VAL);
+        assert_eq!(U4Vec16Builder::from_existing(vec).build(), vec);
+
+        let vec = U4Vec16Builder::from_existing(vec).set_item(0, 0).build();
+        assert_eq!(vec.item(0), 0);
+        assert_eq!(vec.item(1), 0b1011);
+    }
+
+    #[test]
+    fn test_builder_merge_with() {
+        // Root and third merged with root and fifth give a major triad pattern
+        let root_third = U4Vec16Builder::new().set_item(0, 1).set_item(2, 1);
+        let root_fifth = U4Vec16Builder::new().set_item(0, 1).set_item(4, 1);
+        let triad = root_third.merge_with(root_fifth).build();
+        assert_eq!(triad.inner(), 0x10101);
+    }
+
+    #[test]
+    fn test_checksum() {
+        // 10 ^ 11 ^ 14 ^ 15 == 0, twice
+        assert_eq!(U4Vec16::from_u64(VAL).checksum(), 0);
+        assert_eq!(U4Vec16::from_u64(0).checksum(), 0);
+        assert_eq!(U4Vec16::from_u64(0x0000_0000_0001_0101).checksum(), 1);
+
+        let vec = U4Vec16::from_u64(0x0000_0000_0001_0101);
+        let corrupted = U4Vec16::from_u64(vec.inner() ^ 0b0100);
+        assert_ne!(vec.checksum(), corrupted.checksum());
+    }
+
+    #[test]
+    fn test_sum_nibbles() {
+        assert_eq!(
+            U4Vec16::from_u64(VAL).sum_nibbles(),
+            2 * (10 + 11 + 14 + 15)
+        );
+        assert_eq!(U4Vec16::from_u64(0).sum_nibbles(), 0);
+        assert_eq!(U4Vec16::from_u64(u64::MAX).sum_nibbles(), 240);
+    }
+
+    #[test]
+    fn test_count_value() {
+        let vec = U4Vec16::from_u64(VAL);
+        assert_eq!(vec.count_value(0b1010), 2);
+        assert_eq!(vec.count_value(0b1111), 2);
+        assert_eq!(vec.count_value(0), 8);
+        assert_eq!(vec.count_value(0b0001), 0);
+
+        for target in 0..16 {
+            let expected = vec.iter_items().filter(|item| *item == target).count();
+            assert_eq!(vec.count_value(target), expected);
+        }
+    }
+
+    #[test]
+    fn test_positions_of() {
+        let vec = U4Vec16::from_u64(VAL);
+        assert_eq!(vec.positions_of(0b1010).collect::<Vec<_>>(), vec![0, 12]);
+        assert_eq!(
+            vec.positions_of(0).collect::<Vec<_>>(),
+            (4..12).collect::<Vec<_>>()
+        );
+
+        for target in 0..16 {
+            let expected = vec
+                .iter_items()
+                .enumerate()
+                .filter(|(_, item)| *item == target)
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            assert_eq!(vec.positions_of(target).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    /// Tests that apply_lookup maps every item through the table
+    #[test]
+    fn test_apply_lookup() {
+        const QUARTER: [u8; 16] = [0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3];
+
+        let vec = U4Vec16::from_u64(VAL);
+        let mapped = vec.apply_lookup(&QUARTER);
+        for index in 0..16 {
+            assert_eq!(mapped.item(index), vec.item(index) / 4);
+        }
+
+        let identity: [u8; 16] = core::array::from_fn(|i| i as u8);
+        assert_eq!(vec.apply_lookup(&identity), vec);
+    }
+
+    /// Tests that apply_lookup keeps only the low 4 bits of the table entries
+    #[test]
+    fn test_apply_lookup_masks_table_values() {
+        let table = [0x1F; 16];
+        let mapped = U4Vec16::from_u64(VAL).apply_lookup(&table);
+        assert_eq!(mapped.inner(), u64::MAX);
+    }
+
+    /// Tests that the const and runtime lookups agree
+    #[test]
+    fn test_apply_lookup_const() {
+        const TABLE: [u8; 16] = [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
+        const MAPPED: U4Vec16 = U4Vec16::from_u64(VAL).apply_lookup_const(TABLE);
+
+        assert_eq!(MAPPED, U4Vec16::from_u64(VAL).apply_lookup(&TABLE));
+        assert_eq!(MAPPED.inner(), !VAL);
+    }
 }
@@ -0,0 +1,222 @@
+//! U4x2Seq16 - A sequence of 16 packed pairs of 4-bit unsigned integers
+//!
+//! This module provides a fixed-size sequence of 16 `U4x2` values stored in
+//! 16 bytes on the stack. It is useful to keep a pair of small values per
+//! position, such as a chord degree number together with its accidental.
+
+use crate::U4x2;
+
+/// A sequence of 16 `U4x2` values
+///
+/// Each position (0-15) holds one `U4x2`, so the whole sequence takes 16 bytes
+/// with no heap allocation. New sequences start with every position set to
+/// `U4x2::new(0, 0)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_bitflags::{U4x2, U4x2Seq16};
+///
+/// let mut seq = U4x2Seq16::new();
+/// seq.set(3, U4x2::new(5, 2));
+/// assert_eq!(seq.get(3).first(), 5);
+/// assert_eq!(seq.get(3).second(), 2);
+/// assert_eq!(seq.iter().count(), 16);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct U4x2Seq16([U4x2; 16]);
+
+impl U4x2Seq16 {
+    /// The total number of items in a U4x2Seq16
+    const CAPACITY: usize = 16;
+
+    /// Creates a new U4x2Seq16 with all items set to zero
+    ///
+    /// # Returns
+    /// A new U4x2Seq16 instance where every item is `U4x2::new(0, 0)`
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::U4x2Seq16;
+    /// let seq = U4x2Seq16::new();
+    /// assert!(seq.iter().all(|item| item.inner() == 0));
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self([U4x2::new(0, 0); Self::CAPACITY])
+    }
+
+    /// Sets the item at the specified index
+    ///
+    /// # Arguments
+    /// * `index` - The item position to set (0-15)
+    /// * `value` - The packed pair to store
+    ///
+    /// # Panics
+    /// This method will panic if the index is out of bounds (> 15)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::{U4x2, U4x2Seq16};
+    /// let mut seq = U4x2Seq16::new();
+    /// seq.set(15, U4x2::new(1, 4));
+    /// assert_eq!(seq.get(15), U4x2::new(1, 4));
+    /// ```
+    #[inline]
+    pub fn set(&mut self, index: usize, value: U4x2) {
+        self.0[index] = value;
+    }
+
+    /// Returns the item at the specified index
+    ///
+    /// # Arguments
+    /// * `index` - The item position to read (0-15)
+    ///
+    /// # Returns
+    /// The packed pair stored at the position
+    ///
+    /// # Panics
+    /// This method will panic if the index is out of bounds (> 15)
+    #[inline]
+    pub const fn get(&self, index: usize) -> U4x2 {
+        self.0[index]
+    }
+
+    /// Returns an iterator over all 16 items, starting from position 0
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::{U4x2, U4x2Seq16};
+    /// let mut seq = U4x2Seq16::new();
+    /// seq.set(1, U4x2::new(3, 1));
+    /// let firsts: Vec<u8> = seq.iter().map(|item| item.first()).take(3).collect();
+    /// assert_eq!(firsts, vec![0, 3, 0]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = U4x2> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl Default for U4x2Seq16 {
+    /// Creates a U4x2Seq16 with all items set to zero, like `U4x2Seq16::new()`
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for constructing U4x2Seq16 instances
+///
+/// U4x2Seq16Builder provides a fluent, const-friendly interface for
+/// constructing U4x2Seq16 instances by setting individual items.
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_bitflags::{u4x2seq16::U4x2Seq16Builder, U4x2};
+///
+/// let seq = U4x2Seq16Builder::new()
+///     .set_item(0, U4x2::new(1, 1))
+///     .set_item(2, U4x2::new(3, 2))
+///     .build();
+///
+/// assert_eq!(seq.get(2).second(), 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct U4x2Seq16Builder {
+    /// The U4x2Seq16 being constructed
+    seq: U4x2Seq16,
+}
+
+impl U4x2Seq16Builder {
+    /// Creates a new U4x2Seq16Builder with all items initialized to zero
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            seq: U4x2Seq16::new(),
+        }
+    }
+
+    /// Sets the item at the specified index
+    ///
+    /// # Arguments
+    /// * `index` - The item position to set (0-15)
+    /// * `value` - The packed pair to store
+    ///
+    /// # Returns
+    /// A new U4x2Seq16Builder instance with the specified item set
+    ///
+    /// # Panics
+    /// This method will panic if the index is out of bounds (> 15)
+    #[inline]
+    pub const fn set_item(self, index: usize, value: U4x2) -> Self {
+        let mut items = self.seq.0;
+        items[index] = value;
+        Self {
+            seq: U4x2Seq16(items),
+        }
+    }
+
+    /// Finalizes the construction and returns the built U4x2Seq16
+    #[inline]
+    pub const fn build(self) -> U4x2Seq16 {
+        self.seq
+    }
+}
+
+impl Default for U4x2Seq16Builder {
+    /// Creates a default U4x2Seq16Builder, like `U4x2Seq16Builder::new()`
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_get_all_positions() {
+        let mut seq = U4x2Seq16::new();
+        for index in 0..16 {
+            seq.set(index, U4x2::new(index as u8, 15 - index as u8));
+        }
+        for index in 0..16 {
+            assert_eq!(seq.get(index).first(), index as u8);
+            assert_eq!(seq.get(index).second(), 15 - index as u8);
+        }
+    }
+
+    #[test]
+    fn test_iter() {
+        let seq = U4x2Seq16::default();
+        assert_eq!(seq.iter().count(), 16);
+
+        let mut seq = U4x2Seq16::new();
+        seq.set(7, U4x2::new(9, 4));
+        let items = seq.iter().collect::<Vec<_>>();
+        assert_eq!(items.len(), 16);
+        assert_eq!(items[7], U4x2::new(9, 4));
+        assert!(items
+            .iter()
+            .enumerate()
+            .all(|(index, item)| index == 7 || item.inner() == 0));
+    }
+
+    #[test]
+    fn test_builder() {
+        const SEQ: U4x2Seq16 = U4x2Seq16Builder::new()
+            .set_item(0, U4x2::new(1, 1))
+            .set_item(15, U4x2::new(13, 2))
+            .build();
+
+        assert_eq!(SEQ.get(0), U4x2::new(1, 1));
+        assert_eq!(SEQ.get(15), U4x2::new(13, 2));
+        assert_eq!(U4x2Seq16Builder::default().build(), U4x2Seq16::new());
+    }
+
+    #[test]
+    fn test_size() {
+        assert_eq!(std::mem::size_of::<U4x2Seq16>(), 16);
+    }
+}
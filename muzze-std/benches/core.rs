@@ -0,0 +1,74 @@
+//! Benchmarks for the bit operations on the hot paths of the library
+//!
+//! Run them with `cargo bench -p muzze-std`. Criterion keeps the results of
+//! the previous run under `target/criterion` and reports the change against
+//! them; use `--save-baseline` and `--baseline` to compare against a named run.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use muzze_bitflags::{BitVec16, U4Vec16};
+use muzze_std::{Chord, ScaleStepBuilder, HALF, MAJOR, THIRTEENTH_CHORD, WHOLE};
+
+/// Counting bits is a single `popcnt` instruction
+fn bench_bitvec16_count_ones(c: &mut Criterion) {
+    let bits = BitVec16::from_u16(0b0000_1101_0101_1010);
+    c.bench_function("BitVec16::count_ones", |b| {
+        b.iter(|| black_box(bits).count_ones())
+    });
+}
+
+/// Collecting the 16 nibbles shows the iterator and allocation overhead
+fn bench_u4vec16_iter_items(c: &mut Criterion) {
+    let items = U4Vec16::from_u64(0x0123_4567_89AB_CDEF);
+    c.bench_function("U4Vec16::iter_items", |b| {
+        b.iter(|| black_box(items).iter_items().collect::<Vec<_>>())
+    });
+}
+
+/// Rotating the major scale into its 7 modes
+fn bench_scale_modes(c: &mut Criterion) {
+    c.bench_function("Scale::modes (MAJOR)", |b| {
+        b.iter(|| black_box(MAJOR).modes().collect::<Vec<_>>())
+    });
+}
+
+/// Decoding the 7 degrees of a thirteenth chord
+fn bench_chord_degrees(c: &mut Criterion) {
+    c.bench_function("Chord::degrees (THIRTEENTH_CHORD)", |b| {
+        b.iter(|| black_box(THIRTEENTH_CHORD).degrees().collect::<Vec<_>>())
+    });
+}
+
+/// Building the major scale step by step is a handful of shifts and ors
+fn bench_scale_step_builder(c: &mut Criterion) {
+    c.bench_function("ScaleStepBuilder (MAJOR)", |b| {
+        b.iter(|| {
+            ScaleStepBuilder::default()
+                .add_step(black_box(WHOLE))
+                .add_step(WHOLE)
+                .add_step(HALF)
+                .add_step(WHOLE)
+                .add_step(WHOLE)
+                .add_step(WHOLE)
+                .add_step(HALF)
+                .build()
+        })
+    });
+}
+
+/// Converting a chord to its u64 pattern and back is a plain copy
+fn bench_chord_u64_round_trip(c: &mut Criterion) {
+    c.bench_function("Chord::from_u64/to_u64", |b| {
+        b.iter(|| Chord::from_u64(black_box(THIRTEENTH_CHORD).to_u64()))
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_bitvec16_count_ones,
+    bench_u4vec16_iter_items,
+    bench_scale_modes,
+    bench_chord_degrees,
+    bench_scale_step_builder,
+    bench_chord_u64_round_trip
+);
+criterion_main!(benches);
@@ -5,6 +5,7 @@
 //! numeric encodings.
 
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// Represents musical accidentals used to modify the pitch of notes
 ///
@@ -170,6 +171,237 @@ pub const DOUBLE_FLAT: Accidental = Accidental::DoubleFlat;
 /// ```
 pub const DOUBLE_SHARP: Accidental = Accidental::DoubleSharp;
 
+impl Accidental {
+    /// Returns the number of semitones the accidental moves a note by
+    ///
+    /// Both `Natural` and `Reset` leave the note unchanged.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOUBLE_SHARP, FLAT, RESET_ACCIDENTAL};
+    ///
+    /// assert_eq!(FLAT.semitone_offset(), -1);
+    /// assert_eq!(DOUBLE_SHARP.semitone_offset(), 2);
+    /// assert_eq!(RESET_ACCIDENTAL.semitone_offset(), 0);
+    /// ```
+    #[inline]
+    pub const fn semitone_offset(&self) -> i8 {
+        match self {
+            Accidental::Natural | Accidental::Reset => 0,
+            Accidental::Flat => -1,
+            Accidental::DoubleFlat => -2,
+            Accidental::Sharp => 1,
+            Accidental::DoubleSharp => 2,
+        }
+    }
+
+    /// Returns the accidental that moves a note by the given number of semitones
+    ///
+    /// An offset of 0 gives `Natural`, never `Reset`.
+    ///
+    /// # Arguments
+    /// * `offset` - The number of semitones, from -2 to 2
+    ///
+    /// # Returns
+    /// The accidental, or `None` if the offset is beyond a double accidental
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Accidental, DOUBLE_FLAT, NATURAL};
+    ///
+    /// assert_eq!(Accidental::from_semitone_offset(-2), Some(DOUBLE_FLAT));
+    /// assert_eq!(Accidental::from_semitone_offset(0), Some(NATURAL));
+    /// assert_eq!(Accidental::from_semitone_offset(3), None);
+    /// ```
+    #[inline]
+    pub const fn from_semitone_offset(offset: i8) -> Option<Accidental> {
+        match offset {
+            -2 => Some(Accidental::DoubleFlat),
+            -1 => Some(Accidental::Flat),
+            0 => Some(Accidental::Natural),
+            1 => Some(Accidental::Sharp),
+            2 => Some(Accidental::DoubleSharp),
+            _ => None,
+        }
+    }
+
+    /// Combines two accidentals by adding their semitone offsets
+    ///
+    /// Two sharps give a double sharp, a sharp and a flat cancel out into a
+    /// natural, and so on. `Reset` counts as no offset.
+    ///
+    /// # Arguments
+    /// * `other` - The accidental to stack on top of this one
+    ///
+    /// # Returns
+    /// The combined accidental, or `None` if the combined offset is beyond a
+    /// double accidental
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOUBLE_SHARP, FLAT, NATURAL, SHARP};
+    ///
+    /// assert_eq!(SHARP.combine(SHARP), Some(DOUBLE_SHARP));
+    /// assert_eq!(SHARP.combine(FLAT), Some(NATURAL));
+    /// assert_eq!(DOUBLE_SHARP.combine(SHARP), None);
+    /// ```
+    #[inline]
+    pub const fn combine(&self, other: Accidental) -> Option<Accidental> {
+        Self::from_semitone_offset(self.semitone_offset() + other.semitone_offset())
+    }
+
+    /// Combines two accidentals, reporting an error when the result overflows
+    ///
+    /// This is the `Result` returning version of `combine`.
+    ///
+    /// # Arguments
+    /// * `other` - The accidental to stack on top of this one
+    ///
+    /// # Returns
+    /// The combined accidental, or an `AccidentalOverflowError` with the
+    /// combined offset if it is beyond a double accidental
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{AccidentalOverflowError, DOUBLE_FLAT, FLAT};
+    ///
+    /// assert_eq!(FLAT.stack_with(FLAT), Ok(DOUBLE_FLAT));
+    /// assert_eq!(
+    ///     DOUBLE_FLAT.stack_with(FLAT),
+    ///     Err(AccidentalOverflowError { offset: -3 })
+    /// );
+    /// ```
+    #[inline]
+    pub const fn stack_with(
+        self,
+        other: Accidental,
+    ) -> Result<Accidental, AccidentalOverflowError> {
+        let offset = self.semitone_offset() + other.semitone_offset();
+        match Self::from_semitone_offset(offset) {
+            Some(accidental) => Ok(accidental),
+            None => Err(AccidentalOverflowError { offset }),
+        }
+    }
+
+    /// Parses an accidental from its Unicode or ASCII notation
+    ///
+    /// The accepted symbols are:
+    /// - "" for natural
+    /// - "♮" or "n" for reset
+    /// - "♭" or "b" for flat, "♭♭" or "bb" for double flat
+    /// - "♯" or "#" for sharp, "♯♯" or "##" for double sharp
+    ///
+    /// # Arguments
+    /// * `s` - The symbol to parse
+    ///
+    /// # Returns
+    /// The accidental, or `AccidentalParseError::UnknownSymbol` for any other input
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Accidental, AccidentalParseError};
+    ///
+    /// assert_eq!(Accidental::try_from_str("#"), Ok(Accidental::Sharp));
+    /// assert_eq!(Accidental::try_from_str("♭♭"), Ok(Accidental::DoubleFlat));
+    /// assert_eq!(
+    ///     Accidental::try_from_str("x"),
+    ///     Err(AccidentalParseError::UnknownSymbol("x".to_string()))
+    /// );
+    /// ```
+    pub fn try_from_str(s: &str) -> Result<Accidental, AccidentalParseError> {
+        match s {
+            "" => Ok(Accidental::Natural),
+            "♮" | "n" => Ok(Accidental::Reset),
+            "♭" | "b" => Ok(Accidental::Flat),
+            "♭♭" | "bb" => Ok(Accidental::DoubleFlat),
+            "♯" | "#" => Ok(Accidental::Sharp),
+            "♯♯" | "##" => Ok(Accidental::DoubleSharp),
+            _ => Err(AccidentalParseError::UnknownSymbol(s.to_string())),
+        }
+    }
+
+    /// Returns the ASCII notation of the accidental
+    ///
+    /// This is the ASCII counterpart of `Display` and can be parsed back with
+    /// `try_from_str`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Accidental, DOUBLE_SHARP, FLAT, RESET_ACCIDENTAL};
+    ///
+    /// assert_eq!(FLAT.to_ascii(), "b");
+    /// assert_eq!(DOUBLE_SHARP.to_ascii(), "##");
+    /// assert_eq!(RESET_ACCIDENTAL.to_ascii(), "n");
+    /// ```
+    #[inline]
+    pub const fn to_ascii(&self) -> &'static str {
+        match self {
+            Accidental::Natural => "",
+            Accidental::Reset => "n",
+            Accidental::Flat => "b",
+            Accidental::DoubleFlat => "bb",
+            Accidental::Sharp => "#",
+            Accidental::DoubleSharp => "##",
+        }
+    }
+}
+
+/// Errors that can occur when parsing an `Accidental` from a string
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AccidentalParseError {
+    /// The string is not a known accidental symbol
+    UnknownSymbol(String),
+}
+
+impl Display for AccidentalParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccidentalParseError::UnknownSymbol(symbol) => {
+                write!(f, "unknown accidental symbol: {symbol:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AccidentalParseError {}
+
+/// Error returned when stacking two accidentals goes beyond a double accidental
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AccidentalOverflowError {
+    /// The combined semitone offset of the two accidentals
+    pub offset: i8,
+}
+
+impl Display for AccidentalOverflowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "a combined offset of {} semitones is beyond a double accidental",
+            self.offset
+        )
+    }
+}
+
+impl std::error::Error for AccidentalOverflowError {}
+
+impl FromStr for Accidental {
+    type Err = AccidentalParseError;
+
+    /// Parses an accidental, see `Accidental::try_from_str`
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::Accidental;
+    ///
+    /// let sharp: Accidental = "♯".parse().unwrap();
+    /// assert_eq!(sharp, Accidental::Sharp);
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Accidental::try_from_str(s)
+    }
+}
+
 impl Display for Accidental {
     /// Formats the accidental as its Unicode symbol representation
     ///
@@ -203,6 +435,19 @@ impl Display for Accidental {
     }
 }
 
+impl Default for Accidental {
+    /// Returns the natural accidental
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::Accidental;
+    /// assert_eq!(Accidental::default(), Accidental::Natural);
+    /// ```
+    fn default() -> Self {
+        Accidental::Natural
+    }
+}
+
 impl From<Accidental> for u8 {
     /// Converts an `Accidental` to its corresponding `u8` value
     ///
@@ -441,4 +686,96 @@ mod tests {
         assert_eq!(Accidental::Sharp.to_string().len(), 3); // ♯ is 3 bytes in UTF-8
         assert_eq!(Accidental::DoubleSharp.to_string().len(), 6); // ♯♯ is 6 bytes in UTF-8
     }
+
+    #[test]
+    fn test_semitone_offset() {
+        assert_eq!(NATURAL.semitone_offset(), 0);
+        assert_eq!(RESET_ACCIDENTAL.semitone_offset(), 0);
+        assert_eq!(FLAT.semitone_offset(), -1);
+        assert_eq!(DOUBLE_FLAT.semitone_offset(), -2);
+        assert_eq!(SHARP.semitone_offset(), 1);
+        assert_eq!(DOUBLE_SHARP.semitone_offset(), 2);
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let accidentals = [
+            (NATURAL, "", ""),
+            (RESET_ACCIDENTAL, "♮", "n"),
+            (FLAT, "♭", "b"),
+            (DOUBLE_FLAT, "♭♭", "bb"),
+            (SHARP, "♯", "#"),
+            (DOUBLE_SHARP, "♯♯", "##"),
+        ];
+        for (accidental, unicode, ascii) in accidentals {
+            assert_eq!(Accidental::try_from_str(unicode), Ok(accidental));
+            assert_eq!(Accidental::try_from_str(ascii), Ok(accidental));
+            assert_eq!(accidental.to_ascii(), ascii);
+            assert_eq!(ascii.parse::<Accidental>(), Ok(accidental));
+        }
+    }
+
+    #[test]
+    fn test_try_from_str_unknown() {
+        for symbol in ["x", "###", "B", " #", "♭b"] {
+            assert_eq!(
+                Accidental::try_from_str(symbol),
+                Err(AccidentalParseError::UnknownSymbol(symbol.to_string()))
+            );
+        }
+        let err = "?".parse::<Accidental>().unwrap_err();
+        assert_eq!(err.to_string(), "unknown accidental symbol: \"?\"");
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Accidental::default(), Accidental::Natural);
+        assert_eq!(u8::from(Accidental::default()), 0);
+    }
+
+    #[test]
+    fn test_from_semitone_offset() {
+        for accidental in [NATURAL, FLAT, DOUBLE_FLAT, SHARP, DOUBLE_SHARP] {
+            assert_eq!(
+                Accidental::from_semitone_offset(accidental.semitone_offset()),
+                Some(accidental)
+            );
+        }
+        assert_eq!(Accidental::from_semitone_offset(-3), None);
+        assert_eq!(Accidental::from_semitone_offset(3), None);
+    }
+
+    #[test]
+    fn test_combine() {
+        assert_eq!(SHARP.combine(SHARP), Some(DOUBLE_SHARP));
+        assert_eq!(FLAT.combine(FLAT), Some(DOUBLE_FLAT));
+        assert_eq!(SHARP.combine(FLAT), Some(NATURAL));
+        assert_eq!(DOUBLE_SHARP.combine(SHARP), None);
+        assert_eq!(DOUBLE_FLAT.combine(FLAT), None);
+        assert_eq!(DOUBLE_SHARP.combine(DOUBLE_FLAT), Some(NATURAL));
+        assert_eq!(RESET_ACCIDENTAL.combine(SHARP), Some(SHARP));
+        assert_eq!(NATURAL.combine(DOUBLE_FLAT), Some(DOUBLE_FLAT));
+        assert_eq!(NATURAL.combine(FLAT), Some(FLAT));
+        assert_eq!(DOUBLE_FLAT.combine(DOUBLE_SHARP), Some(NATURAL));
+
+        // Combining is commutative over the whole range
+        for a in [DOUBLE_FLAT, FLAT, NATURAL, SHARP, DOUBLE_SHARP] {
+            for b in [DOUBLE_FLAT, FLAT, NATURAL, SHARP, DOUBLE_SHARP] {
+                assert_eq!(a.combine(b), b.combine(a));
+            }
+        }
+    }
+
+    #[test]
+    fn test_stack_with() {
+        assert_eq!(SHARP.stack_with(SHARP), Ok(DOUBLE_SHARP));
+        assert_eq!(FLAT.stack_with(SHARP), Ok(NATURAL));
+
+        let err = DOUBLE_SHARP.stack_with(DOUBLE_SHARP).unwrap_err();
+        assert_eq!(err, AccidentalOverflowError { offset: 4 });
+        assert_eq!(
+            err.to_string(),
+            "a combined offset of 4 semitones is beyond a double accidental"
+        );
+    }
 }
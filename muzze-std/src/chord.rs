@@ -1,10 +1,16 @@
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
 
 use crate::{
-    Degree, DegreeAccidental, DOUBLEFLAT_SEVENTH, ELEVENTH, FIFTH, FLAT_FIFTH, FLAT_SEVENTH,
-    FLAT_THIRD, FOURTH, NINTH, ROOT, SECOND, SEVENTH, SHARP_FIFTH, SIXTH, THIRD, THIRTEENTH,
+    Degree, DegreeAccidental, Interval, PitchClass, Scale, AUGMENTED_FOURTH, DEGREE_DOUBLEFLAT,
+    DEGREE_DOUBLESHARP, DEGREE_FLAT, DEGREE_NATURAL, DEGREE_SHARP, DOUBLEFLAT_SEVENTH, ELEVENTH,
+    FIFTH, FLAT_FIFTH, FLAT_NINTH, FLAT_SEVENTH, FLAT_THIRD, FLAT_THIRTEENTH, FOURTH, MAJOR_SIXTH,
+    MAJOR_THIRD, MINOR_SIXTH, MINOR_THIRD, NINTH, PERFECT_FIFTH, ROOT, SECOND, SEVENTH,
+    SHARP_ELEVENTH, SHARP_FIFTH, SHARP_NINTH, SIXTH, THIRD, THIRTEENTH, UNISON,
 };
 use muzze_bitflags::{u4vec16::U4Vec16Builder, U4Vec16};
+#[cfg(feature = "rand")]
+use rand::{seq::SliceRandom, Rng};
 
 /// Macro to generate chord constants with consistent documentation and structure
 ///
@@ -82,6 +88,7 @@ macro_rules! chord_const {
 /// - **Fast iteration**: O(1) access to individual degrees
 /// - **Memory compact**: No heap allocations required
 /// - **Const construction**: Can be created at compile time
+#[derive(Debug, Clone, Copy)]
 pub struct Chord {
     degrees: U4Vec16,
     name: &'static str,
@@ -116,12 +123,167 @@ impl Chord {
         self.name
     }
 
+    /// Returns the catalog of all the predefined chords
+    ///
+    /// Each predefined chord constant is paired with a human readable name,
+    /// which makes the catalog suitable for chord pickers and name lookups.
+    ///
+    /// # Returns
+    /// The (name, chord) pairs of the predefined chords, triads first
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, DOMINANT_SEVENTH};
+    ///
+    /// let dominant = Chord::all_predefined()
+    ///     .iter()
+    ///     .find(|(name, _)| *name == "Dominant Seventh")
+    ///     .map(|(_, chord)| *chord);
+    /// assert_eq!(dominant, Some(DOMINANT_SEVENTH));
+    /// ```
+    #[inline]
+    pub const fn all_predefined() -> &'static [(&'static str, Chord)] {
+        &PREDEFINED_CHORDS
+    }
+
+    /// Returns the degree pattern of the chord as a u64 value
+    ///
+    /// Each 4-bit item holds the accidental of one degree, as stored in the
+    /// underlying `U4Vec16`. The name of the chord is not part of the value.
+    /// The encoding is meant for compact serialization and is not guaranteed
+    /// to be stable across library versions.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::MAJOR_TRIAD;
+    ///
+    /// assert_eq!(MAJOR_TRIAD.to_u64(), 0x10101);
+    /// ```
+    #[inline]
+    pub const fn to_u64(&self) -> u64 {
+        self.degrees.inner()
+    }
+
+    /// Creates a new unnamed `Chord` from a u64 degree pattern
+    ///
+    /// This is the inverse of `to_u64`. The encoding is not guaranteed to be
+    /// stable across library versions. Nibbles that are not accidental codes
+    /// (above 5) are kept as they are but skipped by `degrees()`, and so by
+    /// `Display` and every method built on it; use `normalize` to clear them.
+    ///
+    /// # Arguments
+    /// * `value` - The degree pattern, as returned by `to_u64`
+    ///
+    /// # Returns
+    /// A new `Chord` with an empty name
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, DOMINANT_SEVENTH};
+    ///
+    /// let chord = Chord::from_u64(DOMINANT_SEVENTH.to_u64());
+    /// assert_eq!(chord, DOMINANT_SEVENTH);
+    ///
+    /// // The 9 in the second nibble is not an accidental code
+    /// assert_eq!(Chord::from_u64(0x10191).to_string(), "R-3-5");
+    /// ```
+    #[inline]
+    pub const fn from_u64(value: u64) -> Chord {
+        Chord::new("", U4Vec16::from_u64(value))
+    }
+
+    /// Generates a random chord with a number of voices in a range
+    ///
+    /// The chord always contains the root. The other degrees are drawn from
+    /// the named chord degrees, from the ♭2 up to the 13th. Two spellings of
+    /// the same degree collapse into one voice, so candidates whose voice
+    /// count falls outside the range are rejected and drawn again.
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator
+    /// * `min_voices` - The minimum number of voices, root included
+    /// * `max_voices` - The maximum number of voices, root included
+    ///
+    /// # Returns
+    /// A new unnamed `Chord` with between `min_voices` and `max_voices` degrees
+    ///
+    /// # Panics
+    /// This method will panic if `max_voices` is 0, if `min_voices` is greater
+    /// than `max_voices`, or if `min_voices` is greater than 10, the number of
+    /// distinct degrees available
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, ROOT};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let chord = Chord::random(&mut rng, 3, 5);
+    /// assert!((3..=5).contains(&chord.degree_count()));
+    /// assert_eq!(chord.degrees().next(), Some(ROOT));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random(rng: &mut impl Rng, min_voices: u8, max_voices: u8) -> Chord {
+        assert!(max_voices > 0, "A chord must have at least one voice");
+        assert!(
+            min_voices <= max_voices,
+            "The minimum number of voices must not exceed the maximum"
+        );
+        assert!(
+            min_voices <= 10,
+            "A chord can have at most 10 distinct degrees"
+        );
+
+        let voices = min_voices.max(1) as usize..=max_voices.min(10) as usize;
+        loop {
+            let count = rng.gen_range(voices.clone());
+            let chord = ChordBuilder::with_root("")
+                .with_degrees(RANDOM_DEGREES.choose_multiple(rng, count - 1).copied())
+                .build();
+            if voices.contains(&chord.degree_count()) {
+                return chord;
+            }
+        }
+    }
+
+    /// Returns the degree pattern of the chord as little-endian bytes
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::MAJOR_TRIAD;
+    ///
+    /// assert_eq!(MAJOR_TRIAD.to_bytes(), [0x01, 0x01, 0x01, 0, 0, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub const fn to_bytes(&self) -> [u8; 8] {
+        self.to_u64().to_le_bytes()
+    }
+
+    /// Creates a new unnamed `Chord` from little-endian bytes
+    ///
+    /// This is the inverse of `to_bytes`. As with `from_u64`, nibbles that are
+    /// not accidental codes are skipped by `degrees()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, MINOR_NINTH};
+    ///
+    /// assert_eq!(Chord::from_bytes(MINOR_NINTH.to_bytes()), MINOR_NINTH);
+    /// ```
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 8]) -> Chord {
+        Chord::from_u64(u64::from_le_bytes(bytes))
+    }
+
     /// Returns an iterator over all degrees in the chord
     ///
     /// This method provides access to all 16 possible degrees (1-16) in the chord.
     /// Each degree is represented as a `Degree` struct containing the degree number
     /// and its accidental modification.
     ///
+    /// Positions holding a value that is not an accidental code, which only
+    /// raw data passed to `from_u64` can produce, are skipped.
+    ///
     /// # Returns
     /// An iterator that yields `Degree` instances for each position in the chord
     ///
@@ -146,1203 +308,4720 @@ impl Chord {
             .iter_items()
             .enumerate()
             .filter_map(|(index, acc)| {
-                if acc == 0 {
-                    None
-                } else {
-                    let index = index as u8 + 1;
-                    let acc = DegreeAccidental::try_from(acc).unwrap();
-                    let degree = Degree::new(index, acc);
-                    Some(degree)
-                }
+                let acc = DegreeAccidental::try_from(acc).ok()?;
+                Some(Degree::new(index as u8 + 1, acc))
             })
     }
-}
 
-impl Display for Chord {
-    /// Formats the chord as its string representation
+    /// Returns the degrees of the chord as a fixed-size array
     ///
-    /// The chord is displayed as a hyphen-separated list of degrees,
-    /// where the root degree shows as "R" and other degrees show their
-    /// accidental (if any) followed by the degree number.
+    /// This is the `const` counterpart of `degrees()`. Entry `n` holds the
+    /// pair (degree number, accidental code) for degree `n + 1`, or `(0, 0)`
+    /// when the degree is absent from the chord. The accidental code is the
+    /// `u8` value of the `DegreeAccidental`.
     ///
-    /// # Examples
+    /// # Returns
+    /// An array with one entry for each of the 16 possible degrees
     ///
+    /// # Example
     /// ```rust
-    /// use muzze_std::{MAJOR_TRIAD, MINOR_TRIAD, DOMINANT_SEVENTH};
+    /// use muzze_std::MINOR_TRIAD;
     ///
-    /// assert_eq!(format!("{}", MAJOR_TRIAD), "R-3-5");
-    /// assert_eq!(format!("{}", MINOR_TRIAD), "R-♭3-5");
-    /// assert_eq!(format!("{}", DOMINANT_SEVENTH), "R-3-5-♭7");
+    /// const DEGREES: [(u8, u8); 16] = MINOR_TRIAD.as_degree_array();
+    /// assert_eq!(DEGREES[0], (1, 1));
+    /// assert_eq!(DEGREES[1], (0, 0));
+    /// assert_eq!(DEGREES[2], (3, 2));
+    /// assert_eq!(DEGREES[4], (5, 1));
     /// ```
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let xs = self
-            .degrees
-            .iter_items()
-            .enumerate()
-            .filter_map(|(index, accidental)| {
-                if accidental == 0 {
-                    None
-                } else {
-                    let index = index as u8 + 1;
-                    let acc = DegreeAccidental::try_from(accidental).unwrap();
-                    let degree = Degree::new(index, acc);
-                    Some(degree.to_string())
-                }
-            })
-            .collect::<Vec<_>>();
-        write!(f, "{}", xs.join("-"))
+    pub const fn as_degree_array(&self) -> [(u8, u8); 16] {
+        let mut degrees = [(0, 0); 16];
+        let mut index = 0;
+        while index < 16 {
+            let accidental = self.degrees.item(index);
+            if accidental != 0 {
+                degrees[index] = (index as u8 + 1, accidental);
+            }
+            index += 1;
+        }
+        degrees
     }
-}
 
-chord_const!(
-    MAJOR_TRIAD,
-    "major triad",
-    "A major triad consists of a root, major third, and perfect fifth. This is the most fundamental major chord and forms the basis of major harmony in Western music.",
-    "Major (bright, stable sound)",
-    "R-3-5",
-    [THIRD, FIFTH]
-);
+    /// Returns the degrees of the chord as a human readable string
+    ///
+    /// The degrees are joined with "-", with the root shown as "R" and the
+    /// other degrees as their accidental followed by the degree number.
+    ///
+    /// # Returns
+    /// The degrees of the chord, for example "R-3-5"
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{HALF_DIMINISHED_SEVENTH, MAJOR_TRIAD};
+    ///
+    /// assert_eq!(MAJOR_TRIAD.to_degree_string(), "R-3-5");
+    /// assert_eq!(HALF_DIMINISHED_SEVENTH.to_degree_string(), "R-♭3-♭5-♭7");
+    /// ```
+    pub fn to_degree_string(&self) -> String {
+        self.degrees()
+            .map(|degree| degree.to_string())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
 
-chord_const!(
-    MINOR_TRIAD,
-    "minor triad",
-    "A minor triad consists of a root, minor third, and perfect fifth. This is the most fundamental minor chord and forms the basis of minor harmony in Western music.",
-    "Minor (dark, melancholic sound)",
-    "R-♭3-5",
-    [FLAT_THIRD, FIFTH]
-);
+    /// Creates a new unnamed `Chord` from semitone intervals above the root
+    ///
+    /// Each interval is mapped to the chord degree that most commonly spells it:
+    /// 0 is the root, 3 the minor third, 6 the flat fifth, 8 the sharp fifth,
+    /// 10 the flat seventh, and intervals from 12 up to 21 semitones map to the
+    /// compound degrees (♭9, 9, ♯9, 11, ♯11, ♭13, 13). The root is only part of
+    /// the chord when 0 is among the intervals. Repeated intervals are allowed.
+    ///
+    /// # Arguments
+    /// * `root_intervals` - The semitone intervals above the root
+    ///
+    /// # Returns
+    /// The `Chord`, or a `ChordError` if an interval has no degree or two intervals
+    /// need the same degree
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, ChordError};
+    ///
+    /// let chord = Chord::from_intervals(&[0, 4, 7, 10, 14]).unwrap();
+    /// assert_eq!(chord.to_string(), "R-3-5-♭7-9");
+    /// assert_eq!(
+    ///     Chord::from_intervals(&[0, 4, 30]).err(),
+    ///     Some(ChordError::UnsupportedInterval(30))
+    /// );
+    /// ```
+    pub fn from_intervals(root_intervals: &[u8]) -> Result<Chord, ChordError> {
+        let mut degrees = U4Vec16::from_u64(0);
+        for interval in root_intervals {
+            let degree = Self::degree_for_interval(*interval)
+                .ok_or(ChordError::UnsupportedInterval(*interval))?;
+
+            let index = degree.degree() as usize - 1;
+            let accidental = degree.accidental() as u8;
+            let current = degrees.item(index);
+            if current != 0 && current != accidental {
+                return Err(ChordError::DegreeConflict(degree.degree()));
+            }
+
+            degrees = degrees.set_item(index, accidental);
+        }
 
-chord_const!(
-    DIMINISHED_TRIAD,
-    "diminished triad",
-    "A diminished triad consists of a root, minor third, and diminished fifth. This chord creates a very tense, unstable sound and is commonly used as a passing chord or in diminished harmony.",
-    "Diminished (tense, unstable sound)",
-    "R-♭3-♭5",
-    [FLAT_THIRD, FLAT_FIFTH]
-);
+        Ok(Chord::new("", degrees))
+    }
 
-chord_const!(
-    AUGMENTED_TRIAD,
-    "augmented triad",
-    "An augmented triad consists of a root, major third, and augmented fifth. This chord creates a bright, tense sound and is commonly used in augmented harmony and as a passing chord.",
-    "Augmented (bright, tense sound)",
-    "R-3-♯5",
-    [THIRD, SHARP_FIFTH]
-);
+    /// Creates a new unnamed `Chord` from a selection of scale tones
+    ///
+    /// The first index selects the root of the chord; every selected tone is
+    /// then turned into its semitone distance above that root, folded into a
+    /// single octave, and spelled with `from_intervals`. Selecting `[0, 2, 4]`
+    /// builds the triad on the first degree, and `[5, 0, 2]` the triad on the
+    /// sixth degree of a heptatonic scale. Unlike `Scale::get_chord_on_degree`,
+    /// any combination of scale tones can be picked.
+    ///
+    /// # Arguments
+    /// * `scale` - The scale to pick the tones from
+    /// * `degree_indices` - The zero-based indices of the scale tones (0 is the tonic)
+    ///
+    /// # Returns
+    /// The `Chord`, or `None` if no index is given, if an index is out of range
+    /// for the scale, or if two tones need the same chord degree
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, MAJOR, MAJOR_TRIAD, MINOR_TRIAD};
+    ///
+    /// assert_eq!(Chord::from_scale_degrees(MAJOR, &[0, 2, 4]), Some(MAJOR_TRIAD));
+    /// assert_eq!(Chord::from_scale_degrees(MAJOR, &[1, 3, 5]), Some(MINOR_TRIAD));
+    /// assert_eq!(Chord::from_scale_degrees(MAJOR, &[0, 2, 7]), None);
+    /// ```
+    pub fn from_scale_degrees(scale: Scale, degree_indices: &[usize]) -> Option<Chord> {
+        let tones = degree_indices
+            .iter()
+            .map(|index| {
+                scale
+                    .get_interval_at(*index)
+                    .map(|interval| interval.inner())
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let root = *tones.first()?;
+        let intervals = tones
+            .iter()
+            .map(|tone| (tone + 12 - root) % 12)
+            .collect::<Vec<_>>();
+        Self::from_intervals(&intervals).ok()
+    }
 
-chord_const!(
-    MAJOR_SEVENTH_CHORD,
-    "major seventh chord",
-    "A major seventh chord consists of a root, major third, perfect fifth, and major seventh. This chord has a sophisticated, jazzy sound and is commonly used in jazz and contemporary music.",
-    "Major 7th (sophisticated, jazzy sound)",
-    "R-3-5-7",
-    [THIRD, FIFTH, SEVENTH]
-);
+    /// Returns the most common degree spelling of a semitone interval above the root
+    const fn degree_for_interval(semitones: u8) -> Option<Degree> {
+        let degree = match semitones {
+            0 => Degree::new(1, DEGREE_NATURAL),
+            1 => Degree::new(2, DEGREE_FLAT),
+            2 => Degree::new(2, DEGREE_NATURAL),
+            3 => Degree::new(3, DEGREE_FLAT),
+            4 => Degree::new(3, DEGREE_NATURAL),
+            5 => Degree::new(4, DEGREE_NATURAL),
+            6 => Degree::new(5, DEGREE_FLAT),
+            7 => Degree::new(5, DEGREE_NATURAL),
+            8 => Degree::new(5, DEGREE_SHARP),
+            9 => Degree::new(6, DEGREE_NATURAL),
+            10 => Degree::new(7, DEGREE_FLAT),
+            11 => Degree::new(7, DEGREE_NATURAL),
+            12 => Degree::new(8, DEGREE_NATURAL),
+            13 => Degree::new(9, DEGREE_FLAT),
+            14 => Degree::new(9, DEGREE_NATURAL),
+            15 => Degree::new(9, DEGREE_SHARP),
+            16 => Degree::new(10, DEGREE_NATURAL),
+            17 => Degree::new(11, DEGREE_NATURAL),
+            18 => Degree::new(11, DEGREE_SHARP),
+            19 => Degree::new(12, DEGREE_NATURAL),
+            20 => Degree::new(13, DEGREE_FLAT),
+            21 => Degree::new(13, DEGREE_NATURAL),
+            _ => return None,
+        };
+        Some(degree)
+    }
 
-chord_const!(
-    MINOR_SEVENTH_CHORD,
-    "minor seventh chord",
-    "A minor seventh chord consists of a root, minor third, perfect fifth, and minor seventh. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
-    "Minor 7th (mellow, bluesy sound)",
-    "R-♭3-5-♭7",
-    [FLAT_THIRD, FIFTH, FLAT_SEVENTH]
-);
+    /// Returns the number of degrees present in the chord
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOMINANT_SEVENTH, MAJOR_TRIAD};
+    ///
+    /// assert_eq!(MAJOR_TRIAD.degree_count(), 3);
+    /// assert_eq!(DOMINANT_SEVENTH.degree_count(), 4);
+    /// ```
+    #[inline]
+    pub fn degree_count(&self) -> usize {
+        self.degrees().count()
+    }
 
-chord_const!(
-    DOMINANT_SEVENTH,
-    "dominant seventh chord",
-    "A dominant seventh chord consists of a root, major third, perfect fifth, and minor seventh. This chord has a strong, bluesy sound and is commonly used as a dominant chord in functional harmony.",
-    "Dominant 7th (strong, bluesy sound)",
-    "R-3-5-♭7",
-    [THIRD, FIFTH, FLAT_SEVENTH]
-);
+    /// Returns the number of voices in the chord
+    ///
+    /// This is an alias for `degree_count()`, using the name common in
+    /// voice-leading contexts.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{MAJOR_NINTH, MAJOR_TRIAD};
+    ///
+    /// assert_eq!(MAJOR_TRIAD.voice_count(), 3);
+    /// assert_eq!(MAJOR_NINTH.voice_count(), 5);
+    /// ```
+    #[inline]
+    pub fn voice_count(&self) -> usize {
+        self.degree_count()
+    }
 
-chord_const!(
-    HALF_DIMINISHED_SEVENTH,
-    "half-diminished seventh chord",
-    "A half-diminished seventh chord consists of a root, minor third, diminished fifth, and minor seventh. This chord has a tense, unstable sound and is commonly used as a minor ii chord in functional harmony.",
-    "Half-diminished 7th (tense, unstable sound)",
-    "R-♭3-♭5-♭7",
-    [FLAT_THIRD, FLAT_FIFTH, FLAT_SEVENTH]
-);
+    /// Returns true if the chord is made of exactly a root, a third and a fifth
+    ///
+    /// The accidentals of the third and fifth are not taken into account, so
+    /// major, minor, diminished and augmented triads are all complete.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DIMINISHED_TRIAD, FIFTH_CHORD, MAJOR_TRIAD, SUSPENDED_SECOND};
+    ///
+    /// assert!(MAJOR_TRIAD.is_complete_triad());
+    /// assert!(DIMINISHED_TRIAD.is_complete_triad());
+    /// assert!(!FIFTH_CHORD.is_complete_triad());
+    /// assert!(!SUSPENDED_SECOND.is_complete_triad());
+    /// ```
+    pub fn is_complete_triad(&self) -> bool {
+        self.degree_count() == 3 && self.has_degrees(&[1, 3, 5])
+    }
 
-chord_const!(
-    DIMINISHED_SEVENTH,
-    "diminished seventh chord",
-    "A diminished seventh chord consists of a root, minor third, diminished fifth, and diminished seventh. This chord has a very tense, unstable sound and is commonly used as a passing chord or in diminished harmony.",
-    "Diminished 7th (very tense, unstable sound)",
-    "R-♭3-♭5-♭♭7",
-    [FLAT_THIRD, FLAT_FIFTH, DOUBLEFLAT_SEVENTH]
-);
+    /// Returns true if the chord is made of exactly a root, a third, a fifth
+    /// and a seventh
+    ///
+    /// The accidentals of the third, fifth and seventh are not taken into
+    /// account.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOMINANT_NINTH, DOMINANT_SEVENTH, HALF_DIMINISHED_SEVENTH, MAJOR_TRIAD};
+    ///
+    /// assert!(DOMINANT_SEVENTH.is_complete_seventh_chord());
+    /// assert!(HALF_DIMINISHED_SEVENTH.is_complete_seventh_chord());
+    /// assert!(!MAJOR_TRIAD.is_complete_seventh_chord());
+    /// assert!(!DOMINANT_NINTH.is_complete_seventh_chord());
+    /// ```
+    pub fn is_complete_seventh_chord(&self) -> bool {
+        self.degree_count() == 4 && self.has_degrees(&[1, 3, 5, 7])
+    }
 
-chord_const!(
-    AUGMENTED_SEVENTH,
-    "augmented seventh chord",
-    "An augmented seventh chord consists of a root, major third, augmented fifth, and minor seventh. This chord has a bright, tense sound and is commonly used in augmented harmony and as a passing chord.",
-    "Augmented 7th (bright, tense sound)",
-    "R-3-♯5-♭7",
-    [THIRD, SHARP_FIFTH, FLAT_SEVENTH]
-);
+    /// Returns true if every given degree number is present in the chord
+    fn has_degrees(&self, degrees: &[u8]) -> bool {
+        degrees
+            .iter()
+            .all(|degree| self.degrees.item(*degree as usize - 1) != 0)
+    }
 
-chord_const!(
-    MINOR_MAJOR_SEVENTH,
-    "minor major seventh chord",
-    "A minor major seventh chord consists of a root, minor third, perfect fifth, and major seventh. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
-    "Minor major 7th (mellow, bluesy sound)",
-    "R-♭3-5-7",
-    [FLAT_THIRD, FIFTH, SEVENTH]
-);
+    /// Returns the intervals of the chord degrees above the root
+    ///
+    /// Each degree is converted with `Interval::from_degree`, so the
+    /// extensions keep their compound intervals (the ninth is 14 semitones).
+    /// Degrees above the 13th, which have no interval, are skipped.
+    ///
+    /// # Returns
+    /// An iterator over the intervals, in degree order
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::DOMINANT_NINTH;
+    ///
+    /// let intervals = DOMINANT_NINTH.to_intervals().map(|i| i.inner()).collect::<Vec<_>>();
+    /// assert_eq!(intervals, vec![0, 4, 7, 10, 14]);
+    /// ```
+    pub fn to_intervals(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.degrees()
+            .filter_map(|degree| Interval::from_degree(&degree))
+    }
 
-chord_const!(
-    SIXTH_CHORD,
-    "sixth chord",
-    "A sixth chord consists of a root, major third, and sixth. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
-    "Sixth (mellow, bluesy sound)",
+    /// Returns the pitch classes of the chord relative to its root
+    ///
+    /// Each degree is converted to its interval above the root (see
+    /// `Interval::from_degree`) and folded into a single octave. Bit `n` of the
+    /// result is set when the chord has a note `n` semitones above the root.
+    ///
+    /// # Returns
+    /// A 12-bit pitch-class set
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOMINANT_NINTH, MAJOR_TRIAD};
+    ///
+    /// assert_eq!(MAJOR_TRIAD.semitone_set(), 0b0000_1001_0001);
+    /// // The ninth folds onto the second
+    /// assert_eq!(DOMINANT_NINTH.semitone_set(), 0b0100_1001_0101);
+    /// ```
+    pub fn semitone_set(&self) -> u16 {
+        self.to_intervals()
+            .fold(0, |set, interval| set | (1 << (interval.inner() % 12)))
+    }
+
+    /// Checks whether the chord built on the tonic of a scale only uses scale tones
+    ///
+    /// The chord tones are folded into a single octave (see `semitone_set`),
+    /// so the extensions are checked like their simple intervals.
+    ///
+    /// # Arguments
+    /// * `scale` - The scale whose tonic is the root of the chord
+    ///
+    /// # Returns
+    /// `true` if every chord tone above the tonic belongs to the scale
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{MAJOR, MAJOR_SEVENTH_CHORD, MINOR_TRIAD, NATURAL_MINOR};
+    ///
+    /// assert!(MAJOR_SEVENTH_CHORD.fits_scale(MAJOR));
+    /// assert!(!MINOR_TRIAD.fits_scale(MAJOR));
+    /// assert!(MINOR_TRIAD.fits_scale(NATURAL_MINOR));
+    /// ```
+    pub fn fits_scale(&self, scale: Scale) -> bool {
+        let set = self.semitone_set();
+        set & scale.chroma() == set
+    }
+
+    /// Returns the pitch classes of the chord built on a root
+    ///
+    /// Each degree is converted to its interval above the root (see
+    /// `Interval::from_degree`) and the root is transposed by it. Degrees
+    /// without an interval (above the 13th) are skipped.
+    ///
+    /// # Arguments
+    /// * `root` - The pitch class of the chord root
+    ///
+    /// # Returns
+    /// The pitch classes of the chord in degree order
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{PitchClass, MINOR_SEVENTH_CHORD};
+    ///
+    /// assert_eq!(
+    ///     MINOR_SEVENTH_CHORD.to_pitch_classes(PitchClass::D),
+    ///     vec![PitchClass::D, PitchClass::F, PitchClass::A, PitchClass::C]
+    /// );
+    /// ```
+    pub fn to_pitch_classes(&self, root: PitchClass) -> Vec<PitchClass> {
+        self.to_intervals()
+            .map(|interval| root.transpose(interval.inner()))
+            .collect()
+    }
+
+    /// Returns true if the chord built on a root contains a pitch class
+    ///
+    /// # Arguments
+    /// * `root` - The pitch class of the chord root
+    /// * `pc` - The pitch class to look for
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{PitchClass, DOMINANT_SEVENTH};
+    ///
+    /// // The ♭7 of G7 is F
+    /// assert!(DOMINANT_SEVENTH.contains_pitch_class(PitchClass::G, PitchClass::F));
+    /// assert!(!DOMINANT_SEVENTH.contains_pitch_class(PitchClass::G, PitchClass::Fs));
+    /// ```
+    pub fn contains_pitch_class(&self, root: PitchClass, pc: PitchClass) -> bool {
+        let semitones = (pc.inner() + 12 - root.inner()) % 12;
+        self.semitone_set() & (1 << semitones) != 0
+    }
+
+    /// Returns true if two voices of the chord are a tritone apart
+    ///
+    /// The tritone is the defining interval of dominant and diminished chords
+    /// and gives them their pull toward resolution.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOMINANT_SEVENTH, HALF_DIMINISHED_SEVENTH, MAJOR_TRIAD};
+    ///
+    /// assert!(DOMINANT_SEVENTH.has_tritone());
+    /// assert!(HALF_DIMINISHED_SEVENTH.has_tritone());
+    /// assert!(!MAJOR_TRIAD.has_tritone());
+    /// ```
+    #[inline]
+    pub fn has_tritone(&self) -> bool {
+        self.tritone_pair().is_some()
+    }
+
+    /// Returns the first pair of voices of the chord a tritone apart
+    ///
+    /// The voices are the intervals of the degrees above the root, compared
+    /// in degree order. Two voices form a tritone when they are 6 semitones
+    /// apart, ignoring octaves.
+    ///
+    /// # Returns
+    /// The intervals of the lower and upper voice of the tritone, or `None`
+    /// if the chord has no tritone
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOMINANT_SEVENTH, MAJOR_THIRD, MAJOR_TRIAD, MINOR_SEVENTH};
+    ///
+    /// assert_eq!(
+    ///     DOMINANT_SEVENTH.tritone_pair(),
+    ///     Some((MAJOR_THIRD, MINOR_SEVENTH))
+    /// );
+    /// assert_eq!(MAJOR_TRIAD.tritone_pair(), None);
+    /// ```
+    pub fn tritone_pair(&self) -> Option<(Interval, Interval)> {
+        let voices = self.to_intervals().collect::<Vec<_>>();
+        voices.iter().enumerate().find_map(|(i, low)| {
+            voices[i + 1..]
+                .iter()
+                .find(|high| high.inner().abs_diff(low.inner()) % 12 == 6)
+                .map(|high| (*low, *high))
+        })
+    }
+
+    /// Returns the interval between every pair of degrees of the chord
+    ///
+    /// This is the pairwise interval content of the chord used in voice
+    /// leading and tension analysis. A chord with `n` degrees yields the
+    /// `n * (n - 1) / 2` pairs in degree order: for a seventh chord 1→3, 1→5,
+    /// 1→7, 3→5, 3→7 and 5→7. The interval between two degrees is the
+    /// difference of their `Degree::to_interval` values, and degrees above
+    /// the 13th, which have no interval, are skipped.
+    ///
+    /// # Returns
+    /// An iterator over (lower degree, upper degree, interval) tuples
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{
+    ///     FIFTH, MAJOR_THIRD, MAJOR_TRIAD, MINOR_THIRD, PERFECT_FIFTH, ROOT, THIRD,
+    /// };
+    ///
+    /// let pairs: Vec<_> = MAJOR_TRIAD.tension_intervals().collect();
+    /// assert_eq!(
+    ///     pairs,
+    ///     vec![
+    ///         (ROOT, THIRD, MAJOR_THIRD),
+    ///         (ROOT, FIFTH, PERFECT_FIFTH),
+    ///         (THIRD, FIFTH, MINOR_THIRD),
+    ///     ]
+    /// );
+    /// ```
+    pub fn tension_intervals(&self) -> impl Iterator<Item = (Degree, Degree, Interval)> {
+        let voices = self
+            .degrees()
+            .filter_map(|degree| degree.to_interval().map(|interval| (degree, interval)))
+            .collect::<Vec<_>>();
+        let mut pairs = Vec::with_capacity(voices.len() * voices.len().saturating_sub(1) / 2);
+        for (i, (low, low_interval)) in voices.iter().enumerate() {
+            for (high, high_interval) in &voices[i + 1..] {
+                let semitones = high_interval.inner().abs_diff(low_interval.inner());
+                pairs.push((*low, *high, Interval::from(semitones)));
+            }
+        }
+        pairs.into_iter()
+    }
+
+    /// Returns a heuristic tension score of the chord, from 0 to 100
+    ///
+    /// Every pair of voices is given the tension of the interval between them,
+    /// reduced to a single octave: 0 for a unison or octave, 5 for a perfect
+    /// fifth, 10 for a major third, up to 90 for a tritone and 100 for a minor
+    /// second. The sum is normalized by the maximum possible sum, which is 100
+    /// for every pair of voices.
+    ///
+    /// # Returns
+    /// The tension score, 0 for chords with fewer than two voices
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DIMINISHED_TRIAD, FIFTH_CHORD, MAJOR_TRIAD};
+    ///
+    /// assert_eq!(FIFTH_CHORD.functional_tension(), 5);
+    /// assert!(MAJOR_TRIAD.functional_tension() < DIMINISHED_TRIAD.functional_tension());
+    /// ```
+    pub fn functional_tension(&self) -> u8 {
+        let voices = self
+            .to_intervals()
+            .map(|interval| interval.inner())
+            .collect::<Vec<_>>();
+        let pairs = voices.len() * voices.len().saturating_sub(1) / 2;
+        if pairs == 0 {
+            return 0;
+        }
+
+        let total = voices
+            .iter()
+            .enumerate()
+            .flat_map(|(i, low)| voices[i + 1..].iter().map(move |high| high.abs_diff(*low)))
+            .map(|semitones| INTERVAL_TENSIONS[semitones as usize % 12] as usize)
+            .sum::<usize>();
+        // The maximum tension is 100 per pair, so the normalized score is the mean
+        (total / pairs) as u8
+    }
+
+    /// Returns the voice leading distance between two chords on the same root
+    ///
+    /// The voices of both chords are folded into a single octave (see
+    /// `semitone_set`) and paired in ascending order, and the semitone
+    /// movements of all pairs are added up. When one chord has more voices,
+    /// each extra voice is paired with the closest voice of the smaller chord.
+    ///
+    /// # Arguments
+    /// * `other` - The chord to move to
+    ///
+    /// # Returns
+    /// The total number of semitones the voices move, 0 for identical chords
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{AUGMENTED_TRIAD, DIMINISHED_TRIAD, MAJOR_TRIAD, MINOR_TRIAD};
+    ///
+    /// assert_eq!(MAJOR_TRIAD.voice_leading_distance(&MAJOR_TRIAD), 0);
+    /// assert_eq!(MAJOR_TRIAD.voice_leading_distance(&MINOR_TRIAD), 1);
+    /// assert_eq!(AUGMENTED_TRIAD.voice_leading_distance(&DIMINISHED_TRIAD), 3);
+    /// ```
+    pub fn voice_leading_distance(&self, other: &Chord) -> u32 {
+        let voices = |chord: &Chord| {
+            let set = chord.semitone_set();
+            (0..12u8)
+                .filter(|semitones| set & (1 << semitones) != 0)
+                .collect::<Vec<_>>()
+        };
+        let (lhs, rhs) = (voices(self), voices(other));
+        let (long, short) = if lhs.len() >= rhs.len() {
+            (lhs, rhs)
+        } else {
+            (rhs, lhs)
+        };
+
+        long.iter()
+            .enumerate()
+            .map(|(index, voice)| match short.get(index) {
+                Some(paired) => voice.abs_diff(*paired) as u32,
+                None => short
+                    .iter()
+                    .map(|paired| voice.abs_diff(*paired) as u32)
+                    .min()
+                    .unwrap_or(0),
+            })
+            .sum()
+    }
+
+    /// Returns a copy of the chord without the given degree
+    ///
+    /// The degree is removed regardless of its accidental. Removing a degree
+    /// that is not part of the chord, or a number outside 1-16, leaves the
+    /// chord unchanged.
+    ///
+    /// # Arguments
+    /// * `degree` - The degree number to remove (1-16)
+    ///
+    /// # Returns
+    /// A new `Chord` with the same name and without the degree
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::DOMINANT_SEVENTH;
+    ///
+    /// let shell = DOMINANT_SEVENTH.remove_degree(5);
+    /// assert_eq!(shell.to_string(), "R-3-♭7");
+    /// ```
+    #[inline]
+    pub const fn remove_degree(&self, degree: u8) -> Chord {
+        if degree == 0 || degree > 16 {
+            return *self;
+        }
+        Chord::new(self.name, self.degrees.reset_item(degree as usize - 1))
+    }
+
+    /// Returns a copy of the chord without its fifth
+    ///
+    /// The fifth is removed whatever its accidental, so diminished and
+    /// augmented fifths are omitted too.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::MAJOR_SEVENTH_CHORD;
+    ///
+    /// assert_eq!(MAJOR_SEVENTH_CHORD.omit_fifth().to_string(), "R-3-7");
+    /// ```
+    #[inline]
+    pub const fn omit_fifth(&self) -> Chord {
+        self.remove_degree(5)
+    }
+
+    /// Returns a copy of the chord without its third
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::MINOR_SEVENTH_CHORD;
+    ///
+    /// assert_eq!(MINOR_SEVENTH_CHORD.omit_third().to_string(), "R-5-♭7");
+    /// ```
+    #[inline]
+    pub const fn omit_third(&self) -> Chord {
+        self.remove_degree(3)
+    }
+
+    /// Returns a copy of the chord with a consistent degree pattern
+    ///
+    /// Each degree position can only hold one accidental, so a chord never has
+    /// conflicting spellings of the same degree. A chord created from raw data
+    /// with `from_u64` may however hold values that are not accidental codes;
+    /// those degrees are cleared. Octave equivalents are then merged with
+    /// `deduplicate_octave_equivalents`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, MAJOR_TRIAD};
+    ///
+    /// // The 9 in the second nibble is not an accidental code
+    /// let chord = Chord::from_u64(0x10191);
+    /// assert_eq!(chord.normalize(), MAJOR_TRIAD);
+    /// assert_eq!(MAJOR_TRIAD.normalize(), MAJOR_TRIAD);
+    /// ```
+    #[inline]
+    pub const fn normalize(&self) -> Chord {
+        let degrees = self.degrees.apply_lookup_const(VALID_ACCIDENTAL_CODES);
+        Chord::new(self.name, degrees).deduplicate_octave_equivalents()
+    }
+
+    /// Returns a copy of the chord without the octave when the root is present
+    ///
+    /// The 8th degree is an octave above the root and sounds as the same pitch
+    /// class, so it is removed from chords that already contain the root.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, MAJOR_TRIAD};
+    ///
+    /// // Root, 3rd, 5th and 8th
+    /// let chord = Chord::from_u64(0x1001_0101);
+    /// assert_eq!(chord.degree_count(), 4);
+    /// assert_eq!(chord.deduplicate_octave_equivalents(), MAJOR_TRIAD);
+    /// ```
+    #[inline]
+    pub const fn deduplicate_octave_equivalents(&self) -> Chord {
+        if self.degrees.item(0) != 0 {
+            self.remove_degree(8)
+        } else {
+            *self
+        }
+    }
+
+    /// Returns the shell voicing of the chord
+    ///
+    /// A shell voicing keeps only the root, the third and the seventh, which
+    /// are enough to define the quality of a seventh chord. The fifth and any
+    /// extensions are dropped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOMINANT_NINTH, DOMINANT_SEVENTH};
+    ///
+    /// assert_eq!(DOMINANT_SEVENTH.shell_voicing().to_string(), "R-3-♭7");
+    /// assert_eq!(DOMINANT_NINTH.shell_voicing().to_string(), "R-3-♭7");
+    /// ```
+    #[inline]
+    pub const fn shell_voicing(&self) -> Chord {
+        let mut degrees = U4Vec16Builder::new();
+        let mut index = 0;
+        while index < 16 {
+            if matches!(index, 0 | 2 | 6) {
+                degrees = degrees.set_item(index, self.degrees.item(index));
+            }
+            index += 1;
+        }
+        Chord::new(self.name, degrees.build())
+    }
+
+    /// Returns the rootless voicing of the chord
+    ///
+    /// Rootless voicings leave the root to the bass player and are common in
+    /// jazz piano comping.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::DOMINANT_NINTH;
+    ///
+    /// assert_eq!(DOMINANT_NINTH.rootless_voicing().to_string(), "3-5-♭7-9");
+    /// ```
+    #[inline]
+    pub const fn rootless_voicing(&self) -> Chord {
+        self.remove_degree(1)
+    }
+
+    /// Returns a copy of the chord with a tension added
+    ///
+    /// Any tension already stored on the same degree number is replaced, so
+    /// adding a ♯11 to a chord with a natural 11 swaps one for the other.
+    ///
+    /// # Arguments
+    /// * `tension` - The tension to add: a 9th, 11th or 13th with any accidental
+    ///
+    /// # Returns
+    /// A new `Chord` with the same name and with the tension set
+    ///
+    /// # Panics
+    /// This method will panic if the degree is not a 9th, 11th or 13th
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOMINANT_SEVENTH, ELEVENTH};
+    ///
+    /// let eleventh = DOMINANT_SEVENTH.add_tension(ELEVENTH);
+    /// assert_eq!(eleventh.to_string(), "R-3-5-♭7-11");
+    /// ```
+    #[inline]
+    pub const fn add_tension(&self, tension: Degree) -> Chord {
+        assert!(
+            is_tension(tension),
+            "A tension must be a 9th, 11th or 13th degree"
+        );
+
+        let index = tension.degree() as usize - 1;
+        let degrees = self
+            .remove_degree(tension.degree())
+            .degrees
+            .set_item(index, tension.accidental() as u8);
+        Chord::new(self.name, degrees)
+    }
+
+    /// Returns a copy of the chord with a tension removed
+    ///
+    /// The tension is only removed when the chord holds it with the same
+    /// accidental. Removing a tension that is absent (or stored with another
+    /// accidental) leaves the chord unchanged.
+    ///
+    /// # Arguments
+    /// * `tension` - The tension to remove
+    ///
+    /// # Returns
+    /// A new `Chord` with the same name and without the tension
+    ///
+    /// # Panics
+    /// This method will panic if the degree is not a 9th, 11th or 13th
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOMINANT_NINTH, NINTH, THIRTEENTH};
+    ///
+    /// assert_eq!(DOMINANT_NINTH.remove_tension(NINTH).to_string(), "R-3-5-♭7");
+    /// assert_eq!(DOMINANT_NINTH.remove_tension(THIRTEENTH).to_string(), "R-3-5-♭7-9");
+    /// ```
+    #[inline]
+    pub const fn remove_tension(&self, tension: Degree) -> Chord {
+        assert!(
+            is_tension(tension),
+            "A tension must be a 9th, 11th or 13th degree"
+        );
+
+        let stored = self.degrees.item(tension.degree() as usize - 1);
+        if stored == tension.accidental() as u8 {
+            self.remove_degree(tension.degree())
+        } else {
+            *self
+        }
+    }
+
+    /// Stacks one more third on top of the chord, using the tones of a scale
+    ///
+    /// The chord is rooted on the tonic of the scale, and the degree a third
+    /// above its highest degree is added: a seventh on a triad, a ninth on a
+    /// seventh chord and so on. The new degree takes the accidental of the
+    /// scale tone at that position. When the scale has no such tone, the
+    /// closest scale tone within a double accidental is used instead, flats
+    /// before sharps, skipping the pitch classes already in the chord.
+    ///
+    /// # Arguments
+    /// * `scale` - The scale to take the stacked tone from
+    ///
+    /// # Returns
+    /// An unnamed chord with the stacked degree, or the chord unchanged if it
+    /// is empty, if the new degree would be above the 13th, or if no scale
+    /// tone is close enough
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{MAJOR, MAJOR_NINTH, MAJOR_SEVENTH_CHORD, MAJOR_TRIAD};
+    ///
+    /// assert_eq!(MAJOR_TRIAD.stack_thirds(MAJOR), MAJOR_SEVENTH_CHORD);
+    /// assert_eq!(MAJOR_TRIAD.stack_thirds(MAJOR).stack_thirds(MAJOR), MAJOR_NINTH);
+    /// ```
+    pub fn stack_thirds(&self, scale: Scale) -> Chord {
+        let Some(highest) = self.degrees().map(|degree| degree.degree()).max() else {
+            return *self;
+        };
+        let Some(natural) = Interval::from_degree(&Degree::new(highest + 2, DEGREE_NATURAL)) else {
+            return *self;
+        };
+
+        let chroma = scale.chroma();
+        let chord_set = self.semitone_set();
+        let stacked = [0i8, -1, 1, -2, 2].into_iter().find_map(|offset| {
+            let pitch_class = (natural.inner() as i8 + offset).rem_euclid(12);
+            let in_scale = chroma & (1 << pitch_class) != 0;
+            let in_chord = chord_set & (1 << pitch_class) != 0;
+            (in_scale && !in_chord)
+                .then(|| DegreeAccidental::from_semitone_offset(offset))
+                .flatten()
+        });
+
+        match stacked {
+            Some(accidental) => {
+                let index = highest as usize + 1;
+                Chord::new("", self.degrees.set_item(index, accidental as u8))
+            }
+            None => *self,
+        }
+    }
+
+    /// Returns the chord with its degrees respelled enharmonically
+    ///
+    /// Double-flat and double-sharp degrees are respelled as the neighbouring
+    /// degree with a single accidental or none (a ♭♭7 becomes a 6), and an
+    /// augmented fifth is respelled as a minor sixth. A degree is only
+    /// respelled when the degree it becomes is not already part of the chord.
+    ///
+    /// # Returns
+    /// A new `Chord` with the same name and the respelled degrees, or `None`
+    /// if no degree of the chord can be respelled
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{AUGMENTED_TRIAD, DIMINISHED_SEVENTH, MAJOR_TRIAD};
+    ///
+    /// let diminished = DIMINISHED_SEVENTH.enharmonic_equivalent().unwrap();
+    /// assert_eq!(diminished.to_string(), "R-♭3-♭5-6");
+    /// let augmented = AUGMENTED_TRIAD.enharmonic_equivalent().unwrap();
+    /// assert_eq!(augmented.to_string(), "R-3-♭6");
+    /// assert_eq!(MAJOR_TRIAD.enharmonic_equivalent(), None);
+    /// ```
+    pub fn enharmonic_equivalent(&self) -> Option<Chord> {
+        let mut respelled = *self;
+        let mut changed = false;
+        for (from, to) in ENHARMONIC_RESPELLINGS {
+            let target = to.degree() as usize - 1;
+            if self.degree_at(from.degree()) == Some(from.accidental())
+                && self.degrees.item(target) == 0
+                && respelled.degrees.item(target) == 0
+            {
+                let degrees = respelled
+                    .remove_degree(from.degree())
+                    .degrees
+                    .set_item(target, to.accidental() as u8);
+                respelled = Chord::new(self.name, degrees);
+                changed = true;
+            }
+        }
+        changed.then_some(respelled)
+    }
+
+    /// Returns the drop-2 voicing of the chord
+    ///
+    /// In a drop-2 voicing the second highest voice of the close-position chord
+    /// is lowered by an octave. Since a `Chord` has no octave information, the
+    /// result is a `VoicedChord` that records which degree is dropped.
+    ///
+    /// # Returns
+    /// A `VoicedChord` with the second highest degree dropped, or with no
+    /// dropped degree if the chord has fewer than two degrees
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::MAJOR_SEVENTH_CHORD;
+    ///
+    /// let voiced = MAJOR_SEVENTH_CHORD.drop2();
+    /// assert_eq!(voiced.dropped_degrees(), &[5]);
+    /// assert_eq!(voiced.voicing(), vec![5, 1, 3, 7]);
+    /// ```
+    pub fn drop2(&self) -> VoicedChord {
+        self.drop_voice(2)
+    }
+
+    /// Puts a degree of the chord in the bass, as in a slash chord
+    ///
+    /// Since a `Chord` has no bass information, the result is a `SlashChord`
+    /// that records the bass degree, see `SlashChord::new`.
+    ///
+    /// # Arguments
+    /// * `bass_degree` - The degree number played in the bass (1-16)
+    ///
+    /// # Panics
+    /// This method will panic if the bass degree is out of range (0 or > 16)
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::MAJOR_TRIAD;
+    ///
+    /// assert_eq!(MAJOR_TRIAD.add_bass(3).to_string(), "R-3-5/3");
+    /// ```
+    #[inline]
+    pub fn add_bass(&self, bass_degree: u8) -> SlashChord {
+        SlashChord::new(*self, bass_degree)
+    }
+
+    /// Returns the drop-3 voicing of the chord
+    ///
+    /// In a drop-3 voicing the third highest voice of the close-position chord
+    /// is lowered by an octave.
+    ///
+    /// # Returns
+    /// A `VoicedChord` with the third highest degree dropped, or with no
+    /// dropped degree if the chord has fewer than three degrees
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::DOMINANT_SEVENTH;
+    ///
+    /// let voiced = DOMINANT_SEVENTH.drop3();
+    /// assert_eq!(voiced.dropped_degrees(), &[3]);
+    /// assert_eq!(voiced.voicing(), vec![3, 1, 5, 7]);
+    /// ```
+    pub fn drop3(&self) -> VoicedChord {
+        self.drop_voice(3)
+    }
+
+    /// Builds a voicing where the n-th highest degree (1 is the top voice) is dropped
+    fn drop_voice(&self, from_top: usize) -> VoicedChord {
+        let dropped_degrees = self
+            .degrees()
+            .map(|degree| degree.degree())
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .nth(from_top - 1)
+            .into_iter()
+            .collect();
+
+        VoicedChord {
+            chord: *self,
+            dropped_degrees,
+        }
+    }
+
+    /// Returns the intervals of the chord in close position
+    ///
+    /// The intervals above the root are sorted in ascending order, which packs
+    /// the voices as closely as possible. Degrees without an interval (above
+    /// the 13th) are skipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_SEVENTH_CHORD};
+    ///
+    /// let voicing: Vec<u8> = MAJOR_SEVENTH_CHORD
+    ///     .tight_voicing()
+    ///     .iter()
+    ///     .map(Interval::inner)
+    ///     .collect();
+    /// assert_eq!(voicing, vec![0, 4, 7, 11]);
+    /// ```
+    pub fn tight_voicing(&self) -> Vec<Interval> {
+        let mut intervals = self.to_intervals().collect::<Vec<_>>();
+        intervals.sort_by_key(Interval::inner);
+        intervals
+    }
+
+    /// Returns true if the chord is built on stacked fourths
+    ///
+    /// The voices of `tight_voicing` must be at least three and each one must
+    /// be a perfect fourth (5 semitones) or an augmented fourth (6 semitones)
+    /// above the previous one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, MAJOR_TRIAD};
+    ///
+    /// assert!(Chord::from_intervals(&[0, 5, 10]).unwrap().is_quartal());
+    /// assert!(!MAJOR_TRIAD.is_quartal());
+    /// ```
+    pub fn is_quartal(&self) -> bool {
+        self.is_stacked(|step| step == 5 || step == 6)
+    }
+
+    /// Returns true if the chord is built on stacked perfect fifths
+    ///
+    /// The voices of `tight_voicing` must be at least three and each one must
+    /// be a perfect fifth (7 semitones) above the previous one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, MAJOR_TRIAD};
+    ///
+    /// assert!(Chord::from_intervals(&[0, 7, 14]).unwrap().is_quintal());
+    /// assert!(!MAJOR_TRIAD.is_quintal());
+    /// ```
+    pub fn is_quintal(&self) -> bool {
+        self.is_stacked(|step| step == 7)
+    }
+
+    /// Returns true if the chord is a tone cluster
+    ///
+    /// The voices of `tight_voicing` must be at least three and each one must
+    /// be at most 2 semitones above the previous one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, MAJOR_TRIAD};
+    ///
+    /// assert!(Chord::from_intervals(&[0, 2, 3]).unwrap().is_cluster());
+    /// assert!(Chord::from_intervals(&[0, 2, 4]).unwrap().is_cluster());
+    /// assert!(!MAJOR_TRIAD.is_cluster());
+    /// ```
+    pub fn is_cluster(&self) -> bool {
+        self.is_stacked(|step| step <= 2)
+    }
+
+    /// Classifies the chord by the interval its voices are stacked in
+    ///
+    /// Chords stacked in thirds (3 or 4 semitones) are `Tertian`, and the
+    /// other types follow `is_quartal`, `is_quintal` and `is_cluster`. Chords
+    /// that mix several intervals, or have fewer than three voices, are `Mixed`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{VoicingType, DOMINANT_NINTH, MAJOR_TRIAD, SUSPENDED_FOURTH};
+    ///
+    /// assert_eq!(MAJOR_TRIAD.voicing_type(), VoicingType::Tertian);
+    /// assert_eq!(DOMINANT_NINTH.voicing_type(), VoicingType::Tertian);
+    /// assert_eq!(SUSPENDED_FOURTH.voicing_type(), VoicingType::Mixed);
+    /// ```
+    pub fn voicing_type(&self) -> VoicingType {
+        if self.is_stacked(|step| step == 3 || step == 4) {
+            VoicingType::Tertian
+        } else if self.is_quartal() {
+            VoicingType::Quartal
+        } else if self.is_quintal() {
+            VoicingType::Quintal
+        } else if self.is_cluster() {
+            VoicingType::Cluster
+        } else {
+            VoicingType::Mixed
+        }
+    }
+
+    /// Returns true if the chord has at least three voices and every step
+    /// between adjacent voices in close position is accepted by `is_step`
+    fn is_stacked(&self, is_step: impl Fn(u8) -> bool) -> bool {
+        let voices = self.tight_voicing();
+        voices.len() >= 3
+            && voices
+                .windows(2)
+                .all(|pair| is_step(pair[1].inner() - pair[0].inner()))
+    }
+
+    /// Returns the intervals of the chord reordered for a spread voicing
+    ///
+    /// The intervals of `tight_voicing` are taken alternately from the bottom
+    /// and from the top of the list. The result lists the voices from the
+    /// lowest to the highest: when each voice is played above the previous one,
+    /// adjacent voices are far apart and the chord spans a wider range than
+    /// in close position.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_SEVENTH_CHORD};
+    ///
+    /// let voicing: Vec<u8> = MAJOR_SEVENTH_CHORD
+    ///     .spread_voicing()
+    ///     .iter()
+    ///     .map(Interval::inner)
+    ///     .collect();
+    /// assert_eq!(voicing, vec![0, 11, 4, 7]);
+    /// ```
+    pub fn spread_voicing(&self) -> Vec<Interval> {
+        let mut tight = std::collections::VecDeque::from(self.tight_voicing());
+        let mut spread = Vec::with_capacity(tight.len());
+        while let Some(low) = tight.pop_front() {
+            spread.push(low);
+            if let Some(high) = tight.pop_back() {
+                spread.push(high);
+            }
+        }
+        spread
+    }
+
+    /// Returns the coarse quality of the chord
+    ///
+    /// The quality is determined by the accidentals of the third, fifth and
+    /// seventh degrees. Extensions (9th, 11th, 13th) and added tones are
+    /// ignored, so a dominant ninth chord is still classified as `Dominant`.
+    /// Chords without a recognizable third/fifth/seventh combination (for
+    /// example power chords or suspended chords) are classified as `Other`.
+    ///
+    /// # Returns
+    /// The `ChordQuality` of the chord
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordQuality, MAJOR_TRIAD, DOMINANT_NINTH, SUSPENDED_FOURTH};
+    ///
+    /// assert_eq!(MAJOR_TRIAD.quality(), ChordQuality::Major);
+    /// assert_eq!(DOMINANT_NINTH.quality(), ChordQuality::Dominant);
+    /// assert_eq!(SUSPENDED_FOURTH.quality(), ChordQuality::Other);
+    /// ```
+    pub fn quality(&self) -> ChordQuality {
+        use DegreeAccidental::{DoubleFlat, Flat, Natural, Sharp};
+
+        let third = self.degree_at(3);
+        let fifth = self.degree_at(5);
+        let seventh = self.degree_at(7);
+
+        match (third, fifth, seventh) {
+            (Some(Natural), Some(Natural), None) => ChordQuality::Major,
+            (Some(Flat), Some(Natural), None) => ChordQuality::Minor,
+            (Some(Flat), Some(Flat), None) => ChordQuality::Diminished,
+            (Some(Natural), Some(Sharp), None) => ChordQuality::Augmented,
+            (Some(Natural), Some(Natural), Some(Flat)) => ChordQuality::Dominant,
+            (Some(Flat), Some(Flat), Some(Flat)) => ChordQuality::HalfDiminished,
+            (Some(Flat), Some(Flat), Some(DoubleFlat)) => ChordQuality::DiminishedSeventh,
+            (Some(Natural), Some(Natural), Some(Natural)) => ChordQuality::MajorSeventh,
+            (Some(Flat), Some(Natural), Some(Flat)) => ChordQuality::MinorSeventh,
+            _ => ChordQuality::Other,
+        }
+    }
+
+    /// Formats the chord in LilyPond chord mode notation
+    ///
+    /// The root is written with its LilyPond note name (see
+    /// `PitchClass::to_lilypond`), followed by the modifier of the chord:
+    /// nothing for a major triad, ":m" for a minor triad, ":7" for a dominant
+    /// seventh, ":maj7" for a major seventh, ":dim" for a diminished triad and
+    /// so on. Chords without a standard modifier list their degrees above the
+    /// root instead, with "-" for a flat and "+" for a sharp, as in
+    /// "c:3.5-.7-.9+".
+    ///
+    /// # Arguments
+    /// * `root` - The pitch class of the root of the chord
+    ///
+    /// # Returns
+    /// The chord mode notation of the chord, without the `\chordmode` block
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{PitchClass, DOMINANT_SEVENTH, MAJOR_TRIAD, MINOR_SEVENTH_CHORD};
+    ///
+    /// assert_eq!(DOMINANT_SEVENTH.to_lilypond(PitchClass::G), "g:7");
+    /// assert_eq!(MAJOR_TRIAD.to_lilypond(PitchClass::C), "c");
+    /// assert_eq!(MINOR_SEVENTH_CHORD.to_lilypond(PitchClass::Fs), "fis:m7");
+    /// ```
+    pub fn to_lilypond(&self, root: PitchClass) -> String {
+        let modifier = LILYPOND_MODIFIERS
+            .iter()
+            .find(|(chord, _)| chord == self)
+            .map(|(_, modifier)| modifier.to_string())
+            .unwrap_or_else(|| {
+                let degrees = self
+                    .degrees()
+                    .filter(|degree| degree.degree() > 1)
+                    .map(|degree| {
+                        let alteration = match degree.accidental() {
+                            DegreeAccidental::Natural => "",
+                            DegreeAccidental::Flat => "-",
+                            DegreeAccidental::DoubleFlat => "--",
+                            DegreeAccidental::Sharp => "+",
+                            DegreeAccidental::DoubleSharp => "++",
+                        };
+                        format!("{}{alteration}", degree.degree())
+                    })
+                    .collect::<Vec<_>>();
+                format!(":{}", degrees.join("."))
+            });
+        format!("{}{modifier}", root.to_lilypond())
+    }
+
+    /// Returns common substitutes for the chord
+    ///
+    /// A chord does not carry its root, so each substitute is returned with the
+    /// interval from the root of the chord up to the root of the substitute.
+    /// The substitutions depend on the `quality` of the chord:
+    /// - dominant: the tritone substitution, the related ii chord, the
+    ///   diminished seventh chord a major third above, and the backcycled
+    ///   dominant a fifth above (V7/V)
+    /// - major: the mediant (iii for I), the relative minor (vi for I) and
+    ///   the secondary dominant a fifth above
+    /// - minor: the relative major, the parallel major and the secondary
+    ///   dominant a fifth above
+    /// - half-diminished and diminished: the dominant a major third below,
+    ///   whose upper voices they are
+    /// - augmented: the augmented triads a major third above and below
+    /// - diminished seventh: the other inversions of the symmetric chord, and
+    ///   the dominant ♭9 a major third below
+    ///
+    /// Substitutes for seventh chords are seventh chords themselves, and the
+    /// tritone and backcycled substitutions keep the extensions of the chord.
+    /// Other chords have no substitutes.
+    ///
+    /// # Returns
+    /// The (root offset, substitute chord) pairs, most common first
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{AUGMENTED_FOURTH, DOMINANT_SEVENTH, MAJOR_SIXTH, MAJOR_TRIAD, MINOR_TRIAD};
+    ///
+    /// // D♭7 for G7
+    /// assert!(DOMINANT_SEVENTH
+    ///     .substitutions()
+    ///     .contains(&(AUGMENTED_FOURTH, DOMINANT_SEVENTH)));
+    /// // Am for C
+    /// assert!(MAJOR_TRIAD.substitutions().contains(&(MAJOR_SIXTH, MINOR_TRIAD)));
+    /// ```
+    pub fn substitutions(&self) -> Vec<(Interval, Chord)> {
+        match self.quality() {
+            ChordQuality::Dominant => vec![
+                (AUGMENTED_FOURTH, *self),
+                (PERFECT_FIFTH, MINOR_SEVENTH_CHORD),
+                (MAJOR_THIRD, DIMINISHED_SEVENTH),
+                (PERFECT_FIFTH, *self),
+            ],
+            ChordQuality::Major => vec![
+                (MAJOR_THIRD, MINOR_TRIAD),
+                (MAJOR_SIXTH, MINOR_TRIAD),
+                (PERFECT_FIFTH, DOMINANT_SEVENTH),
+            ],
+            ChordQuality::MajorSeventh => vec![
+                (MAJOR_THIRD, MINOR_SEVENTH_CHORD),
+                (MAJOR_SIXTH, MINOR_SEVENTH_CHORD),
+                (PERFECT_FIFTH, DOMINANT_SEVENTH),
+            ],
+            ChordQuality::Minor => vec![
+                (MINOR_THIRD, MAJOR_TRIAD),
+                (UNISON, MAJOR_TRIAD),
+                (PERFECT_FIFTH, DOMINANT_SEVENTH),
+            ],
+            ChordQuality::MinorSeventh => vec![
+                (MINOR_THIRD, MAJOR_SEVENTH_CHORD),
+                (UNISON, MAJOR_SEVENTH_CHORD),
+                (PERFECT_FIFTH, DOMINANT_SEVENTH),
+            ],
+            ChordQuality::HalfDiminished => vec![(MINOR_SIXTH, DOMINANT_NINTH)],
+            ChordQuality::Diminished => vec![(MINOR_SIXTH, DOMINANT_SEVENTH)],
+            ChordQuality::DiminishedSeventh => vec![
+                (MINOR_SIXTH, DOMINANT_FLAT_NINE),
+                (MINOR_THIRD, DIMINISHED_SEVENTH),
+                (AUGMENTED_FOURTH, DIMINISHED_SEVENTH),
+                (MAJOR_SIXTH, DIMINISHED_SEVENTH),
+            ],
+            ChordQuality::Augmented => vec![
+                (MAJOR_THIRD, AUGMENTED_TRIAD),
+                (MINOR_SIXTH, AUGMENTED_TRIAD),
+            ],
+            ChordQuality::Other => Vec::new(),
+        }
+    }
+
+    /// Returns the accidental of a degree of the chord
+    ///
+    /// This is a constant time lookup, unlike searching `degrees()` for the
+    /// degree number.
+    ///
+    /// # Arguments
+    /// * `degree` - The degree number (1-16)
+    ///
+    /// # Returns
+    /// The accidental of the degree, or `None` if the chord does not contain
+    /// the degree or the number is outside 1-16
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DegreeAccidental, MAJOR_SEVENTH_CHORD, MINOR_TRIAD};
+    ///
+    /// assert_eq!(MAJOR_SEVENTH_CHORD.degree_at(7), Some(DegreeAccidental::Natural));
+    /// assert_eq!(MAJOR_SEVENTH_CHORD.degree_at(9), None);
+    /// assert_eq!(MINOR_TRIAD.degree_at(3), Some(DegreeAccidental::Flat));
+    /// ```
+    #[inline]
+    pub fn degree_at(&self, degree: u8) -> Option<DegreeAccidental> {
+        if degree == 0 || degree as usize > self.degrees.capacity() {
+            return None;
+        }
+        DegreeAccidental::try_from(self.degrees.item(degree as usize - 1)).ok()
+    }
+}
+
+/// Degrees drawn by `Chord::random`: every named chord degree but the root
+#[cfg(feature = "rand")]
+const RANDOM_DEGREES: [Degree; 19] = [
+    Degree::new(2, DEGREE_FLAT),
+    SECOND,
+    FLAT_THIRD,
+    THIRD,
+    FOURTH,
+    FLAT_FIFTH,
+    FIFTH,
+    SHARP_FIFTH,
+    SIXTH,
+    DOUBLEFLAT_SEVENTH,
+    FLAT_SEVENTH,
+    SEVENTH,
+    FLAT_NINTH,
+    NINTH,
+    SHARP_NINTH,
+    ELEVENTH,
+    SHARP_ELEVENTH,
+    FLAT_THIRTEENTH,
+    THIRTEENTH,
+];
+
+/// Tensions drawn by `ChordBuilder::random_extension`
+#[cfg(feature = "rand")]
+const RANDOM_TENSIONS: [Degree; 7] = [
+    FLAT_NINTH,
+    NINTH,
+    SHARP_NINTH,
+    ELEVENTH,
+    SHARP_ELEVENTH,
+    FLAT_THIRTEENTH,
+    THIRTEENTH,
+];
+
+/// Lookup table used by `Chord::normalize` to keep the accidental codes and
+/// clear any other nibble value
+const VALID_ACCIDENTAL_CODES: [u8; 16] = [0, 1, 2, 3, 4, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Enharmonic respellings used by `Chord::enharmonic_equivalent`, as pairs of
+/// (spelling to replace, replacement spelling)
+const ENHARMONIC_RESPELLINGS: [(Degree, Degree); 9] = [
+    (Degree::new(2, DEGREE_DOUBLESHARP), THIRD),
+    (Degree::new(3, DEGREE_DOUBLEFLAT), SECOND),
+    (Degree::new(4, DEGREE_DOUBLESHARP), FIFTH),
+    (Degree::new(5, DEGREE_DOUBLEFLAT), FOURTH),
+    (Degree::new(5, DEGREE_DOUBLESHARP), SIXTH),
+    (Degree::new(6, DEGREE_DOUBLEFLAT), FIFTH),
+    (Degree::new(6, DEGREE_DOUBLESHARP), SEVENTH),
+    (DOUBLEFLAT_SEVENTH, SIXTH),
+    (SHARP_FIFTH, Degree::new(6, DEGREE_FLAT)),
+];
+
+/// Tension of the intervals from a unison up to a major seventh, used by
+/// `Chord::functional_tension`
+const INTERVAL_TENSIONS: [u8; 12] = [0, 100, 60, 20, 10, 15, 90, 5, 25, 15, 50, 80];
+
+/// Checks whether a degree is a chord tension (a 9th, 11th or 13th)
+#[inline]
+const fn is_tension(degree: Degree) -> bool {
+    matches!(degree.degree(), 9 | 11 | 13)
+}
+
+/// Errors that can occur when constructing a `Chord` from runtime data
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::{Chord, ChordError};
+///
+/// assert_eq!(
+///     Chord::from_intervals(&[0, 3, 4]).err(),
+///     Some(ChordError::DegreeConflict(3))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChordError {
+    /// The interval (in semitones) does not correspond to any chord degree
+    UnsupportedInterval(u8),
+    /// Two different intervals map to the same chord degree
+    DegreeConflict(u8),
+    /// The item for the chord degree is not an accidental code
+    InvalidAccidental(u8),
+}
+
+impl Display for ChordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChordError::UnsupportedInterval(interval) => {
+                write!(f, "no chord degree for an interval of {interval} semitones")
+            }
+            ChordError::DegreeConflict(degree) => {
+                write!(f, "two intervals map to the chord degree {degree}")
+            }
+            ChordError::InvalidAccidental(degree) => {
+                write!(f, "no accidental for the code of the chord degree {degree}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChordError {}
+
+impl PartialEq for Chord {
+    /// Two chords are equal when they hold the same degrees, whatever their names
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, MAJOR_TRIAD, THIRD, FIFTH};
+    ///
+    /// let triad = ChordBuilder::with_root("C").set_degree(THIRD).set_degree(FIFTH).build();
+    /// assert_eq!(triad, MAJOR_TRIAD);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.degrees == other.degrees
+    }
+}
+
+impl Eq for Chord {}
+
+impl Hash for Chord {
+    /// Hashes the degrees of the chord, consistently with `PartialEq`
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.degrees.hash(state);
+    }
+}
+
+impl PartialOrd for Chord {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Chord {
+    /// Orders chords by complexity
+    ///
+    /// Chords with fewer degrees sort first. Chords with the same number of
+    /// degrees are ordered by the numeric value of their degree pattern.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DOMINANT_SEVENTH, FIFTH_CHORD, MAJOR_TRIAD};
+    ///
+    /// assert!(FIFTH_CHORD < MAJOR_TRIAD);
+    /// assert!(MAJOR_TRIAD < DOMINANT_SEVENTH);
+    /// ```
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.degree_count()
+            .cmp(&other.degree_count())
+            .then_with(|| self.degrees.inner().cmp(&other.degrees.inner()))
+    }
+}
+
+impl Default for Chord {
+    /// Returns the major triad, the conventional default chord
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, MAJOR_TRIAD};
+    /// assert_eq!(Chord::default(), MAJOR_TRIAD);
+    /// ```
+    fn default() -> Self {
+        MAJOR_TRIAD
+    }
+}
+
+impl From<Chord> for U4Vec16 {
+    /// Converts a `Chord` to its underlying `U4Vec16` of degree accidentals
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_bitflags::U4Vec16;
+    /// use muzze_std::MAJOR_TRIAD;
+    ///
+    /// let degrees = U4Vec16::from(MAJOR_TRIAD);
+    /// assert_eq!(degrees.inner(), 0x10101);
+    /// ```
+    #[inline]
+    fn from(chord: Chord) -> Self {
+        chord.degrees
+    }
+}
+
+impl TryFrom<U4Vec16> for Chord {
+    type Error = ChordError;
+
+    /// Converts a `U4Vec16` of degree accidentals to an unnamed `Chord`
+    ///
+    /// # Returns
+    /// * `Ok(Chord)` - When every non-zero item is an accidental code (1-5)
+    /// * `Err(ChordError::InvalidAccidental(degree))` - For the first degree
+    ///   whose item is not an accidental code
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_bitflags::U4Vec16;
+    /// use muzze_std::{Chord, ChordError};
+    ///
+    /// let chord = Chord::try_from(U4Vec16::from_u64(0x10201)).unwrap();
+    /// assert_eq!(chord.to_string(), "R-♭3-5");
+    /// assert_eq!(chord.name(), "");
+    ///
+    /// assert_eq!(
+    ///     Chord::try_from(U4Vec16::from_u64(0x10901)),
+    ///     Err(ChordError::InvalidAccidental(3))
+    /// );
+    /// ```
+    fn try_from(degrees: U4Vec16) -> Result<Self, Self::Error> {
+        for (index, code) in degrees.iter_items().enumerate() {
+            if code != 0 && DegreeAccidental::try_from(code).is_err() {
+                return Err(ChordError::InvalidAccidental(index as u8 + 1));
+            }
+        }
+        Ok(Self::new("", degrees))
+    }
+}
+
+impl FromIterator<Degree> for Chord {
+    /// Collects degrees into an unnamed `Chord`
+    ///
+    /// The degrees are set in iterator order, so a later degree with the same
+    /// number overwrites an earlier one, just like `ChordBuilder::set_degree`.
+    /// As with `ChordBuilder::with_root`, the root is always part of the chord.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Chord, ROOT, FLAT_THIRD, FIFTH};
+    ///
+    /// let chord: Chord = vec![ROOT, FLAT_THIRD, FIFTH].into_iter().collect();
+    /// assert_eq!(chord.to_string(), "R-♭3-5");
+    /// ```
+    fn from_iter<T: IntoIterator<Item = Degree>>(iter: T) -> Self {
+        iter.into_iter()
+            .fold(ChordBuilder::with_root(""), ChordBuilder::set_degree)
+            .build()
+    }
+}
+
+/// Represents a chord together with the degrees that are dropped an octave
+///
+/// A `VoicedChord` is produced by the drop voicing transformations of
+/// [`Chord`], such as [`Chord::drop2`] and [`Chord::drop3`]. The chord keeps its
+/// abstract degrees, while the dropped degrees are played an octave lower than
+/// in the close-position voicing.
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::MINOR_SEVENTH_CHORD;
+///
+/// let voiced = MINOR_SEVENTH_CHORD.drop2();
+/// assert!(voiced.is_dropped(5));
+/// assert_eq!(voiced.chord().to_string(), "R-♭3-5-♭7");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VoicedChord {
+    chord: Chord,
+    dropped_degrees: Vec<u8>,
+}
+
+impl VoicedChord {
+    /// Returns the chord being voiced
+    #[inline]
+    pub fn chord(&self) -> &Chord {
+        &self.chord
+    }
+
+    /// Returns the degree numbers that are dropped an octave, in ascending order
+    #[inline]
+    pub fn dropped_degrees(&self) -> &[u8] {
+        &self.dropped_degrees
+    }
+
+    /// Checks whether the given degree number is dropped an octave
+    ///
+    /// # Arguments
+    /// * `degree` - The degree number to check
+    ///
+    /// # Returns
+    /// `true` if the degree is played an octave lower
+    #[inline]
+    pub fn is_dropped(&self, degree: u8) -> bool {
+        self.dropped_degrees.contains(&degree)
+    }
+
+    /// Returns the degree numbers ordered from the lowest to the highest voice
+    ///
+    /// The dropped degrees come first, followed by the remaining degrees in
+    /// close position.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::DOMINANT_NINTH;
+    ///
+    /// assert_eq!(DOMINANT_NINTH.drop2().voicing(), vec![7, 1, 3, 5, 9]);
+    /// ```
+    pub fn voicing(&self) -> Vec<u8> {
+        let (dropped, close): (Vec<u8>, Vec<u8>) = self
+            .chord
+            .degrees()
+            .map(|degree| degree.degree())
+            .partition(|degree| self.is_dropped(*degree));
+        dropped.into_iter().chain(close).collect()
+    }
+}
+
+/// A chord with an explicit degree in the bass, as in "C/E"
+///
+/// The bass degree is either one of the chord tones, which makes the slash
+/// chord an inversion, or a degree outside of the chord, which makes it a
+/// pedal point.
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::{SlashChord, MAJOR_TRIAD};
+///
+/// let first_inversion = SlashChord::new(MAJOR_TRIAD, 3);
+/// assert_eq!(first_inversion.to_string(), "R-3-5/3");
+/// assert!(first_inversion.is_inversion());
+///
+/// let pedal = SlashChord::new(MAJOR_TRIAD, 2);
+/// assert!(pedal.is_pedal_point());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlashChord {
+    chord: Chord,
+    bass_degree: u8,
+}
+
+impl SlashChord {
+    /// Creates a new slash chord from a chord and the degree played in the bass
+    ///
+    /// # Arguments
+    /// * `chord` - The chord played above the bass
+    /// * `bass_degree` - The degree number played in the bass (1-16)
+    ///
+    /// # Panics
+    /// This method will panic if the bass degree is out of range (0 or > 16)
+    pub fn new(chord: Chord, bass_degree: u8) -> SlashChord {
+        assert!(
+            (1..=16).contains(&bass_degree),
+            "bass degree out of range: {bass_degree}"
+        );
+        Self { chord, bass_degree }
+    }
+
+    /// Returns the chord played above the bass
+    #[inline]
+    pub fn chord(&self) -> &Chord {
+        &self.chord
+    }
+
+    /// Returns the degree number played in the bass
+    #[inline]
+    pub fn bass_degree(&self) -> u8 {
+        self.bass_degree
+    }
+
+    /// Checks whether the bass is one of the chord tones
+    ///
+    /// # Returns
+    /// `true` if the bass degree is part of the chord
+    #[inline]
+    pub fn is_inversion(&self) -> bool {
+        self.chord.degree_at(self.bass_degree).is_some()
+    }
+
+    /// Checks whether the bass is a degree outside of the chord
+    ///
+    /// # Returns
+    /// `true` if the bass degree is not part of the chord
+    #[inline]
+    pub fn is_pedal_point(&self) -> bool {
+        !self.is_inversion()
+    }
+}
+
+impl Display for SlashChord {
+    /// Formats the slash chord as the chord followed by its bass degree
+    ///
+    /// When the bass is a chord tone it is shown with its accidental, as in
+    /// the chord itself; otherwise only the degree number is shown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{MINOR_TRIAD, SlashChord};
+    ///
+    /// assert_eq!(SlashChord::new(MINOR_TRIAD, 3).to_string(), "R-♭3-5/♭3");
+    /// assert_eq!(SlashChord::new(MINOR_TRIAD, 4).to_string(), "R-♭3-5/4");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.chord.degree_at(self.bass_degree) {
+            Some(accidental) => write!(
+                f,
+                "{}/{}",
+                self.chord,
+                Degree::new(self.bass_degree, accidental)
+            ),
+            None => write!(f, "{}/{}", self.chord, self.bass_degree),
+        }
+    }
+}
+
+/// Represents the coarse quality of a chord
+///
+/// The quality summarizes the sound of a chord from its third, fifth and
+/// seventh degrees. It is returned by [`Chord::quality`].
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::{ChordQuality, MINOR_SEVENTH_CHORD};
+///
+/// let quality = MINOR_SEVENTH_CHORD.quality();
+/// assert_eq!(quality, ChordQuality::MinorSeventh);
+/// assert_eq!(format!("{}", quality), "m7");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChordQuality {
+    /// Major third and perfect fifth
+    Major,
+    /// Minor third and perfect fifth
+    Minor,
+    /// Minor third and diminished fifth
+    Diminished,
+    /// Major third and augmented fifth
+    Augmented,
+    /// Major third, perfect fifth and minor seventh
+    Dominant,
+    /// Minor third, diminished fifth and minor seventh
+    HalfDiminished,
+    /// Minor third, diminished fifth and diminished seventh
+    DiminishedSeventh,
+    /// Major third, perfect fifth and major seventh
+    MajorSeventh,
+    /// Minor third, perfect fifth and minor seventh
+    MinorSeventh,
+    /// Any other combination of degrees
+    Other,
+}
+
+impl Display for ChordQuality {
+    /// Formats the chord quality as its short abbreviation
+    ///
+    /// The abbreviations are the suffixes of lead-sheet chord symbols, so a
+    /// minor seventh is "m7" next to the "m7♭5" of the half-diminished seventh.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::ChordQuality;
+    ///
+    /// assert_eq!(format!("{}", ChordQuality::Major), "maj");
+    /// assert_eq!(format!("{}", ChordQuality::Dominant), "7");
+    /// assert_eq!(format!("{}", ChordQuality::MinorSeventh), "m7");
+    /// assert_eq!(format!("{}", ChordQuality::HalfDiminished), "m7♭5");
+    /// assert_eq!(format!("{}", ChordQuality::DiminishedSeventh), "dim7");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChordQuality::Major => write!(f, "maj"),
+            ChordQuality::Minor => write!(f, "m"),
+            ChordQuality::Diminished => write!(f, "dim"),
+            ChordQuality::Augmented => write!(f, "aug"),
+            ChordQuality::Dominant => write!(f, "7"),
+            ChordQuality::HalfDiminished => write!(f, "m7♭5"),
+            ChordQuality::DiminishedSeventh => write!(f, "dim7"),
+            ChordQuality::MajorSeventh => write!(f, "maj7"),
+            ChordQuality::MinorSeventh => write!(f, "m7"),
+            ChordQuality::Other => write!(f, "other"),
+        }
+    }
+}
+
+/// Represents the shape of a chord built by stacking scale tones
+///
+/// A `ChordType` describes which tones of a scale are stacked above a scale
+/// degree to harmonize it, as done by [`Scale::get_chord_on_degree`](crate::Scale::get_chord_on_degree).
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::{ChordType, MAJOR, DOMINANT_SEVENTH};
+///
+/// let chord = MAJOR.get_chord_on_degree(4, ChordType::Seventh);
+/// assert_eq!(chord, Some(DOMINANT_SEVENTH));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChordType {
+    /// Root, third and fifth (stacked thirds)
+    Triad,
+    /// Root, third, fifth and seventh
+    Seventh,
+    /// Root, third, fifth, seventh and ninth
+    Ninth,
+    /// Root and fifth only (a power chord)
+    OpenFifth,
+}
+
+/// Represents the interval the voices of a chord are stacked in
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::{Chord, VoicingType};
+///
+/// let so_what = Chord::from_intervals(&[0, 5, 10, 15]).unwrap();
+/// assert_eq!(so_what.voicing_type(), VoicingType::Quartal);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoicingType {
+    /// Stacked thirds, as in traditional triads and seventh chords
+    Tertian,
+    /// Stacked fourths
+    Quartal,
+    /// Stacked perfect fifths
+    Quintal,
+    /// Voices at most a whole step apart
+    Cluster,
+    /// Any other combination of intervals
+    Mixed,
+}
+
+impl ChordType {
+    /// Returns the chord degrees of the chord type and their zero-based
+    /// positions in the scale, relative to the degree being harmonized
+    #[inline]
+    pub(crate) const fn stack(&self) -> &'static [(u8, usize)] {
+        match self {
+            ChordType::Triad => &[(1, 0), (3, 2), (5, 4)],
+            ChordType::Seventh => &[(1, 0), (3, 2), (5, 4), (7, 6)],
+            ChordType::Ninth => &[(1, 0), (3, 2), (5, 4), (7, 6), (9, 8)],
+            ChordType::OpenFifth => &[(1, 0), (5, 4)],
+        }
+    }
+}
+
+impl Display for Chord {
+    /// Formats the chord as its string representation
+    ///
+    /// The chord is displayed as a hyphen-separated list of degrees,
+    /// where the root degree shows as "R" and other degrees show their
+    /// accidental (if any) followed by the degree number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{MAJOR_TRIAD, MINOR_TRIAD, DOMINANT_SEVENTH};
+    ///
+    /// assert_eq!(format!("{}", MAJOR_TRIAD), "R-3-5");
+    /// assert_eq!(format!("{}", MINOR_TRIAD), "R-♭3-5");
+    /// assert_eq!(format!("{}", DOMINANT_SEVENTH), "R-3-5-♭7");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_degree_string())
+    }
+}
+
+chord_const!(
+    MAJOR_TRIAD,
+    "major triad",
+    "A major triad consists of a root, major third, and perfect fifth. This is the most fundamental major chord and forms the basis of major harmony in Western music.",
+    "Major (bright, stable sound)",
+    "R-3-5",
+    [THIRD, FIFTH]
+);
+
+chord_const!(
+    MINOR_TRIAD,
+    "minor triad",
+    "A minor triad consists of a root, minor third, and perfect fifth. This is the most fundamental minor chord and forms the basis of minor harmony in Western music.",
+    "Minor (dark, melancholic sound)",
+    "R-♭3-5",
+    [FLAT_THIRD, FIFTH]
+);
+
+chord_const!(
+    DIMINISHED_TRIAD,
+    "diminished triad",
+    "A diminished triad consists of a root, minor third, and diminished fifth. This chord creates a very tense, unstable sound and is commonly used as a passing chord or in diminished harmony.",
+    "Diminished (tense, unstable sound)",
+    "R-♭3-♭5",
+    [FLAT_THIRD, FLAT_FIFTH]
+);
+
+chord_const!(
+    AUGMENTED_TRIAD,
+    "augmented triad",
+    "An augmented triad consists of a root, major third, and augmented fifth. This chord creates a bright, tense sound and is commonly used in augmented harmony and as a passing chord.",
+    "Augmented (bright, tense sound)",
+    "R-3-♯5",
+    [THIRD, SHARP_FIFTH]
+);
+
+chord_const!(
+    MAJOR_SEVENTH_CHORD,
+    "major seventh chord",
+    "A major seventh chord consists of a root, major third, perfect fifth, and major seventh. This chord has a sophisticated, jazzy sound and is commonly used in jazz and contemporary music.",
+    "Major 7th (sophisticated, jazzy sound)",
+    "R-3-5-7",
+    [THIRD, FIFTH, SEVENTH]
+);
+
+chord_const!(
+    MINOR_SEVENTH_CHORD,
+    "minor seventh chord",
+    "A minor seventh chord consists of a root, minor third, perfect fifth, and minor seventh. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
+    "Minor 7th (mellow, bluesy sound)",
+    "R-♭3-5-♭7",
+    [FLAT_THIRD, FIFTH, FLAT_SEVENTH]
+);
+
+chord_const!(
+    DOMINANT_SEVENTH,
+    "dominant seventh chord",
+    "A dominant seventh chord consists of a root, major third, perfect fifth, and minor seventh. This chord has a strong, bluesy sound and is commonly used as a dominant chord in functional harmony.",
+    "Dominant 7th (strong, bluesy sound)",
+    "R-3-5-♭7",
+    [THIRD, FIFTH, FLAT_SEVENTH]
+);
+
+chord_const!(
+    HALF_DIMINISHED_SEVENTH,
+    "half-diminished seventh chord",
+    "A half-diminished seventh chord consists of a root, minor third, diminished fifth, and minor seventh. This chord has a tense, unstable sound and is commonly used as a minor ii chord in functional harmony.",
+    "Half-diminished 7th (tense, unstable sound)",
+    "R-♭3-♭5-♭7",
+    [FLAT_THIRD, FLAT_FIFTH, FLAT_SEVENTH]
+);
+
+chord_const!(
+    DIMINISHED_SEVENTH,
+    "diminished seventh chord",
+    "A diminished seventh chord consists of a root, minor third, diminished fifth, and diminished seventh. This chord has a very tense, unstable sound and is commonly used as a passing chord or in diminished harmony.",
+    "Diminished 7th (very tense, unstable sound)",
+    "R-♭3-♭5-♭♭7",
+    [FLAT_THIRD, FLAT_FIFTH, DOUBLEFLAT_SEVENTH]
+);
+
+chord_const!(
+    AUGMENTED_SEVENTH,
+    "augmented seventh chord",
+    "An augmented seventh chord consists of a root, major third, augmented fifth, and minor seventh. This chord has a bright, tense sound and is commonly used in augmented harmony and as a passing chord.",
+    "Augmented 7th (bright, tense sound)",
+    "R-3-♯5-♭7",
+    [THIRD, SHARP_FIFTH, FLAT_SEVENTH]
+);
+
+chord_const!(
+    MINOR_MAJOR_SEVENTH,
+    "minor major seventh chord",
+    "A minor major seventh chord consists of a root, minor third, perfect fifth, and major seventh. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
+    "Minor major 7th (mellow, bluesy sound)",
+    "R-♭3-5-7",
+    [FLAT_THIRD, FIFTH, SEVENTH]
+);
+
+chord_const!(
+    SIXTH_CHORD,
+    "sixth chord",
+    "A sixth chord consists of a root, major third, and sixth. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
+    "Sixth (mellow, bluesy sound)",
     "R-3-5-6",
     [THIRD, FIFTH, SIXTH]
 );
 
-chord_const!(
-    SIXTH_MINOR_CHORD,
-    "sixth minor chord",
-    "A sixth minor chord consists of a root, minor third, and sixth. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
-    "Sixth minor (mellow, bluesy sound)",
-    "R-♭3-5-6",
-    [FLAT_THIRD, FIFTH, SIXTH]
-);
+chord_const!(
+    SIXTH_MINOR_CHORD,
+    "sixth minor chord",
+    "A sixth minor chord consists of a root, minor third, and sixth. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
+    "Sixth minor (mellow, bluesy sound)",
+    "R-♭3-5-6",
+    [FLAT_THIRD, FIFTH, SIXTH]
+);
+
+chord_const!(
+    SIXTH_NINTH_CHORD,
+    "sixth ninth chord",
+    "A sixth ninth chord consists of a root, major third, and ninth. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
+    "Sixth ninth (mellow, bluesy sound)",
+    "R-3-5-6-9",
+    [THIRD, FIFTH, SIXTH, NINTH]
+);
+
+chord_const!(
+    FIFTH_CHORD,
+    "fifth chord",
+    "A fifth chord consists of a root, perfect fifth, and perfect sixth. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
+    "Fifth (mellow, bluesy sound)",
+    "R-5",
+    [FIFTH]
+);
+
+chord_const!(
+    DOMINANT_NINTH,
+    "dominant ninth chord",
+    "A dominant ninth chord consists of a root, major third, perfect fifth, and minor seventh. This chord has a strong, bluesy sound and is commonly used as a dominant chord in functional harmony.",
+    "Dominant 9th (strong, bluesy sound)",
+    "R-3-5-♭7-9",
+    [THIRD, FIFTH, FLAT_SEVENTH, NINTH]
+);
+
+chord_const!(
+    MINOR_NINTH,
+    "minor ninth chord",
+    "A minor ninth chord consists of a root, minor third, perfect fifth, and minor seventh. This chord has a mellow, bluesy sound and is commonly used as a minor chord in functional harmony.",
+    "Minor 9th (mellow, bluesy sound)",
+    "R-♭3-5-♭7-9",
+    [FLAT_THIRD, FIFTH, FLAT_SEVENTH, NINTH]
+);
+
+chord_const!(
+    MAJOR_NINTH,
+    "major ninth chord",
+    "A major ninth chord consists of a root, major third, perfect fifth, and major seventh. This chord has a bright, tense sound and is commonly used as a major chord in functional harmony.",
+    "Major 9th (bright, tense sound)",
+    "R-3-5-7-9",
+    [THIRD, FIFTH, SEVENTH, NINTH]
+);
+
+chord_const!(
+    ELEVENTH_CHORD,
+    "eleventh chord",
+    "An eleventh chord consists of a root, major third, perfect fifth, major seventh, and eleventh. This chord has a bright, tense sound and is commonly used in functional harmony.",
+    "Eleventh (bright, tense sound)",
+    "R-3-5-7-11",
+    [THIRD, FIFTH, SEVENTH, NINTH, ELEVENTH]
+);
+
+chord_const!(
+    MINOR_ELEVENTH,
+    "minor eleventh chord",
+    "A minor eleventh chord consists of a root, minor third, perfect fifth, minor seventh, and eleventh. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
+    "Minor 11th (mellow, bluesy sound)",
+    "R-♭3-5-♭7-11",
+    [FLAT_THIRD, FIFTH, FLAT_SEVENTH, NINTH, ELEVENTH]
+);
+
+chord_const!(
+    THIRTEENTH_CHORD,
+    "thirteenth chord",
+    "A thirteenth chord consists of a root, major third, perfect fifth, minor seventh, eleventh, and thirteenth. This chord has a bright, tense sound and is commonly used in functional harmony.",
+    "Thirteenth (bright, tense sound)",
+    "R-3-5-♭7-11-13",
+    [THIRD, FIFTH, FLAT_SEVENTH, NINTH, ELEVENTH, THIRTEENTH]
+);
+
+chord_const!(
+    MINOR_THIRTEENTH,
+    "minor thirteenth chord",
+    "A minor thirteenth chord consists of a root, minor third, perfect fifth, minor seventh, eleventh, and thirteenth. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
+    "Minor 13th (mellow, bluesy sound)",
+    "R-♭3-5-♭7-11-13",
+    [FLAT_THIRD, FIFTH, FLAT_SEVENTH, NINTH, ELEVENTH, THIRTEENTH]
+);
+
+chord_const!(
+    MAJOR_THIRTEENTH,
+    "major thirteenth chord",
+    "A major thirteenth chord consists of a root, major third, perfect fifth, minor seventh, eleventh, and thirteenth. This chord has a bright, tense sound and is commonly used in functional harmony.",
+    "Major 13th (bright, tense sound)",
+    "R-3-5-7-11-13",
+    [THIRD, FIFTH, SEVENTH, NINTH, ELEVENTH, THIRTEENTH]
+);
+
+chord_const!(
+    MAJOR_ELEVENTH,
+    "major eleventh chord",
+    "A major eleventh chord consists of a root, major third, perfect fifth, major seventh, and eleventh. This chord has a bright, tense sound and is commonly used in functional harmony.",
+    "Major 11th (bright, tense sound)",
+    "R-3-5-7-11",
+    [THIRD, FIFTH, SEVENTH, NINTH, ELEVENTH]
+);
+
+chord_const!(
+    SUSPENDED_SECOND,
+    "suspended second chord",
+    "A suspended second chord consists of a root, perfect second, and perfect fifth. This chord has a suspended, unresolved sound and is commonly used in contemporary music and jazz.",
+    "Suspended 2nd (suspended, unresolved sound)",
+    "R-2-5",
+    [SECOND, FIFTH]
+);
+
+chord_const!(
+    SUSPENDED_FOURTH,
+    "suspended fourth chord",
+    "A suspended fourth chord consists of a root, perfect fourth, and perfect fifth. This chord has a suspended, unresolved sound and is commonly used in contemporary music and jazz.",
+    "Suspended 4th (suspended, unresolved sound)",
+    "R-4-5",
+    [FOURTH, FIFTH]
+);
+
+chord_const!(
+    ADDED_SECOND,
+    "added second chord",
+    "A added second chord consists of a root, perfect second, and perfect fifth. This chord has a bright, tense sound and is commonly used in functional harmony.",
+    "Added 2nd (bright, tense sound)",
+    "R-2-3-5",
+    [SECOND, THIRD, FIFTH]
+);
+
+chord_const!(
+    ADDED_NINTH,
+    "added ninth chord",
+    "A added ninth chord consists of a root, major third, perfect fifth, and ninth. This chord has a bright, tense sound and is commonly used in functional harmony.",
+    "Added 9th (bright, tense sound)",
+    "R-3-5-9",
+    [THIRD, FIFTH, NINTH]
+);
+
+chord_const!(
+    ADDED_ELEVENTH,
+    "added eleventh chord",
+    "A added eleventh chord consists of a root, major third, perfect fifth, major seventh, and eleventh. This chord has a bright, tense sound and is commonly used in functional harmony.",
+    "Added 11th (bright, tense sound)",
+    "R-3-5-11",
+    [THIRD, FIFTH, ELEVENTH]
+);
+
+chord_const!(
+    DOMINANT_SEVENTH_FLAT_FIVE,
+    "dominant seventh flat five chord",
+    "A dominant seventh flat five chord consists of a root, major third, flat fifth, and flat seventh. This chord has a strong, bluesy sound and is commonly used as a dominant chord in functional harmony.",
+    "Dominant 7th flat 5 (strong, bluesy sound)",
+    "R-3-♭5-♭7",
+    [THIRD, FLAT_FIFTH, FLAT_SEVENTH]
+);
+
+chord_const!(
+    DOMINANT_SEVENTH_SHARP_FIVE,
+    "dominant seventh sharp five chord",
+    "A dominant seventh sharp five chord consists of a root, major third, sharp fifth, and flat seventh. This chord has a strong, bluesy sound and is commonly used as a dominant chord in functional harmony.",
+    "Dominant 7th sharp 5 (strong, bluesy sound)",
+    "R-3-♯5-♭7",
+    [THIRD, SHARP_FIFTH, FLAT_SEVENTH]
+);
+
+chord_const!(
+    DOMINANT_FLAT_NINE,
+    "dominant seventh flat nine chord",
+    "A dominant seventh flat nine chord consists of a root, major third, perfect fifth, flat seventh, and flat ninth. This chord has a dark, tense sound and is commonly used as the dominant of a minor key.",
+    "Dominant 7th flat 9 (dark, tense sound)",
+    "R-3-5-♭7-♭9",
+    [THIRD, FIFTH, FLAT_SEVENTH, FLAT_NINTH]
+);
+
+chord_const!(
+    DOMINANT_SHARP_NINE,
+    "dominant seventh sharp nine chord",
+    "A dominant seventh sharp nine chord consists of a root, major third, perfect fifth, flat seventh, and sharp ninth. Known as the Hendrix chord, it clashes the major third with the minor third above it for a gritty, bluesy sound.",
+    "Dominant 7th sharp 9 (gritty, bluesy sound)",
+    "R-3-5-♭7-♯9",
+    [THIRD, FIFTH, FLAT_SEVENTH, SHARP_NINTH]
+);
+
+chord_const!(
+    DOMINANT_SHARP_ELEVEN,
+    "dominant seventh sharp eleven chord",
+    "A dominant seventh sharp eleven chord consists of a root, major third, perfect fifth, flat seventh, and sharp eleventh. It is the chord of the lydian dominant scale, often used for non-resolving dominants.",
+    "Dominant 7th sharp 11 (bright, lydian dominant sound)",
+    "R-3-5-♭7-♯11",
+    [THIRD, FIFTH, FLAT_SEVENTH, SHARP_ELEVENTH]
+);
+
+chord_const!(
+    DOMINANT_FLAT_THIRTEEN,
+    "dominant seventh flat thirteen chord",
+    "A dominant seventh flat thirteen chord consists of a root, major third, perfect fifth, flat seventh, and flat thirteenth. This chord has a dark, mysterious sound and is commonly used as a dominant resolving to a minor chord.",
+    "Dominant 7th flat 13 (dark, mysterious sound)",
+    "R-3-5-♭7-♭13",
+    [THIRD, FIFTH, FLAT_SEVENTH, FLAT_THIRTEENTH]
+);
+
+/// LilyPond chord mode modifiers of the chords with a standard notation
+const LILYPOND_MODIFIERS: [(Chord, &str); 22] = [
+    (MAJOR_TRIAD, ""),
+    (MINOR_TRIAD, ":m"),
+    (DIMINISHED_TRIAD, ":dim"),
+    (AUGMENTED_TRIAD, ":aug"),
+    (DOMINANT_SEVENTH, ":7"),
+    (MAJOR_SEVENTH_CHORD, ":maj7"),
+    (MINOR_SEVENTH_CHORD, ":m7"),
+    (HALF_DIMINISHED_SEVENTH, ":m7.5-"),
+    (DIMINISHED_SEVENTH, ":dim7"),
+    (AUGMENTED_SEVENTH, ":aug7"),
+    (MINOR_MAJOR_SEVENTH, ":m7+"),
+    (SIXTH_CHORD, ":6"),
+    (SIXTH_MINOR_CHORD, ":m6"),
+    (SIXTH_NINTH_CHORD, ":6.9"),
+    (FIFTH_CHORD, ":1.5"),
+    (DOMINANT_NINTH, ":9"),
+    (MAJOR_NINTH, ":maj9"),
+    (MINOR_NINTH, ":m9"),
+    (ELEVENTH_CHORD, ":11"),
+    (THIRTEENTH_CHORD, ":13"),
+    (SUSPENDED_SECOND, ":sus2"),
+    (SUSPENDED_FOURTH, ":sus4"),
+];
+
+/// Catalog of the predefined chords returned by `Chord::all_predefined`
+const PREDEFINED_CHORDS: [(&str, Chord); 35] = [
+    ("Major Triad", MAJOR_TRIAD),
+    ("Minor Triad", MINOR_TRIAD),
+    ("Diminished Triad", DIMINISHED_TRIAD),
+    ("Augmented Triad", AUGMENTED_TRIAD),
+    ("Major Seventh", MAJOR_SEVENTH_CHORD),
+    ("Minor Seventh", MINOR_SEVENTH_CHORD),
+    ("Dominant Seventh", DOMINANT_SEVENTH),
+    ("Half-Diminished Seventh", HALF_DIMINISHED_SEVENTH),
+    ("Diminished Seventh", DIMINISHED_SEVENTH),
+    ("Augmented Seventh", AUGMENTED_SEVENTH),
+    ("Minor Major Seventh", MINOR_MAJOR_SEVENTH),
+    ("Sixth", SIXTH_CHORD),
+    ("Minor Sixth", SIXTH_MINOR_CHORD),
+    ("Six Nine", SIXTH_NINTH_CHORD),
+    ("Fifth", FIFTH_CHORD),
+    ("Dominant Ninth", DOMINANT_NINTH),
+    ("Minor Ninth", MINOR_NINTH),
+    ("Major Ninth", MAJOR_NINTH),
+    ("Eleventh", ELEVENTH_CHORD),
+    ("Minor Eleventh", MINOR_ELEVENTH),
+    ("Major Eleventh", MAJOR_ELEVENTH),
+    ("Thirteenth", THIRTEENTH_CHORD),
+    ("Minor Thirteenth", MINOR_THIRTEENTH),
+    ("Major Thirteenth", MAJOR_THIRTEENTH),
+    ("Suspended Second", SUSPENDED_SECOND),
+    ("Suspended Fourth", SUSPENDED_FOURTH),
+    ("Added Second", ADDED_SECOND),
+    ("Added Ninth", ADDED_NINTH),
+    ("Added Eleventh", ADDED_ELEVENTH),
+    ("Dominant Seventh Flat Five", DOMINANT_SEVENTH_FLAT_FIVE),
+    ("Dominant Seventh Sharp Five", DOMINANT_SEVENTH_SHARP_FIVE),
+    ("Dominant Seventh Flat Nine", DOMINANT_FLAT_NINE),
+    ("Dominant Seventh Sharp Nine", DOMINANT_SHARP_NINE),
+    ("Dominant Seventh Sharp Eleven", DOMINANT_SHARP_ELEVEN),
+    ("Dominant Seventh Flat Thirteen", DOMINANT_FLAT_THIRTEEN),
+];
+
+/// A fluent builder for constructing `Chord` instances
+///
+/// `ChordBuilder` provides a convenient way to construct chords using a fluent interface.
+/// It uses a `U4Vec16Builder` internally to efficiently build chord structures with
+/// up to 16 degrees, where each degree can have its own accidental modification.
+///
+/// The builder pattern allows for method chaining, making it easy to construct
+/// complex chords in a readable and maintainable way.
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::{ChordBuilder, ROOT, THIRD, FIFTH, FLAT_THIRD, SHARP_FIFTH, FLAT_SEVENTH};
+///
+/// // Build a major triad
+/// let major_triad = ChordBuilder::with_root("my chord")
+///     .set_degree(THIRD)
+///     .set_degree(FIFTH)
+///     .build();
+///
+/// // Build a minor triad with augmented fifth
+/// let minor_augmented = ChordBuilder::with_root("my chord")
+///     .set_degree(FLAT_THIRD)
+///     .set_degree(SHARP_FIFTH)
+///     .build();
+///
+/// // Build a complex jazz chord
+/// let jazz_chord = ChordBuilder::with_root("my chord")
+///     .set_degree(THIRD)
+///     .set_degree(SHARP_FIFTH)
+///     .set_degree(FLAT_SEVENTH)
+///     .build();
+/// ```
+///
+/// # Performance
+///
+/// - **Const construction**: All methods are const, allowing compile-time evaluation
+/// - **Efficient storage**: Uses 64 bits total (16 degrees × 4 bits each)
+/// - **No allocations**: Stack-only operations with no heap allocations
+/// - **Method chaining**: Fluent interface for readable chord construction
+pub struct ChordBuilder {
+    name: &'static str,
+    bldr: U4Vec16Builder,
+}
+
+impl ChordBuilder {
+    /// Creates a new `ChordBuilder` with a root degree
+    ///
+    /// This method initializes a new chord builder with the root degree (1st degree)
+    /// set to natural. This provides a sensible default starting point for chord
+    /// construction, as most chords include the root note.
+    ///
+    /// # Returns
+    /// A new `ChordBuilder` instance with the root degree set to natural
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, ROOT};
+    ///
+    /// let builder = ChordBuilder::with_root("my chord");
+    /// let chord = builder.build();
+    ///
+    /// // The root degree should be set
+    /// let degrees: Vec<_> = chord.degrees().collect();
+    /// assert_eq!(degrees[0], ROOT);
+    /// ```
+    #[inline]
+    pub const fn with_root(name: &'static str) -> Self {
+        let bldr = U4Vec16Builder::new();
+        let bldr = Self { name, bldr };
+        bldr.set_degree(ROOT)
+    }
+
+    /// Creates a new unnamed `ChordBuilder` with the canonical degrees of a quality
+    ///
+    /// This is the reverse of `Chord::quality`: `Major` sets the root, the third
+    /// and the fifth, `Minor` the root, the flat third and the fifth, `Dominant`
+    /// adds the flat seventh to the major triad, and so on. `Other` has no
+    /// canonical degrees, so only the root is set. Further degrees can be added
+    /// to the returned builder.
+    ///
+    /// # Arguments
+    /// * `quality` - The quality of the chord to build
+    ///
+    /// # Returns
+    /// A new `ChordBuilder` instance with the degrees of the quality set
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, ChordQuality, DOMINANT_NINTH, MINOR_TRIAD, NINTH};
+    ///
+    /// assert_eq!(ChordBuilder::from_quality(ChordQuality::Minor).build(), MINOR_TRIAD);
+    /// let ninth = ChordBuilder::from_quality(ChordQuality::Dominant)
+    ///     .set_degree(NINTH)
+    ///     .build();
+    /// assert_eq!(ninth, DOMINANT_NINTH);
+    /// ```
+    pub const fn from_quality(quality: ChordQuality) -> Self {
+        let bldr = Self::with_root("");
+        match quality {
+            ChordQuality::Major => bldr.set_degree(THIRD).set_degree(FIFTH),
+            ChordQuality::Minor => bldr.set_degree(FLAT_THIRD).set_degree(FIFTH),
+            ChordQuality::Diminished => bldr.set_degree(FLAT_THIRD).set_degree(FLAT_FIFTH),
+            ChordQuality::Augmented => bldr.set_degree(THIRD).set_degree(SHARP_FIFTH),
+            ChordQuality::Dominant => bldr
+                .set_degree(THIRD)
+                .set_degree(FIFTH)
+                .set_degree(FLAT_SEVENTH),
+            ChordQuality::HalfDiminished => bldr
+                .set_degree(FLAT_THIRD)
+                .set_degree(FLAT_FIFTH)
+                .set_degree(FLAT_SEVENTH),
+            ChordQuality::MajorSeventh => {
+                bldr.set_degree(THIRD).set_degree(FIFTH).set_degree(SEVENTH)
+            }
+            ChordQuality::MinorSeventh => bldr
+                .set_degree(FLAT_THIRD)
+                .set_degree(FIFTH)
+                .set_degree(FLAT_SEVENTH),
+            ChordQuality::DiminishedSeventh => bldr
+                .set_degree(FLAT_THIRD)
+                .set_degree(FLAT_FIFTH)
+                .set_degree(DOUBLEFLAT_SEVENTH),
+            ChordQuality::Other => bldr,
+        }
+    }
+
+    /// Sets a degree in the chord being built
+    ///
+    /// This method adds or modifies a degree in the chord. If the same degree
+    /// is set multiple times, the last setting will overwrite the previous one.
+    /// The degree number is used to determine the position in the chord (1-16),
+    /// and the accidental determines the pitch modification for that degree.
+    ///
+    /// # Arguments
+    /// * `degree` - The `Degree` to set in the chord, containing the degree number
+    ///   and accidental modification
+    ///
+    /// # Returns
+    /// A new `ChordBuilder` instance with the specified degree set
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, ROOT, THIRD, FLAT_THIRD};
+    ///
+    /// let builder = ChordBuilder::with_root("my chord")
+    ///     .set_degree(THIRD)     // Set major third (3rd degree)
+    ///     .set_degree(FLAT_THIRD); // Overwrite with minor third
+    ///
+    /// let chord = builder.build();
+    /// let degrees: Vec<_> = chord.degrees().collect();
+    /// assert_eq!(degrees[0], ROOT);        // Root is still set
+    /// assert_eq!(degrees[1], FLAT_THIRD);  // Third is now minor
+    /// ```
+    #[inline]
+    pub const fn set_degree(self, degree: Degree) -> Self {
+        Self {
+            name: self.name,
+            bldr: self
+                .bldr
+                .set_item(degree.degree() as usize - 1, degree.accidental() as u8),
+        }
+    }
+
+    /// Sets several degrees in the chord being built
+    ///
+    /// This method calls `set_degree` for each degree in order, so later degrees
+    /// overwrite earlier ones with the same number. It is handy for building
+    /// extended chords from partial structures.
+    ///
+    /// # Arguments
+    /// * `degrees` - The degrees to set in the chord
+    ///
+    /// # Returns
+    /// A new `ChordBuilder` instance with all the degrees set
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, THIRD, FIFTH, SEVENTH, NINTH};
+    ///
+    /// let chord = ChordBuilder::with_root("major ninth")
+    ///     .with_degrees([THIRD, FIFTH, SEVENTH, NINTH])
+    ///     .build();
+    /// assert_eq!(chord.to_string(), "R-3-5-7-9");
+    /// ```
+    #[inline]
+    pub fn with_degrees(self, degrees: impl IntoIterator<Item = Degree>) -> Self {
+        degrees.into_iter().fold(self, Self::set_degree)
+    }
+
+    /// Removes a degree from the chord being built
+    ///
+    /// The degree is removed regardless of its accidental. Removing a degree
+    /// that was never set, or a number outside 1-16, has no effect.
+    ///
+    /// # Arguments
+    /// * `degree` - The degree number to remove (1-16)
+    ///
+    /// # Returns
+    /// A new `ChordBuilder` instance without the degree
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, THIRD, FIFTH};
+    ///
+    /// let chord = ChordBuilder::with_root("rootless")
+    ///     .set_degree(THIRD)
+    ///     .set_degree(FIFTH)
+    ///     .remove_degree(1)
+    ///     .build();
+    /// assert_eq!(chord.to_string(), "3-5");
+    /// ```
+    #[inline]
+    pub const fn remove_degree(self, degree: u8) -> Self {
+        if degree == 0 || degree > 16 {
+            return self;
+        }
+        Self {
+            name: self.name,
+            bldr: self.bldr.set_item(degree as usize - 1, 0),
+        }
+    }
+
+    /// Adds a tension to the chord being built
+    ///
+    /// Any tension already set on the same degree number is replaced.
+    ///
+    /// # Arguments
+    /// * `tension` - The tension to add: a 9th, 11th or 13th with any accidental
+    ///
+    /// # Returns
+    /// A new `ChordBuilder` instance with the tension set
+    ///
+    /// # Panics
+    /// This method will panic if the degree is not a 9th, 11th or 13th
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, THIRD, FIFTH, FLAT_SEVENTH, NINTH, THIRTEENTH};
+    ///
+    /// let chord = ChordBuilder::with_root("13")
+    ///     .with_degrees([THIRD, FIFTH, FLAT_SEVENTH])
+    ///     .add_tension(NINTH)
+    ///     .add_tension(THIRTEENTH)
+    ///     .build();
+    /// assert_eq!(chord.to_string(), "R-3-5-♭7-9-13");
+    /// ```
+    #[inline]
+    pub const fn add_tension(self, tension: Degree) -> Self {
+        assert!(
+            is_tension(tension),
+            "A tension must be a 9th, 11th or 13th degree"
+        );
+        self.remove_degree(tension.degree()).set_degree(tension)
+    }
+
+    /// Removes a tension from the chord being built
+    ///
+    /// The tension is only removed when it was set with the same accidental.
+    ///
+    /// # Arguments
+    /// * `tension` - The tension to remove
+    ///
+    /// # Returns
+    /// A new `ChordBuilder` instance without the tension
+    ///
+    /// # Panics
+    /// This method will panic if the degree is not a 9th, 11th or 13th
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, THIRD, NINTH, ELEVENTH};
+    ///
+    /// let chord = ChordBuilder::with_root("add9")
+    ///     .with_degrees([THIRD, NINTH])
+    ///     .remove_tension(ELEVENTH)
+    ///     .build();
+    /// assert_eq!(chord.to_string(), "R-3-9");
+    /// ```
+    #[inline]
+    pub const fn remove_tension(self, tension: Degree) -> Self {
+        assert!(
+            is_tension(tension),
+            "A tension must be a 9th, 11th or 13th degree"
+        );
+
+        let index = tension.degree() as usize - 1;
+        if self.bldr.build().item(index) == tension.accidental() as u8 {
+            self.remove_degree(tension.degree())
+        } else {
+            self
+        }
+    }
+
+    /// Adds random tensions to the chord being built
+    ///
+    /// Up to `extra_voices` of the 9th, 11th and 13th that are not set yet are
+    /// chosen at random and added with `add_tension`, each with a random
+    /// accidental among the named tensions (♭9, 9, ♯9, 11, ♯11, ♭13 and 13).
+    /// Fewer tensions are added when not enough of them are free.
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator
+    /// * `extra_voices` - The number of tensions to add
+    ///
+    /// # Returns
+    /// A new `ChordBuilder` instance with the random tensions set
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, FLAT_SEVENTH, THIRD};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let chord = ChordBuilder::with_root("7")
+    ///     .with_degrees([THIRD, FLAT_SEVENTH])
+    ///     .random_extension(&mut rng, 2)
+    ///     .build();
+    /// assert_eq!(chord.degree_count(), 5);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_extension(self, rng: &mut impl Rng, extra_voices: u8) -> Self {
+        let current = self.bldr.build();
+        let mut free = [9, 11, 13]
+            .into_iter()
+            .filter(|degree| current.item(degree - 1) == 0)
+            .collect::<Vec<usize>>();
+        free.shuffle(rng);
+        free.truncate(extra_voices as usize);
+
+        free.into_iter().fold(self, |builder, degree| {
+            let choices = RANDOM_TENSIONS
+                .iter()
+                .filter(|tension| tension.degree() as usize == degree)
+                .collect::<Vec<_>>();
+            let tension = choices
+                .choose(rng)
+                .expect("Every tension degree has at least one spelling");
+            builder.add_tension(**tension)
+        })
+    }
+
+    /// Builds the final `Chord` from the builder
+    ///
+    /// This method consumes the builder and returns the constructed `Chord`.
+    /// After calling this method, the builder can no longer be used.
+    ///
+    /// # Returns
+    /// A new `Chord` instance with all the degrees that were set during construction
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, ROOT, THIRD, FIFTH};
+    ///
+    /// let chord = ChordBuilder::with_root("my chord")
+    ///     .set_degree(THIRD)
+    ///     .set_degree(FIFTH)
+    ///     .build(); // Builder is consumed here
+    ///
+    /// // chord is now ready to use
+    /// let degrees: Vec<_> = chord.degrees().collect();
+    /// assert_eq!(degrees[0], ROOT);
+    /// assert_eq!(degrees[1], THIRD);
+    /// assert_eq!(degrees[2], FIFTH);
+    /// ```
+    #[inline]
+    pub const fn build(self) -> Chord {
+        Chord::new(self.name, self.bldr.build())
+    }
+}
+
+impl Extend<Degree> for ChordBuilder {
+    /// Sets every degree of the iterator in the chord being built
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, MAJOR_TRIAD, THIRD, FIFTH};
+    ///
+    /// let mut builder = ChordBuilder::with_root("major");
+    /// builder.extend([THIRD, FIFTH]);
+    /// assert_eq!(builder.build(), MAJOR_TRIAD);
+    /// ```
+    fn extend<T: IntoIterator<Item = Degree>>(&mut self, iter: T) {
+        for degree in iter {
+            *self = std::mem::replace(self, ChordBuilder::with_root("")).set_degree(degree);
+        }
+    }
+}
+
+impl FromIterator<Degree> for ChordBuilder {
+    /// Collects degrees into an unnamed `ChordBuilder`
+    ///
+    /// The builder starts from `ChordBuilder::with_root`, so the root is
+    /// always part of the chord.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordBuilder, MINOR_TRIAD, ROOT, FLAT_THIRD, FIFTH};
+    ///
+    /// let builder: ChordBuilder = [ROOT, FLAT_THIRD, FIFTH].iter().copied().collect();
+    /// assert_eq!(builder.build(), MINOR_TRIAD);
+    /// ```
+    fn from_iter<T: IntoIterator<Item = Degree>>(iter: T) -> Self {
+        let mut builder = ChordBuilder::with_root("");
+        builder.extend(iter);
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEGREE_DOUBLEFLAT, DEGREE_FLAT, DEGREE_NATURAL, DEGREE_SHARP};
+
+    // Chord tests
+    #[test]
+    fn test_chord_new() {
+        let builder = U4Vec16Builder::new()
+            .set_item(0, DEGREE_NATURAL as u8)
+            .set_item(2, DEGREE_NATURAL as u8)
+            .set_item(4, DEGREE_NATURAL as u8);
+        let chord = Chord::new("test", builder.build());
+
+        let degrees: Vec<Degree> = chord.degrees().collect();
+        assert_eq!(degrees.len(), 3);
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(chord.name, "test");
+    }
+
+    #[test]
+    fn test_chord_degrees_iterator() {
+        let builder = U4Vec16Builder::new()
+            .set_item(0, DEGREE_NATURAL as u8)
+            .set_item(2, DEGREE_FLAT as u8)
+            .set_item(4, DEGREE_SHARP as u8);
+        let chord = Chord::new("test", builder.build());
+
+        let degrees: Vec<Degree> = chord.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], SHARP_FIFTH);
+        assert_eq!(chord.name, "test");
+    }
+
+    // ChordBuilder tests
+    #[test]
+    fn test_chord_builder_new() {
+        let builder = ChordBuilder::with_root("test");
+        let chord = builder.build();
+        let degrees: Vec<Degree> = chord.degrees().collect();
+
+        // Should only have the root degree
+        assert_eq!(degrees.len(), 1);
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(chord.name, "test");
+    }
+
+    #[test]
+    fn test_chord_builder_set_degree() {
+        let builder = ChordBuilder::with_root("test")
+            .set_degree(THIRD)
+            .set_degree(FIFTH);
+        let chord = builder.build();
+
+        let degrees: Vec<Degree> = chord.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(chord.name, "test");
+    }
+
+    #[test]
+    fn test_chord_builder_set_degree_chaining() {
+        let builder = ChordBuilder::with_root("test")
+            .set_degree(FLAT_THIRD)
+            .set_degree(SHARP_FIFTH)
+            .set_degree(FLAT_SEVENTH);
+        let chord = builder.build();
+
+        let degrees: Vec<Degree> = chord.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], SHARP_FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+    }
+
+    #[test]
+    fn test_chord_builder_set_degree_overwrite() {
+        let builder = ChordBuilder::with_root("test")
+            .set_degree(THIRD)
+            .set_degree(FLAT_THIRD); // This should overwrite the previous THIRD
+        let chord = builder.build();
+
+        let degrees: Vec<Degree> = chord.degrees().collect();
+        assert_eq!(degrees[1], FLAT_THIRD);
+    }
+
+    #[test]
+    fn test_chord_builder_build_consumes() {
+        let builder = ChordBuilder::with_root("test");
+        let _chord = builder.build();
+        // builder is consumed, so we can't use it again
+    }
+
+    // Predefined chord tests
+    #[test]
+    fn test_major_triad() {
+        let degrees: Vec<Degree> = MAJOR_TRIAD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+    }
+
+    #[test]
+    fn test_minor_triad() {
+        let degrees: Vec<Degree> = MINOR_TRIAD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FIFTH);
+    }
+
+    #[test]
+    fn test_diminished_triad() {
+        let degrees: Vec<Degree> = DIMINISHED_TRIAD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FLAT_FIFTH);
+    }
+
+    #[test]
+    fn test_augmented_triad() {
+        let degrees: Vec<Degree> = AUGMENTED_TRIAD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], SHARP_FIFTH);
+    }
+
+    #[test]
+    fn test_major_seventh_chord() {
+        let degrees: Vec<Degree> = MAJOR_SEVENTH_CHORD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], SEVENTH);
+    }
+
+    #[test]
+    fn test_minor_seventh_chord() {
+        let degrees: Vec<Degree> = MINOR_SEVENTH_CHORD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+    }
+
+    #[test]
+    fn test_dominant_seventh() {
+        let degrees: Vec<Degree> = DOMINANT_SEVENTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+    }
+
+    #[test]
+    fn test_half_diminished_seventh() {
+        let degrees: Vec<Degree> = HALF_DIMINISHED_SEVENTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FLAT_FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+    }
+
+    #[test]
+    fn test_diminished_seventh() {
+        let degrees: Vec<Degree> = DIMINISHED_SEVENTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FLAT_FIFTH);
+        assert_eq!(degrees[3], DOUBLEFLAT_SEVENTH);
+    }
+
+    #[test]
+    fn test_augmented_seventh() {
+        let degrees: Vec<Degree> = AUGMENTED_SEVENTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], SHARP_FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+    }
+
+    #[test]
+    fn test_suspended_fourth() {
+        let degrees: Vec<Degree> = SUSPENDED_FOURTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FOURTH);
+        assert_eq!(degrees[2], FIFTH);
+    }
+
+    #[test]
+    fn test_minor_major_seventh() {
+        let degrees: Vec<Degree> = MINOR_MAJOR_SEVENTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], SEVENTH);
+    }
+
+    #[test]
+    fn test_sixth_chord() {
+        let degrees: Vec<Degree> = SIXTH_CHORD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], SIXTH);
+    }
+
+    #[test]
+    fn test_sixth_minor_chord() {
+        let degrees: Vec<Degree> = SIXTH_MINOR_CHORD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], SIXTH);
+    }
+
+    #[test]
+    fn test_sixth_ninth_chord() {
+        let degrees: Vec<Degree> = SIXTH_NINTH_CHORD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], SIXTH);
+        assert_eq!(degrees[4], NINTH);
+    }
+
+    #[test]
+    fn test_fifth_chord() {
+        let degrees: Vec<Degree> = FIFTH_CHORD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FIFTH);
+    }
+
+    #[test]
+    fn test_dominant_ninth() {
+        let degrees: Vec<Degree> = DOMINANT_NINTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+        assert_eq!(degrees[4], NINTH);
+    }
+
+    #[test]
+    fn test_minor_ninth() {
+        let degrees: Vec<Degree> = MINOR_NINTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+        assert_eq!(degrees[4], NINTH);
+    }
+
+    #[test]
+    fn test_major_ninth() {
+        let degrees: Vec<Degree> = MAJOR_NINTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], SEVENTH);
+        assert_eq!(degrees[4], NINTH);
+    }
+
+    #[test]
+    fn test_eleventh_chord() {
+        let degrees: Vec<Degree> = ELEVENTH_CHORD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], SEVENTH);
+        assert_eq!(degrees[4], NINTH);
+        assert_eq!(degrees[5], ELEVENTH);
+    }
 
-chord_const!(
-    SIXTH_NINTH_CHORD,
-    "sixth ninth chord",
-    "A sixth ninth chord consists of a root, major third, and ninth. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
-    "Sixth ninth (mellow, bluesy sound)",
-    "R-3-5-6-9",
-    [THIRD, FIFTH, SIXTH, NINTH]
-);
+    #[test]
+    fn test_minor_eleventh() {
+        let degrees: Vec<Degree> = MINOR_ELEVENTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+        assert_eq!(degrees[4], NINTH);
+        assert_eq!(degrees[5], ELEVENTH);
+    }
 
-chord_const!(
-    FIFTH_CHORD,
-    "fifth chord",
-    "A fifth chord consists of a root, perfect fifth, and perfect sixth. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
-    "Fifth (mellow, bluesy sound)",
-    "R-5",
-    [FIFTH]
-);
+    #[test]
+    fn test_major_eleventh() {
+        let degrees: Vec<Degree> = MAJOR_ELEVENTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], SEVENTH);
+        assert_eq!(degrees[4], NINTH);
+        assert_eq!(degrees[5], ELEVENTH);
+    }
+
+    #[test]
+    fn test_thirteenth_chord() {
+        let degrees: Vec<Degree> = THIRTEENTH_CHORD.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+        assert_eq!(degrees[4], NINTH);
+        assert_eq!(degrees[5], ELEVENTH);
+        assert_eq!(degrees[6], THIRTEENTH);
+    }
+
+    #[test]
+    fn test_minor_thirteenth() {
+        let degrees: Vec<Degree> = MINOR_THIRTEENTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+        assert_eq!(degrees[4], NINTH);
+        assert_eq!(degrees[5], ELEVENTH);
+        assert_eq!(degrees[6], THIRTEENTH);
+    }
+
+    #[test]
+    fn test_major_thirteenth() {
+        let degrees: Vec<Degree> = MAJOR_THIRTEENTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], SEVENTH);
+        assert_eq!(degrees[4], NINTH);
+        assert_eq!(degrees[5], ELEVENTH);
+        assert_eq!(degrees[6], THIRTEENTH);
+    }
+
+    #[test]
+    fn test_suspended_second() {
+        let degrees: Vec<Degree> = SUSPENDED_SECOND.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], SECOND);
+        assert_eq!(degrees[2], FIFTH);
+    }
+
+    #[test]
+    fn test_added_second() {
+        let degrees: Vec<Degree> = ADDED_SECOND.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], SECOND);
+        assert_eq!(degrees[2], THIRD);
+        assert_eq!(degrees[3], FIFTH);
+    }
+
+    #[test]
+    fn test_added_ninth() {
+        let degrees: Vec<Degree> = ADDED_NINTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], NINTH);
+    }
+
+    #[test]
+    fn test_added_eleventh() {
+        let degrees: Vec<Degree> = ADDED_ELEVENTH.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FIFTH);
+        assert_eq!(degrees[3], ELEVENTH);
+    }
+
+    #[test]
+    fn test_dominant_seventh_flat_five() {
+        let degrees: Vec<Degree> = DOMINANT_SEVENTH_FLAT_FIVE.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], FLAT_FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+    }
+
+    #[test]
+    fn test_dominant_seventh_sharp_five() {
+        let degrees: Vec<Degree> = DOMINANT_SEVENTH_SHARP_FIVE.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], THIRD);
+        assert_eq!(degrees[2], SHARP_FIFTH);
+        assert_eq!(degrees[3], FLAT_SEVENTH);
+    }
+
+    // Edge case tests
+    #[test]
+    fn test_chord_builder_edge_values() {
+        let builder = ChordBuilder::with_root("test")
+            .set_degree(Degree::new(1, DEGREE_NATURAL))
+            .set_degree(Degree::new(16, DEGREE_SHARP));
+        let chord = builder.build();
+
+        let degrees: Vec<Degree> = chord.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], Degree::new(16, DEGREE_SHARP));
+    }
+
+    #[test]
+    fn test_chord_builder_all_accidentals() {
+        let builder = ChordBuilder::with_root("test")
+            .set_degree(Degree::new(2, DEGREE_FLAT))
+            .set_degree(Degree::new(3, DEGREE_DOUBLEFLAT))
+            .set_degree(Degree::new(4, DEGREE_SHARP));
+        let chord = builder.build();
+
+        let degrees: Vec<Degree> = chord.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], Degree::new(2, DEGREE_FLAT));
+        assert_eq!(degrees[2], Degree::new(3, DEGREE_DOUBLEFLAT));
+        assert_eq!(degrees[3], Degree::new(4, DEGREE_SHARP));
+    }
+
+    #[test]
+    fn test_chord_builder_complex_pattern() {
+        let builder = ChordBuilder::with_root("test")
+            .set_degree(FLAT_THIRD)
+            .set_degree(FOURTH)
+            .set_degree(SHARP_FIFTH)
+            .set_degree(FLAT_SEVENTH);
+        let chord = builder.build();
+
+        let degrees: Vec<Degree> = chord.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], FLAT_THIRD);
+        assert_eq!(degrees[2], FOURTH);
+        assert_eq!(degrees[3], SHARP_FIFTH);
+        assert_eq!(degrees[4], FLAT_SEVENTH);
+    }
+
+    #[test]
+    fn test_chord_builder_sparse_pattern() {
+        let builder = ChordBuilder::with_root("test")
+            .set_degree(SHARP_FIFTH)
+            .set_degree(FLAT_SEVENTH);
+        let chord = builder.build();
+
+        let degrees: Vec<Degree> = chord.degrees().collect();
+        assert_eq!(degrees[0], ROOT);
+        assert_eq!(degrees[1], SHARP_FIFTH);
+        assert_eq!(degrees[2], FLAT_SEVENTH);
+    }
+
+    // Display implementation tests
+    #[test]
+    fn test_chord_display_empty_chord() {
+        let chord = ChordBuilder::with_root("test").build();
+        let display = format!("{chord}");
+        assert_eq!(display, "R");
+    }
+
+    #[test]
+    fn test_chord_display_major_triad() {
+        let display = format!("{MAJOR_TRIAD}");
+        assert_eq!(display, "R-3-5");
+    }
+
+    #[test]
+    fn test_chord_display_minor_triad() {
+        let display = format!("{MINOR_TRIAD}");
+        assert_eq!(display, "R-♭3-5");
+    }
+
+    #[test]
+    fn test_chord_display_diminished_triad() {
+        let display = format!("{DIMINISHED_TRIAD}");
+        assert_eq!(display, "R-♭3-♭5");
+    }
+
+    #[test]
+    fn test_chord_display_augmented_triad() {
+        let display = format!("{AUGMENTED_TRIAD}");
+        assert_eq!(display, "R-3-♯5");
+    }
+
+    #[test]
+    fn test_chord_display_major_seventh_chord() {
+        let display = format!("{MAJOR_SEVENTH_CHORD}");
+        assert_eq!(display, "R-3-5-7");
+    }
+
+    #[test]
+    fn test_chord_display_minor_seventh_chord() {
+        let display = format!("{MINOR_SEVENTH_CHORD}");
+        assert_eq!(display, "R-♭3-5-♭7");
+    }
+
+    #[test]
+    fn test_chord_display_dominant_seventh() {
+        let display = format!("{DOMINANT_SEVENTH}");
+        assert_eq!(display, "R-3-5-♭7");
+    }
+
+    #[test]
+    fn test_chord_display_half_diminished_seventh() {
+        let display = format!("{HALF_DIMINISHED_SEVENTH}");
+        assert_eq!(display, "R-♭3-♭5-♭7");
+    }
 
-chord_const!(
-    DOMINANT_NINTH,
-    "dominant ninth chord",
-    "A dominant ninth chord consists of a root, major third, perfect fifth, and minor seventh. This chord has a strong, bluesy sound and is commonly used as a dominant chord in functional harmony.",
-    "Dominant 9th (strong, bluesy sound)",
-    "R-3-5-♭7-9",
-    [THIRD, FIFTH, FLAT_SEVENTH, NINTH]
-);
+    #[test]
+    fn test_chord_display_diminished_seventh() {
+        let display = format!("{DIMINISHED_SEVENTH}");
+        assert_eq!(display, "R-♭3-♭5-♭♭7");
+    }
 
-chord_const!(
-    MINOR_NINTH,
-    "minor ninth chord",
-    "A minor ninth chord consists of a root, minor third, perfect fifth, and minor seventh. This chord has a mellow, bluesy sound and is commonly used as a minor chord in functional harmony.",
-    "Minor 9th (mellow, bluesy sound)",
-    "R-♭3-5-♭7-9",
-    [FLAT_THIRD, FIFTH, FLAT_SEVENTH, NINTH]
-);
+    #[test]
+    fn test_chord_display_augmented_seventh() {
+        let display = format!("{AUGMENTED_SEVENTH}");
+        assert_eq!(display, "R-3-♯5-♭7");
+    }
 
-chord_const!(
-    MAJOR_NINTH,
-    "major ninth chord",
-    "A major ninth chord consists of a root, major third, perfect fifth, and major seventh. This chord has a bright, tense sound and is commonly used as a major chord in functional harmony.",
-    "Major 9th (bright, tense sound)",
-    "R-3-5-7-9",
-    [THIRD, FIFTH, SEVENTH, NINTH]
-);
+    #[test]
+    fn test_chord_display_suspended_fourth() {
+        let display = format!("{SUSPENDED_FOURTH}");
+        assert_eq!(display, "R-4-5");
+    }
 
-chord_const!(
-    ELEVENTH_CHORD,
-    "eleventh chord",
-    "An eleventh chord consists of a root, major third, perfect fifth, major seventh, and eleventh. This chord has a bright, tense sound and is commonly used in functional harmony.",
-    "Eleventh (bright, tense sound)",
-    "R-3-5-7-11",
-    [THIRD, FIFTH, SEVENTH, NINTH, ELEVENTH]
-);
+    #[test]
+    fn test_chord_display_minor_major_seventh() {
+        let display = format!("{MINOR_MAJOR_SEVENTH}");
+        assert_eq!(display, "R-♭3-5-7");
+    }
 
-chord_const!(
-    MINOR_ELEVENTH,
-    "minor eleventh chord",
-    "A minor eleventh chord consists of a root, minor third, perfect fifth, minor seventh, and eleventh. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
-    "Minor 11th (mellow, bluesy sound)",
-    "R-♭3-5-♭7-11",
-    [FLAT_THIRD, FIFTH, FLAT_SEVENTH, NINTH, ELEVENTH]
-);
+    #[test]
+    fn test_chord_display_sixth_chord() {
+        let display = format!("{SIXTH_CHORD}");
+        assert_eq!(display, "R-3-5-6");
+    }
 
-chord_const!(
-    THIRTEENTH_CHORD,
-    "thirteenth chord",
-    "A thirteenth chord consists of a root, major third, perfect fifth, minor seventh, eleventh, and thirteenth. This chord has a bright, tense sound and is commonly used in functional harmony.",
-    "Thirteenth (bright, tense sound)",
-    "R-3-5-♭7-11-13",
-    [THIRD, FIFTH, FLAT_SEVENTH, NINTH, ELEVENTH, THIRTEENTH]
-);
+    #[test]
+    fn test_chord_display_sixth_minor_chord() {
+        let display = format!("{SIXTH_MINOR_CHORD}");
+        assert_eq!(display, "R-♭3-5-6");
+    }
 
-chord_const!(
-    MINOR_THIRTEENTH,
-    "minor thirteenth chord",
-    "A minor thirteenth chord consists of a root, minor third, perfect fifth, minor seventh, eleventh, and thirteenth. This chord has a mellow, bluesy sound and is commonly used in jazz, blues, and contemporary music.",
-    "Minor 13th (mellow, bluesy sound)",
-    "R-♭3-5-♭7-11-13",
-    [FLAT_THIRD, FIFTH, FLAT_SEVENTH, NINTH, ELEVENTH, THIRTEENTH]
-);
+    #[test]
+    fn test_chord_display_sixth_ninth_chord() {
+        let display = format!("{SIXTH_NINTH_CHORD}");
+        assert_eq!(display, "R-3-5-6-9");
+    }
 
-chord_const!(
-    MAJOR_THIRTEENTH,
-    "major thirteenth chord",
-    "A major thirteenth chord consists of a root, major third, perfect fifth, minor seventh, eleventh, and thirteenth. This chord has a bright, tense sound and is commonly used in functional harmony.",
-    "Major 13th (bright, tense sound)",
-    "R-3-5-7-11-13",
-    [THIRD, FIFTH, SEVENTH, NINTH, ELEVENTH, THIRTEENTH]
-);
+    #[test]
+    fn test_chord_display_fifth_chord() {
+        let display = format!("{FIFTH_CHORD}");
+        assert_eq!(display, "R-5");
+    }
 
-chord_const!(
-    MAJOR_ELEVENTH,
-    "major eleventh chord",
-    "A major eleventh chord consists of a root, major third, perfect fifth, major seventh, and eleventh. This chord has a bright, tense sound and is commonly used in functional harmony.",
-    "Major 11th (bright, tense sound)",
-    "R-3-5-7-11",
-    [THIRD, FIFTH, SEVENTH, NINTH, ELEVENTH]
-);
+    #[test]
+    fn test_chord_display_dominant_ninth() {
+        let display = format!("{DOMINANT_NINTH}");
+        assert_eq!(display, "R-3-5-♭7-9");
+    }
 
-chord_const!(
-    SUSPENDED_SECOND,
-    "suspended second chord",
-    "A suspended second chord consists of a root, perfect second, and perfect fifth. This chord has a suspended, unresolved sound and is commonly used in contemporary music and jazz.",
-    "Suspended 2nd (suspended, unresolved sound)",
-    "R-2-5",
-    [SECOND, FIFTH]
-);
+    #[test]
+    fn test_chord_display_minor_ninth() {
+        let display = format!("{MINOR_NINTH}");
+        assert_eq!(display, "R-♭3-5-♭7-9");
+    }
 
-chord_const!(
-    SUSPENDED_FOURTH,
-    "suspended fourth chord",
-    "A suspended fourth chord consists of a root, perfect fourth, and perfect fifth. This chord has a suspended, unresolved sound and is commonly used in contemporary music and jazz.",
-    "Suspended 4th (suspended, unresolved sound)",
-    "R-4-5",
-    [FOURTH, FIFTH]
-);
+    #[test]
+    fn test_chord_display_major_ninth() {
+        let display = format!("{MAJOR_NINTH}");
+        assert_eq!(display, "R-3-5-7-9");
+    }
 
-chord_const!(
-    ADDED_SECOND,
-    "added second chord",
-    "A added second chord consists of a root, perfect second, and perfect fifth. This chord has a bright, tense sound and is commonly used in functional harmony.",
-    "Added 2nd (bright, tense sound)",
-    "R-2-3-5",
-    [SECOND, THIRD, FIFTH]
-);
+    #[test]
+    fn test_chord_display_eleventh_chord() {
+        let display = format!("{ELEVENTH_CHORD}");
+        assert_eq!(display, "R-3-5-7-9-11");
+    }
 
-chord_const!(
-    ADDED_NINTH,
-    "added ninth chord",
-    "A added ninth chord consists of a root, major third, perfect fifth, and ninth. This chord has a bright, tense sound and is commonly used in functional harmony.",
-    "Added 9th (bright, tense sound)",
-    "R-3-5-9",
-    [THIRD, FIFTH, NINTH]
-);
+    #[test]
+    fn test_chord_display_minor_eleventh() {
+        let display = format!("{MINOR_ELEVENTH}");
+        assert_eq!(display, "R-♭3-5-♭7-9-11");
+    }
 
-chord_const!(
-    ADDED_ELEVENTH,
-    "added eleventh chord",
-    "A added eleventh chord consists of a root, major third, perfect fifth, major seventh, and eleventh. This chord has a bright, tense sound and is commonly used in functional harmony.",
-    "Added 11th (bright, tense sound)",
-    "R-3-5-11",
-    [THIRD, FIFTH, ELEVENTH]
-);
+    #[test]
+    fn test_chord_display_major_eleventh() {
+        let display = format!("{MAJOR_ELEVENTH}");
+        assert_eq!(display, "R-3-5-7-9-11");
+    }
 
-chord_const!(
-    DOMINANT_SEVENTH_FLAT_FIVE,
-    "dominant seventh flat five chord",
-    "A dominant seventh flat five chord consists of a root, major third, flat fifth, and flat seventh. This chord has a strong, bluesy sound and is commonly used as a dominant chord in functional harmony.",
-    "Dominant 7th flat 5 (strong, bluesy sound)",
-    "R-3-♭5-♭7",
-    [THIRD, FLAT_FIFTH, FLAT_SEVENTH]
-);
+    #[test]
+    fn test_chord_display_thirteenth_chord() {
+        let display = format!("{THIRTEENTH_CHORD}");
+        assert_eq!(display, "R-3-5-♭7-9-11-13");
+    }
 
-chord_const!(
-    DOMINANT_SEVENTH_SHARP_FIVE,
-    "dominant seventh sharp five chord",
-    "A dominant seventh sharp five chord consists of a root, major third, sharp fifth, and flat seventh. This chord has a strong, bluesy sound and is commonly used as a dominant chord in functional harmony.",
-    "Dominant 7th sharp 5 (strong, bluesy sound)",
-    "R-3-♯5-♭7",
-    [THIRD, SHARP_FIFTH, FLAT_SEVENTH]
-);
+    #[test]
+    fn test_chord_display_minor_thirteenth() {
+        let display = format!("{MINOR_THIRTEENTH}");
+        assert_eq!(display, "R-♭3-5-♭7-9-11-13");
+    }
 
-/// A fluent builder for constructing `Chord` instances
-///
-/// `ChordBuilder` provides a convenient way to construct chords using a fluent interface.
-/// It uses a `U4Vec16Builder` internally to efficiently build chord structures with
-/// up to 16 degrees, where each degree can have its own accidental modification.
-///
-/// The builder pattern allows for method chaining, making it easy to construct
-/// complex chords in a readable and maintainable way.
-///
-/// # Examples
-///
-/// ```rust
-/// use muzze_std::{ChordBuilder, ROOT, THIRD, FIFTH, FLAT_THIRD, SHARP_FIFTH, FLAT_SEVENTH};
-///
-/// // Build a major triad
-/// let major_triad = ChordBuilder::with_root("my chord")
-///     .set_degree(THIRD)
-///     .set_degree(FIFTH)
-///     .build();
-///
-/// // Build a minor triad with augmented fifth
-/// let minor_augmented = ChordBuilder::with_root("my chord")
-///     .set_degree(FLAT_THIRD)
-///     .set_degree(SHARP_FIFTH)
-///     .build();
-///
-/// // Build a complex jazz chord
-/// let jazz_chord = ChordBuilder::with_root("my chord")
-///     .set_degree(THIRD)
-///     .set_degree(SHARP_FIFTH)
-///     .set_degree(FLAT_SEVENTH)
-///     .build();
-/// ```
-///
-/// # Performance
-///
-/// - **Const construction**: All methods are const, allowing compile-time evaluation
-/// - **Efficient storage**: Uses 64 bits total (16 degrees × 4 bits each)
-/// - **No allocations**: Stack-only operations with no heap allocations
-/// - **Method chaining**: Fluent interface for readable chord construction
-pub struct ChordBuilder {
-    name: &'static str,
-    bldr: U4Vec16Builder,
-}
+    #[test]
+    fn test_chord_display_major_thirteenth() {
+        let display = format!("{MAJOR_THIRTEENTH}");
+        assert_eq!(display, "R-3-5-7-9-11-13");
+    }
 
-impl ChordBuilder {
-    /// Creates a new `ChordBuilder` with a root degree
-    ///
-    /// This method initializes a new chord builder with the root degree (1st degree)
-    /// set to natural. This provides a sensible default starting point for chord
-    /// construction, as most chords include the root note.
-    ///
-    /// # Returns
-    /// A new `ChordBuilder` instance with the root degree set to natural
-    ///
-    /// # Example
-    /// ```rust
-    /// use muzze_std::{ChordBuilder, ROOT};
-    ///
-    /// let builder = ChordBuilder::with_root("my chord");
-    /// let chord = builder.build();
-    ///
-    /// // The root degree should be set
-    /// let degrees: Vec<_> = chord.degrees().collect();
-    /// assert_eq!(degrees[0], ROOT);
-    /// ```
-    #[inline]
-    pub const fn with_root(name: &'static str) -> Self {
-        let bldr = U4Vec16Builder::new();
-        let bldr = Self { name, bldr };
-        bldr.set_degree(ROOT)
+    #[test]
+    fn test_chord_display_suspended_second() {
+        let display = format!("{SUSPENDED_SECOND}");
+        assert_eq!(display, "R-2-5");
     }
 
-    /// Sets a degree in the chord being built
-    ///
-    /// This method adds or modifies a degree in the chord. If the same degree
-    /// is set multiple times, the last setting will overwrite the previous one.
-    /// The degree number is used to determine the position in the chord (1-16),
-    /// and the accidental determines the pitch modification for that degree.
-    ///
-    /// # Arguments
-    /// * `degree` - The `Degree` to set in the chord, containing the degree number
-    ///   and accidental modification
-    ///
-    /// # Returns
-    /// A new `ChordBuilder` instance with the specified degree set
-    ///
-    /// # Example
-    /// ```rust
-    /// use muzze_std::{ChordBuilder, ROOT, THIRD, FLAT_THIRD};
-    ///
-    /// let builder = ChordBuilder::with_root("my chord")
-    ///     .set_degree(THIRD)     // Set major third (3rd degree)
-    ///     .set_degree(FLAT_THIRD); // Overwrite with minor third
-    ///
-    /// let chord = builder.build();
-    /// let degrees: Vec<_> = chord.degrees().collect();
-    /// assert_eq!(degrees[0], ROOT);        // Root is still set
-    /// assert_eq!(degrees[1], FLAT_THIRD);  // Third is now minor
-    /// ```
-    #[inline]
-    pub const fn set_degree(self, degree: Degree) -> Self {
-        Self {
-            name: self.name,
-            bldr: self
-                .bldr
-                .set_item(degree.degree() as usize - 1, degree.accidental() as u8),
-        }
+    #[test]
+    fn test_chord_display_added_second() {
+        let display = format!("{ADDED_SECOND}");
+        assert_eq!(display, "R-2-3-5");
     }
 
-    /// Builds the final `Chord` from the builder
-    ///
-    /// This method consumes the builder and returns the constructed `Chord`.
-    /// After calling this method, the builder can no longer be used.
-    ///
-    /// # Returns
-    /// A new `Chord` instance with all the degrees that were set during construction
-    ///
-    /// # Example
-    /// ```rust
-    /// use muzze_std::{ChordBuilder, ROOT, THIRD, FIFTH};
-    ///
-    /// let chord = ChordBuilder::with_root("my chord")
-    ///     .set_degree(THIRD)
-    ///     .set_degree(FIFTH)
-    ///     .build(); // Builder is consumed here
-    ///
-    /// // chord is now ready to use
-    /// let degrees: Vec<_> = chord.degrees().collect();
-    /// assert_eq!(degrees[0], ROOT);
-    /// assert_eq!(degrees[1], THIRD);
-    /// assert_eq!(degrees[2], FIFTH);
-    /// ```
-    #[inline]
-    pub const fn build(self) -> Chord {
-        Chord::new(self.name, self.bldr.build())
+    #[test]
+    fn test_chord_display_added_ninth() {
+        let display = format!("{ADDED_NINTH}");
+        assert_eq!(display, "R-3-5-9");
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{DEGREE_DOUBLEFLAT, DEGREE_FLAT, DEGREE_NATURAL, DEGREE_SHARP};
+    #[test]
+    fn test_chord_display_added_eleventh() {
+        let display = format!("{ADDED_ELEVENTH}");
+        assert_eq!(display, "R-3-5-11");
+    }
 
-    // Chord tests
     #[test]
-    fn test_chord_new() {
-        let builder = U4Vec16Builder::new()
-            .set_item(0, DEGREE_NATURAL as u8)
-            .set_item(2, DEGREE_NATURAL as u8)
-            .set_item(4, DEGREE_NATURAL as u8);
-        let chord = Chord::new("test", builder.build());
+    fn test_chord_display_dominant_seventh_flat_five() {
+        let display = format!("{DOMINANT_SEVENTH_FLAT_FIVE}");
+        assert_eq!(display, "R-3-♭5-♭7");
+    }
 
-        let degrees: Vec<Degree> = chord.degrees().collect();
-        assert_eq!(degrees.len(), 3);
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(chord.name, "test");
+    #[test]
+    fn test_chord_display_dominant_seventh_sharp_five() {
+        let display = format!("{DOMINANT_SEVENTH_SHARP_FIVE}");
+        assert_eq!(display, "R-3-♯5-♭7");
     }
 
     #[test]
-    fn test_chord_degrees_iterator() {
-        let builder = U4Vec16Builder::new()
-            .set_item(0, DEGREE_NATURAL as u8)
-            .set_item(2, DEGREE_FLAT as u8)
-            .set_item(4, DEGREE_SHARP as u8);
-        let chord = Chord::new("test", builder.build());
+    fn test_chord_display_custom_chord() {
+        let chord = ChordBuilder::with_root("test")
+            .set_degree(Degree::new(2, DEGREE_SHARP))
+            .set_degree(FLAT_THIRD)
+            .set_degree(Degree::new(4, DEGREE_DOUBLEFLAT))
+            .set_degree(SHARP_FIFTH)
+            .set_degree(FLAT_SEVENTH)
+            .build();
 
-        let degrees: Vec<Degree> = chord.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], SHARP_FIFTH);
-        assert_eq!(chord.name, "test");
+        let display = format!("{chord}");
+        assert_eq!(display, "R-♯2-♭3-♭♭4-♯5-♭7");
     }
 
-    // ChordBuilder tests
     #[test]
-    fn test_chord_builder_new() {
-        let builder = ChordBuilder::with_root("test");
-        let chord = builder.build();
-        let degrees: Vec<Degree> = chord.degrees().collect();
+    fn test_chord_display_all_accidentals() {
+        let chord = ChordBuilder::with_root("test")
+            .set_degree(Degree::new(2, DEGREE_FLAT))
+            .set_degree(Degree::new(3, DEGREE_DOUBLEFLAT))
+            .set_degree(Degree::new(4, DEGREE_SHARP))
+            .build();
 
-        // Should only have the root degree
-        assert_eq!(degrees.len(), 1);
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(chord.name, "test");
+        let display = format!("{chord}");
+        assert_eq!(display, "R-♭2-♭♭3-♯4");
     }
 
     #[test]
-    fn test_chord_builder_set_degree() {
-        let builder = ChordBuilder::with_root("test")
+    fn test_chord_display_edge_degrees() {
+        let chord = ChordBuilder::with_root("test")
+            .set_degree(Degree::new(16, DEGREE_SHARP))
+            .build();
+
+        let display = format!("{chord}");
+        assert_eq!(display, "R-♯16");
+    }
+
+    #[test]
+    fn test_chord_display_complex_jazz_chord() {
+        let chord = ChordBuilder::with_root("test")
+            .set_degree(Degree::new(2, DEGREE_SHARP))
             .set_degree(THIRD)
-            .set_degree(FIFTH);
-        let chord = builder.build();
+            .set_degree(Degree::new(4, DEGREE_SHARP))
+            .set_degree(SHARP_FIFTH)
+            .set_degree(Degree::new(6, DEGREE_FLAT))
+            .set_degree(FLAT_SEVENTH)
+            .set_degree(Degree::new(9, DEGREE_NATURAL))
+            .build();
 
-        let degrees: Vec<Degree> = chord.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(chord.name, "test");
+        let display = format!("{chord}");
+        assert_eq!(display, "R-♯2-3-♯4-♯5-♭6-♭7-9");
     }
 
     #[test]
-    fn test_chord_builder_set_degree_chaining() {
-        let builder = ChordBuilder::with_root("test")
-            .set_degree(FLAT_THIRD)
+    fn test_chord_display_sparse_chord() {
+        let chord = ChordBuilder::with_root("test")
             .set_degree(SHARP_FIFTH)
-            .set_degree(FLAT_SEVENTH);
-        let chord = builder.build();
+            .set_degree(FLAT_SEVENTH)
+            .build();
 
-        let degrees: Vec<Degree> = chord.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], SHARP_FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
+        let display = format!("{chord}");
+        assert_eq!(display, "R-♯5-♭7");
+    }
+
+    #[test]
+    fn test_chord_display_alterations() {
+        let chord = ChordBuilder::with_root("test")
+            .set_degree(THIRD)
+            .set_degree(FLAT_FIFTH)
+            .set_degree(SHARP_FIFTH) // This should overwrite the flat fifth
+            .set_degree(SEVENTH)
+            .set_degree(FLAT_SEVENTH) // This should overwrite the natural seventh
+            .build();
+
+        let display = format!("{chord}");
+        assert_eq!(display, "R-3-♯5-♭7");
+    }
+
+    #[test]
+    fn test_chord_display_unicode_symbols() {
+        let chord = ChordBuilder::with_root("test")
+            .set_degree(Degree::new(2, DEGREE_FLAT))
+            .set_degree(Degree::new(3, DEGREE_DOUBLEFLAT))
+            .set_degree(Degree::new(4, DEGREE_SHARP))
+            .build();
+
+        let display = format!("{chord}");
+        // Verify that Unicode symbols are properly displayed
+        assert!(display.contains("♭")); // Flat symbol
+        assert!(display.contains("♭♭")); // Double flat symbol
+        assert!(display.contains("♯")); // Sharp symbol
+        assert!(!display.contains("b")); // Should not contain ASCII 'b'
+        assert!(!display.contains("#")); // Should not contain ASCII '#'
+    }
+
+    // ChordQuality tests
+    #[test]
+    fn test_chord_quality_triads() {
+        assert_eq!(MAJOR_TRIAD.quality(), ChordQuality::Major);
+        assert_eq!(MINOR_TRIAD.quality(), ChordQuality::Minor);
+        assert_eq!(DIMINISHED_TRIAD.quality(), ChordQuality::Diminished);
+        assert_eq!(AUGMENTED_TRIAD.quality(), ChordQuality::Augmented);
+    }
+
+    #[test]
+    fn test_chord_quality_sevenths() {
+        assert_eq!(MAJOR_SEVENTH_CHORD.quality(), ChordQuality::MajorSeventh);
+        assert_eq!(MINOR_SEVENTH_CHORD.quality(), ChordQuality::MinorSeventh);
+        assert_eq!(DOMINANT_SEVENTH.quality(), ChordQuality::Dominant);
+        assert_eq!(
+            HALF_DIMINISHED_SEVENTH.quality(),
+            ChordQuality::HalfDiminished
+        );
+        assert_eq!(
+            DIMINISHED_SEVENTH.quality(),
+            ChordQuality::DiminishedSeventh
+        );
+        assert_eq!(AUGMENTED_SEVENTH.quality(), ChordQuality::Other);
+        assert_eq!(MINOR_MAJOR_SEVENTH.quality(), ChordQuality::Other);
+        assert_eq!(DOMINANT_SEVENTH_FLAT_FIVE.quality(), ChordQuality::Other);
+        assert_eq!(DOMINANT_SEVENTH_SHARP_FIVE.quality(), ChordQuality::Other);
     }
 
     #[test]
-    fn test_chord_builder_set_degree_overwrite() {
-        let builder = ChordBuilder::with_root("test")
-            .set_degree(THIRD)
-            .set_degree(FLAT_THIRD); // This should overwrite the previous THIRD
-        let chord = builder.build();
+    fn test_chord_quality_sixths_and_added_tones() {
+        // Sixths and added tones do not change the underlying triad quality
+        assert_eq!(SIXTH_CHORD.quality(), ChordQuality::Major);
+        assert_eq!(SIXTH_MINOR_CHORD.quality(), ChordQuality::Minor);
+        assert_eq!(SIXTH_NINTH_CHORD.quality(), ChordQuality::Major);
+        assert_eq!(ADDED_SECOND.quality(), ChordQuality::Major);
+        assert_eq!(ADDED_NINTH.quality(), ChordQuality::Major);
+        assert_eq!(ADDED_ELEVENTH.quality(), ChordQuality::Major);
+    }
 
-        let degrees: Vec<Degree> = chord.degrees().collect();
-        assert_eq!(degrees[1], FLAT_THIRD);
+    #[test]
+    fn test_chord_quality_extended() {
+        // Extensions are ignored, the seventh chord underneath decides
+        assert_eq!(DOMINANT_NINTH.quality(), ChordQuality::Dominant);
+        assert_eq!(MINOR_NINTH.quality(), ChordQuality::MinorSeventh);
+        assert_eq!(MAJOR_NINTH.quality(), ChordQuality::MajorSeventh);
+        assert_eq!(ELEVENTH_CHORD.quality(), ChordQuality::MajorSeventh);
+        assert_eq!(MINOR_ELEVENTH.quality(), ChordQuality::MinorSeventh);
+        assert_eq!(MAJOR_ELEVENTH.quality(), ChordQuality::MajorSeventh);
+        assert_eq!(THIRTEENTH_CHORD.quality(), ChordQuality::Dominant);
+        assert_eq!(MINOR_THIRTEENTH.quality(), ChordQuality::MinorSeventh);
+        assert_eq!(MAJOR_THIRTEENTH.quality(), ChordQuality::MajorSeventh);
     }
 
     #[test]
-    fn test_chord_builder_build_consumes() {
-        let builder = ChordBuilder::with_root("test");
-        let _chord = builder.build();
-        // builder is consumed, so we can't use it again
+    fn test_chord_quality_other() {
+        // No third: power chords and suspended chords
+        assert_eq!(FIFTH_CHORD.quality(), ChordQuality::Other);
+        assert_eq!(SUSPENDED_SECOND.quality(), ChordQuality::Other);
+        assert_eq!(SUSPENDED_FOURTH.quality(), ChordQuality::Other);
+        assert_eq!(
+            ChordBuilder::with_root("test").build().quality(),
+            ChordQuality::Other
+        );
     }
 
-    // Predefined chord tests
     #[test]
-    fn test_major_triad() {
-        let degrees: Vec<Degree> = MAJOR_TRIAD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
+    fn test_chord_quality_display() {
+        assert_eq!(format!("{}", ChordQuality::Major), "maj");
+        assert_eq!(format!("{}", ChordQuality::Minor), "m");
+        assert_eq!(format!("{}", ChordQuality::Diminished), "dim");
+        assert_eq!(format!("{}", ChordQuality::Augmented), "aug");
+        assert_eq!(format!("{}", ChordQuality::Dominant), "7");
+        assert_eq!(format!("{}", ChordQuality::HalfDiminished), "m7♭5");
+        assert_eq!(format!("{}", ChordQuality::DiminishedSeventh), "dim7");
+        assert_eq!(format!("{}", ChordQuality::MajorSeventh), "maj7");
+        assert_eq!(format!("{}", ChordQuality::MinorSeventh), "m7");
+        assert_eq!(format!("{}", ChordQuality::Other), "other");
     }
 
     #[test]
-    fn test_minor_triad() {
-        let degrees: Vec<Degree> = MINOR_TRIAD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FIFTH);
+    fn test_chord_u4vec16_conversions() {
+        let degrees: U4Vec16 = MAJOR_TRIAD.into();
+        assert_eq!(degrees.inner(), 0x10101);
+
+        let chord = Chord::try_from(degrees).unwrap();
+        assert_eq!(chord.to_string(), MAJOR_TRIAD.to_string());
+        assert_eq!(chord.name(), "");
     }
 
     #[test]
-    fn test_diminished_triad() {
-        let degrees: Vec<Degree> = DIMINISHED_TRIAD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FLAT_FIFTH);
+    fn test_chord_from_iterator() {
+        let chord = vec![ROOT, THIRD, FIFTH].into_iter().collect::<Chord>();
+        assert_eq!(chord.to_string(), MAJOR_TRIAD.to_string());
+        assert_eq!(chord.name(), "");
+
+        // The root is implied
+        let chord = [FLAT_THIRD, FIFTH, FLAT_SEVENTH]
+            .into_iter()
+            .collect::<Chord>();
+        assert_eq!(chord.to_string(), MINOR_SEVENTH_CHORD.to_string());
     }
 
     #[test]
-    fn test_augmented_triad() {
-        let degrees: Vec<Degree> = AUGMENTED_TRIAD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], SHARP_FIFTH);
+    fn test_chord_from_iterator_overwrite() {
+        // Later degrees overwrite earlier ones with the same number
+        let chord = [THIRD, FIFTH, FLAT_THIRD].into_iter().collect::<Chord>();
+        assert_eq!(chord.to_string(), MINOR_TRIAD.to_string());
     }
 
     #[test]
-    fn test_major_seventh_chord() {
-        let degrees: Vec<Degree> = MAJOR_SEVENTH_CHORD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], SEVENTH);
+    fn test_chord_builder_with_degrees() {
+        let chord = ChordBuilder::with_root("test")
+            .with_degrees([THIRD, FIFTH, SEVENTH, NINTH])
+            .build();
+        assert_eq!(chord.to_string(), MAJOR_NINTH.to_string());
+
+        // Extending can be mixed with set_degree
+        let chord = ChordBuilder::with_root("test")
+            .set_degree(THIRD)
+            .with_degrees(vec![FIFTH, FLAT_SEVENTH])
+            .set_degree(NINTH)
+            .build();
+        assert_eq!(chord.to_string(), DOMINANT_NINTH.to_string());
     }
 
     #[test]
-    fn test_minor_seventh_chord() {
-        let degrees: Vec<Degree> = MINOR_SEVENTH_CHORD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
+    fn test_chord_builder_with_degrees_empty() {
+        let chord = ChordBuilder::with_root("test")
+            .set_degree(THIRD)
+            .set_degree(FIFTH)
+            .with_degrees(std::iter::empty())
+            .build();
+        assert_eq!(chord.to_string(), MAJOR_TRIAD.to_string());
     }
 
     #[test]
-    fn test_dominant_seventh() {
-        let degrees: Vec<Degree> = DOMINANT_SEVENTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
+    fn test_chord_from_intervals() {
+        let chord = Chord::from_intervals(&[0, 4, 7]).unwrap();
+        assert_eq!(chord.to_string(), MAJOR_TRIAD.to_string());
+
+        let chord = Chord::from_intervals(&[0, 3, 7, 10]).unwrap();
+        assert_eq!(chord.to_string(), MINOR_SEVENTH_CHORD.to_string());
+
+        let chord = Chord::from_intervals(&[0, 3, 6, 9]).unwrap();
+        assert_eq!(chord.to_string(), "R-♭3-♭5-6");
+
+        // Order does not matter
+        let chord = Chord::from_intervals(&[7, 0, 4]).unwrap();
+        assert_eq!(chord.to_string(), MAJOR_TRIAD.to_string());
+
+        // The tritone is spelled as a flat fifth
+        let chord = Chord::from_intervals(&[0, 4, 6, 10]).unwrap();
+        assert_eq!(chord.to_string(), DOMINANT_SEVENTH_FLAT_FIVE.to_string());
     }
 
     #[test]
-    fn test_half_diminished_seventh() {
-        let degrees: Vec<Degree> = HALF_DIMINISHED_SEVENTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FLAT_FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
+    fn test_chord_from_intervals_extended() {
+        let chord = Chord::from_intervals(&[0, 4, 7, 10, 14]).unwrap();
+        assert_eq!(chord.to_string(), DOMINANT_NINTH.to_string());
+
+        let chord = Chord::from_intervals(&[0, 4, 7, 11, 14, 17]).unwrap();
+        assert_eq!(chord.to_string(), MAJOR_ELEVENTH.to_string());
+
+        let chord = Chord::from_intervals(&[0, 4, 7, 10, 14, 17, 21]).unwrap();
+        assert_eq!(chord.to_string(), THIRTEENTH_CHORD.to_string());
+
+        let chord = Chord::from_intervals(&[0, 4, 10, 13, 18, 20]).unwrap();
+        assert_eq!(chord.to_string(), "R-3-♭7-♭9-♯11-♭13");
+
+        // ♭9 and ♯9 both need the ninth degree
+        assert_eq!(
+            Chord::from_intervals(&[0, 4, 10, 13, 15]).err(),
+            Some(ChordError::DegreeConflict(9))
+        );
     }
 
     #[test]
-    fn test_diminished_seventh() {
-        let degrees: Vec<Degree> = DIMINISHED_SEVENTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FLAT_FIFTH);
-        assert_eq!(degrees[3], DOUBLEFLAT_SEVENTH);
+    fn test_chord_from_intervals_rootless() {
+        let chord = Chord::from_intervals(&[4, 10, 14]).unwrap();
+        assert_eq!(chord.to_string(), "3-♭7-9");
+        assert_eq!(chord.name(), "");
     }
 
     #[test]
-    fn test_augmented_seventh() {
-        let degrees: Vec<Degree> = AUGMENTED_SEVENTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], SHARP_FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
+    fn test_chord_from_intervals_errors() {
+        assert_eq!(
+            Chord::from_intervals(&[0, 4, 22]).err(),
+            Some(ChordError::UnsupportedInterval(22))
+        );
+        assert_eq!(
+            Chord::from_intervals(&[0, 3, 4, 7]).err(),
+            Some(ChordError::DegreeConflict(3))
+        );
+        // Repeating the same interval is not a conflict, however often
+        assert!(Chord::from_intervals(&[0, 4, 4, 7]).is_ok());
+        assert_eq!(Chord::from_intervals(&[0; 17]).unwrap().to_string(), "R");
+        let repeated = [0, 4, 7, 10].repeat(5);
+        assert_eq!(Chord::from_intervals(&repeated), Ok(DOMINANT_SEVENTH));
     }
 
     #[test]
-    fn test_suspended_fourth() {
-        let degrees: Vec<Degree> = SUSPENDED_FOURTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FOURTH);
-        assert_eq!(degrees[2], FIFTH);
+    fn test_chord_error_display() {
+        assert_eq!(
+            ChordError::UnsupportedInterval(22).to_string(),
+            "no chord degree for an interval of 22 semitones"
+        );
+        assert_eq!(
+            ChordError::DegreeConflict(3).to_string(),
+            "two intervals map to the chord degree 3"
+        );
+        assert_eq!(
+            ChordError::InvalidAccidental(5).to_string(),
+            "no accidental for the code of the chord degree 5"
+        );
     }
 
     #[test]
-    fn test_minor_major_seventh() {
-        let degrees: Vec<Degree> = MINOR_MAJOR_SEVENTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], SEVENTH);
+    fn test_chord_try_from_u4vec16() {
+        let chord = Chord::try_from(U4Vec16::from(DOMINANT_NINTH)).unwrap();
+        assert_eq!(chord, DOMINANT_NINTH);
+        assert_eq!(chord.name(), "");
+
+        assert_eq!(
+            Chord::try_from(U4Vec16::from_u64(0x60001)),
+            Err(ChordError::InvalidAccidental(5))
+        );
+        assert_eq!(
+            Chord::try_from(U4Vec16::from_u64(0xF)),
+            Err(ChordError::InvalidAccidental(1))
+        );
     }
 
     #[test]
-    fn test_sixth_chord() {
-        let degrees: Vec<Degree> = SIXTH_CHORD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], SIXTH);
+    fn test_chord_degree_count() {
+        assert_eq!(FIFTH_CHORD.degree_count(), 2);
+        assert_eq!(MAJOR_TRIAD.degree_count(), 3);
+        assert_eq!(DOMINANT_NINTH.degree_count(), 5);
     }
 
     #[test]
-    fn test_sixth_minor_chord() {
-        let degrees: Vec<Degree> = SIXTH_MINOR_CHORD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], SIXTH);
+    fn test_chord_remove_degree() {
+        let chord = DOMINANT_SEVENTH.remove_degree(5);
+        assert_eq!(chord.to_string(), "R-3-♭7");
+        assert_eq!(chord.name(), DOMINANT_SEVENTH.name());
+
+        // Removing an absent degree is a no-op
+        let chord = MAJOR_TRIAD.remove_degree(7);
+        assert_eq!(chord.to_string(), MAJOR_TRIAD.to_string());
     }
 
     #[test]
-    fn test_sixth_ninth_chord() {
-        let degrees: Vec<Degree> = SIXTH_NINTH_CHORD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], SIXTH);
-        assert_eq!(degrees[4], NINTH);
+    fn test_chord_add_tension() {
+        let chord = DOMINANT_SEVENTH.add_tension(Degree::new(11, DEGREE_SHARP));
+        assert_eq!(chord.degree_count(), 5);
+        assert_eq!(chord.to_string(), "R-3-5-♭7-♯11");
+
+        // A tension on the same degree number replaces the previous one
+        let chord = DOMINANT_NINTH.add_tension(Degree::new(9, DEGREE_FLAT));
+        assert_eq!(chord.to_string(), "R-3-5-♭7-♭9");
+        let chord = chord.add_tension(Degree::new(13, DEGREE_FLAT));
+        assert_eq!(chord.to_string(), "R-3-5-♭7-♭9-♭13");
     }
 
     #[test]
-    fn test_fifth_chord() {
-        let degrees: Vec<Degree> = FIFTH_CHORD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FIFTH);
+    #[should_panic(expected = "A tension must be a 9th, 11th or 13th degree")]
+    fn test_chord_add_tension_invalid() {
+        let _ = MAJOR_TRIAD.add_tension(FLAT_SEVENTH);
     }
 
     #[test]
-    fn test_dominant_ninth() {
-        let degrees: Vec<Degree> = DOMINANT_NINTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
-        assert_eq!(degrees[4], NINTH);
+    fn test_chord_remove_tension() {
+        let sharp_ninth = Degree::new(9, DEGREE_SHARP);
+        let chord = DOMINANT_SEVENTH.add_tension(sharp_ninth);
+        assert_eq!(chord.remove_tension(sharp_ninth).to_string(), "R-3-5-♭7");
+
+        // Removing an absent tension, or one with another accidental, is a no-op
+        assert_eq!(
+            DOMINANT_SEVENTH.remove_tension(NINTH).to_string(),
+            DOMINANT_SEVENTH.to_string()
+        );
+        assert_eq!(chord.remove_tension(NINTH).to_string(), chord.to_string());
     }
 
     #[test]
-    fn test_minor_ninth() {
-        let degrees: Vec<Degree> = MINOR_NINTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
-        assert_eq!(degrees[4], NINTH);
+    #[should_panic(expected = "A tension must be a 9th, 11th or 13th degree")]
+    fn test_chord_remove_tension_invalid() {
+        let _ = MAJOR_TRIAD.remove_tension(THIRD);
     }
 
     #[test]
-    fn test_major_ninth() {
-        let degrees: Vec<Degree> = MAJOR_NINTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], SEVENTH);
-        assert_eq!(degrees[4], NINTH);
+    #[should_panic(expected = "A tension must be a 9th, 11th or 13th degree")]
+    fn test_chord_builder_remove_tension_invalid() {
+        let _ = ChordBuilder::with_root("")
+            .set_degree(THIRD)
+            .remove_tension(THIRD);
     }
 
     #[test]
-    fn test_eleventh_chord() {
-        let degrees: Vec<Degree> = ELEVENTH_CHORD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], SEVENTH);
-        assert_eq!(degrees[4], NINTH);
-        assert_eq!(degrees[5], ELEVENTH);
+    fn test_chord_builder_tensions() {
+        let chord = ChordBuilder::with_root("13♯11")
+            .with_degrees([THIRD, FIFTH, FLAT_SEVENTH])
+            .add_tension(NINTH)
+            .add_tension(ELEVENTH)
+            .add_tension(Degree::new(11, DEGREE_SHARP))
+            .add_tension(THIRTEENTH)
+            .remove_tension(Degree::new(13, DEGREE_FLAT))
+            .build();
+        assert_eq!(chord.to_string(), "R-3-5-♭7-9-♯11-13");
+
+        let chord = ChordBuilder::with_root("7")
+            .with_degrees([THIRD, FIFTH, FLAT_SEVENTH, NINTH])
+            .remove_tension(NINTH)
+            .remove_degree(5)
+            .build();
+        assert_eq!(chord.to_string(), "R-3-♭7");
     }
 
     #[test]
-    fn test_minor_eleventh() {
-        let degrees: Vec<Degree> = MINOR_ELEVENTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
-        assert_eq!(degrees[4], NINTH);
-        assert_eq!(degrees[5], ELEVENTH);
+    fn test_chord_to_degree_string() {
+        assert_eq!(MAJOR_TRIAD.to_degree_string(), "R-3-5");
+        assert_eq!(DOMINANT_NINTH.to_degree_string(), "R-3-5-♭7-9");
+        assert_eq!(
+            DOMINANT_SEVENTH.to_degree_string(),
+            DOMINANT_SEVENTH.to_string()
+        );
     }
 
     #[test]
-    fn test_major_eleventh() {
-        let degrees: Vec<Degree> = MAJOR_ELEVENTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], SEVENTH);
-        assert_eq!(degrees[4], NINTH);
-        assert_eq!(degrees[5], ELEVENTH);
+    fn test_chord_builder_merge_degrees() {
+        let root_third = U4Vec16Builder::from_existing(U4Vec16::from(
+            ChordBuilder::with_root("").set_degree(THIRD).build(),
+        ));
+        let root_fifth = U4Vec16Builder::from_existing(U4Vec16::from(
+            ChordBuilder::with_root("").set_degree(FIFTH).build(),
+        ));
+        let chord = Chord::try_from(root_third.merge_with(root_fifth).build()).unwrap();
+        assert_eq!(chord.to_string(), MAJOR_TRIAD.to_string());
+        assert_eq!(U4Vec16::from(chord), U4Vec16::from(MAJOR_TRIAD));
     }
 
     #[test]
-    fn test_thirteenth_chord() {
-        let degrees: Vec<Degree> = THIRTEENTH_CHORD.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
-        assert_eq!(degrees[4], NINTH);
-        assert_eq!(degrees[5], ELEVENTH);
-        assert_eq!(degrees[6], THIRTEENTH);
+    fn test_chord_drop2() {
+        let voiced = MAJOR_SEVENTH_CHORD.drop2();
+        assert_eq!(voiced.dropped_degrees(), &[5]);
+        assert!(voiced.is_dropped(5));
+        assert!(!voiced.is_dropped(7));
+        assert_eq!(voiced.voicing(), vec![5, 1, 3, 7]);
+        assert_eq!(voiced.chord().to_string(), MAJOR_SEVENTH_CHORD.to_string());
+
+        let voiced = MAJOR_TRIAD.drop2();
+        assert_eq!(voiced.dropped_degrees(), &[3]);
+        assert_eq!(voiced.voicing(), vec![3, 1, 5]);
     }
 
     #[test]
-    fn test_minor_thirteenth() {
-        let degrees: Vec<Degree> = MINOR_THIRTEENTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
-        assert_eq!(degrees[4], NINTH);
-        assert_eq!(degrees[5], ELEVENTH);
-        assert_eq!(degrees[6], THIRTEENTH);
+    fn test_chord_drop3() {
+        let voiced = MAJOR_SEVENTH_CHORD.drop3();
+        assert_eq!(voiced.dropped_degrees(), &[3]);
+        assert_eq!(voiced.voicing(), vec![3, 1, 5, 7]);
+
+        // A power chord has no third voice from the top to drop
+        let voiced = FIFTH_CHORD.drop3();
+        assert!(voiced.dropped_degrees().is_empty());
+        assert_eq!(voiced.voicing(), vec![1, 5]);
     }
 
     #[test]
-    fn test_major_thirteenth() {
-        let degrees: Vec<Degree> = MAJOR_THIRTEENTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], SEVENTH);
-        assert_eq!(degrees[4], NINTH);
-        assert_eq!(degrees[5], ELEVENTH);
-        assert_eq!(degrees[6], THIRTEENTH);
+    fn test_altered_dominant_chords() {
+        let chords = [
+            (DOMINANT_FLAT_NINE, "R-3-5-♭7-♭9"),
+            (DOMINANT_SHARP_NINE, "R-3-5-♭7-♯9"),
+            (DOMINANT_SHARP_ELEVEN, "R-3-5-♭7-♯11"),
+            (DOMINANT_FLAT_THIRTEEN, "R-3-5-♭7-♭13"),
+        ];
+        for (chord, display) in chords {
+            assert_eq!(chord.to_string(), display);
+            assert_eq!(chord.degree_count(), 5);
+            assert_eq!(chord.quality(), ChordQuality::Dominant);
+        }
     }
 
     #[test]
-    fn test_suspended_second() {
-        let degrees: Vec<Degree> = SUSPENDED_SECOND.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], SECOND);
-        assert_eq!(degrees[2], FIFTH);
+    fn test_chord_omit_degrees() {
+        assert_eq!(MAJOR_SEVENTH_CHORD.omit_fifth().degree_count(), 3);
+        assert_eq!(DIMINISHED_TRIAD.omit_fifth().to_string(), "R-♭3");
+        assert_eq!(DOMINANT_SEVENTH.omit_third().to_string(), "R-5-♭7");
+        assert_eq!(FIFTH_CHORD.omit_third().to_string(), "R-5");
     }
 
     #[test]
-    fn test_added_second() {
-        let degrees: Vec<Degree> = ADDED_SECOND.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], SECOND);
-        assert_eq!(degrees[2], THIRD);
-        assert_eq!(degrees[3], FIFTH);
+    fn test_chord_shell_voicing() {
+        let degrees = DOMINANT_SEVENTH
+            .shell_voicing()
+            .degrees()
+            .collect::<Vec<_>>();
+        assert_eq!(degrees, vec![ROOT, THIRD, FLAT_SEVENTH]);
+
+        assert_eq!(THIRTEENTH_CHORD.shell_voicing().to_string(), "R-3-♭7");
+        assert_eq!(
+            HALF_DIMINISHED_SEVENTH.shell_voicing().to_string(),
+            "R-♭3-♭7"
+        );
     }
 
     #[test]
-    fn test_added_ninth() {
-        let degrees: Vec<Degree> = ADDED_NINTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], NINTH);
+    fn test_chord_rootless_voicing() {
+        let chord = MINOR_NINTH.rootless_voicing();
+        assert!(chord.degrees().all(|degree| degree != ROOT));
+        assert_eq!(chord.degree_count(), MINOR_NINTH.degree_count() - 1);
     }
 
     #[test]
-    fn test_added_eleventh() {
-        let degrees: Vec<Degree> = ADDED_ELEVENTH.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FIFTH);
-        assert_eq!(degrees[3], ELEVENTH);
+    fn test_chord_ordering() {
+        let mut chords = vec![MAJOR_THIRTEENTH, MAJOR_TRIAD, DOMINANT_SEVENTH, FIFTH_CHORD];
+        chords.sort();
+        assert_eq!(
+            chords,
+            vec![FIFTH_CHORD, MAJOR_TRIAD, DOMINANT_SEVENTH, MAJOR_THIRTEENTH]
+        );
+        assert_eq!(
+            chords.iter().map(Chord::degree_count).collect::<Vec<_>>(),
+            vec![2, 3, 4, 7]
+        );
     }
 
     #[test]
-    fn test_dominant_seventh_flat_five() {
-        let degrees: Vec<Degree> = DOMINANT_SEVENTH_FLAT_FIVE.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], FLAT_FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
+    fn test_chord_ordering_ties() {
+        // Same number of degrees: ordered by the raw degree pattern
+        assert!(MAJOR_TRIAD < MINOR_TRIAD);
+        assert!(MINOR_TRIAD < AUGMENTED_TRIAD);
+        assert_eq!(MAJOR_TRIAD.cmp(&MAJOR_TRIAD), std::cmp::Ordering::Equal);
+        assert_eq!(
+            MAJOR_TRIAD.partial_cmp(&MINOR_TRIAD),
+            Some(MAJOR_TRIAD.cmp(&MINOR_TRIAD))
+        );
     }
 
     #[test]
-    fn test_dominant_seventh_sharp_five() {
-        let degrees: Vec<Degree> = DOMINANT_SEVENTH_SHARP_FIVE.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], THIRD);
-        assert_eq!(degrees[2], SHARP_FIFTH);
-        assert_eq!(degrees[3], FLAT_SEVENTH);
+    fn test_chord_equality() {
+        assert_eq!(MAJOR_TRIAD, MAJOR_TRIAD);
+        assert_ne!(MAJOR_TRIAD, MINOR_TRIAD);
+
+        let copy = DOMINANT_SEVENTH;
+        assert_eq!(copy, DOMINANT_SEVENTH.clone());
     }
 
-    // Edge case tests
     #[test]
-    fn test_chord_builder_edge_values() {
-        let builder = ChordBuilder::with_root("test")
-            .set_degree(Degree::new(1, DEGREE_NATURAL))
-            .set_degree(Degree::new(16, DEGREE_SHARP));
-        let chord = builder.build();
+    fn test_chord_hash() {
+        use std::collections::HashSet;
 
-        let degrees: Vec<Degree> = chord.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], Degree::new(16, DEGREE_SHARP));
+        assert_eq!(HashSet::from([MAJOR_TRIAD, MAJOR_TRIAD]).len(), 1);
+
+        // Chords with the same degrees hash the same, whatever their names
+        let unnamed = ChordBuilder::with_root("")
+            .with_degrees([FLAT_THIRD, FIFTH])
+            .build();
+        let chords = HashSet::from([MINOR_TRIAD, unnamed, MAJOR_TRIAD]);
+        assert_eq!(chords.len(), 2);
+        assert!(chords.contains(&MINOR_TRIAD));
+    }
+
+    /// All predefined chord constants
+    const ALL_CHORDS: [Chord; 36] = [
+        MAJOR_TRIAD,
+        MINOR_TRIAD,
+        DIMINISHED_TRIAD,
+        AUGMENTED_TRIAD,
+        MAJOR_SEVENTH_CHORD,
+        MINOR_SEVENTH_CHORD,
+        DOMINANT_SEVENTH,
+        HALF_DIMINISHED_SEVENTH,
+        DIMINISHED_SEVENTH,
+        AUGMENTED_SEVENTH,
+        MINOR_MAJOR_SEVENTH,
+        SIXTH_CHORD,
+        SIXTH_MINOR_CHORD,
+        SIXTH_NINTH_CHORD,
+        FIFTH_CHORD,
+        DOMINANT_NINTH,
+        MINOR_NINTH,
+        MAJOR_NINTH,
+        ELEVENTH_CHORD,
+        MINOR_ELEVENTH,
+        THIRTEENTH_CHORD,
+        MINOR_THIRTEENTH,
+        MAJOR_THIRTEENTH,
+        MAJOR_ELEVENTH,
+        SUSPENDED_SECOND,
+        SUSPENDED_FOURTH,
+        ADDED_SECOND,
+        ADDED_NINTH,
+        ADDED_ELEVENTH,
+        DOMINANT_SEVENTH_FLAT_FIVE,
+        DOMINANT_SEVENTH_SHARP_FIVE,
+        DOMINANT_FLAT_NINE,
+        DOMINANT_SHARP_NINE,
+        DOMINANT_SHARP_ELEVEN,
+        DOMINANT_FLAT_THIRTEEN,
+        Chord::from_u64(0),
+    ];
+
+    #[test]
+    fn test_chord_u64_round_trip() {
+        for chord in ALL_CHORDS {
+            let restored = Chord::from_u64(chord.to_u64());
+            assert_eq!(restored, chord);
+            assert_eq!(restored.to_string(), chord.to_string());
+            assert_eq!(restored.name(), "");
+        }
+        assert_eq!(Chord::from_u64(0).degree_count(), 0);
+    }
+
+    #[test]
+    fn test_chord_bytes_round_trip() {
+        for chord in ALL_CHORDS {
+            assert_eq!(Chord::from_bytes(chord.to_bytes()), chord);
+            assert_eq!(chord.to_bytes(), chord.to_u64().to_le_bytes());
+        }
+        assert_eq!(Chord::from_u64(0).to_bytes(), [0; 8]);
     }
 
     #[test]
-    fn test_chord_builder_all_accidentals() {
-        let builder = ChordBuilder::with_root("test")
-            .set_degree(Degree::new(2, DEGREE_FLAT))
-            .set_degree(Degree::new(3, DEGREE_DOUBLEFLAT))
-            .set_degree(Degree::new(4, DEGREE_SHARP));
-        let chord = builder.build();
+    fn test_chord_from_u64_invalid_nibbles() {
+        // Codes above 5 are not accidentals and are skipped
+        let chord = Chord::from_u64(0x10191);
+        assert_eq!(chord.to_string(), "R-3-5");
+        assert_eq!(chord.degree_count(), 3);
+        assert_eq!(chord.normalize(), MAJOR_TRIAD);
+
+        let chord = Chord::from_bytes([0xFF; 8]);
+        assert_eq!(chord.to_string(), "");
+        assert_eq!(chord.degree_count(), 0);
+        assert_eq!(chord.normalize(), Chord::from_u64(0));
+    }
 
-        let degrees: Vec<Degree> = chord.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], Degree::new(2, DEGREE_FLAT));
-        assert_eq!(degrees[2], Degree::new(3, DEGREE_DOUBLEFLAT));
-        assert_eq!(degrees[3], Degree::new(4, DEGREE_SHARP));
+    #[test]
+    fn test_chord_builder_extend() {
+        let mut builder = ChordBuilder::with_root("major");
+        builder.extend([THIRD, FIFTH]);
+        assert_eq!(builder.build(), MAJOR_TRIAD);
+
+        let mut builder = ChordBuilder::with_root("7");
+        builder.extend(vec![THIRD, FIFTH]);
+        builder.extend(std::iter::once(FLAT_SEVENTH));
+        assert_eq!(builder.build(), DOMINANT_SEVENTH);
     }
 
     #[test]
-    fn test_chord_builder_complex_pattern() {
-        let builder = ChordBuilder::with_root("test")
-            .set_degree(FLAT_THIRD)
-            .set_degree(FOURTH)
-            .set_degree(SHARP_FIFTH)
-            .set_degree(FLAT_SEVENTH);
-        let chord = builder.build();
+    fn test_chord_builder_from_iterator() {
+        let builder: ChordBuilder = [ROOT, THIRD, FIFTH].iter().copied().collect();
+        assert_eq!(builder.build(), MAJOR_TRIAD);
 
-        let degrees: Vec<Degree> = chord.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], FLAT_THIRD);
-        assert_eq!(degrees[2], FOURTH);
-        assert_eq!(degrees[3], SHARP_FIFTH);
-        assert_eq!(degrees[4], FLAT_SEVENTH);
+        let builder: ChordBuilder = std::iter::empty().collect();
+        assert_eq!(builder.build().to_string(), "R");
     }
 
     #[test]
-    fn test_chord_builder_sparse_pattern() {
-        let builder = ChordBuilder::with_root("test")
-            .set_degree(SHARP_FIFTH)
-            .set_degree(FLAT_SEVENTH);
-        let chord = builder.build();
+    fn test_chord_type_stack() {
+        for chord_type in [
+            ChordType::Triad,
+            ChordType::Seventh,
+            ChordType::Ninth,
+            ChordType::OpenFifth,
+        ] {
+            let stack = chord_type.stack();
+            assert_eq!(stack[0], (1, 0));
+            // Every chord degree is stacked a third (two scale tones) per step
+            assert!(stack
+                .iter()
+                .all(|(degree, position)| *position == (*degree as usize - 1)));
+        }
+    }
 
-        let degrees: Vec<Degree> = chord.degrees().collect();
-        assert_eq!(degrees[0], ROOT);
-        assert_eq!(degrees[1], SHARP_FIFTH);
-        assert_eq!(degrees[2], FLAT_SEVENTH);
+    #[test]
+    fn test_chord_semitone_set() {
+        assert_eq!(MINOR_TRIAD.semitone_set(), 0b0000_1000_1001);
+        assert_eq!(DIMINISHED_SEVENTH.semitone_set(), 0b0010_0100_1001);
+        assert_eq!(THIRTEENTH_CHORD.semitone_set(), 0b0110_1011_0101);
+        assert_eq!(Chord::from_u64(0).semitone_set(), 0);
     }
 
-    // Display implementation tests
     #[test]
-    fn test_chord_display_empty_chord() {
-        let chord = ChordBuilder::with_root("test").build();
-        let display = format!("{chord}");
-        assert_eq!(display, "R");
+    fn test_chord_as_degree_array() {
+        const TRIAD: [(u8, u8); 16] = MAJOR_TRIAD.as_degree_array();
+        assert_eq!(TRIAD[..5], [(1, 1), (0, 0), (3, 1), (0, 0), (5, 1)]);
+        assert!(TRIAD[5..].iter().all(|entry| *entry == (0, 0)));
+
+        for chord in ALL_CHORDS {
+            let from_array = chord
+                .as_degree_array()
+                .iter()
+                .filter(|(degree, _)| *degree != 0)
+                .map(|(degree, accidental)| {
+                    Degree::new(*degree, DegreeAccidental::try_from(*accidental).unwrap())
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(from_array, chord.degrees().collect::<Vec<_>>());
+        }
     }
 
     #[test]
-    fn test_chord_display_major_triad() {
-        let display = format!("{MAJOR_TRIAD}");
-        assert_eq!(display, "R-3-5");
+    fn test_chord_voice_count() {
+        for chord in ALL_CHORDS {
+            assert_eq!(chord.voice_count(), chord.degree_count());
+        }
+        assert_eq!(MAJOR_TRIAD.voice_count(), 3);
+        assert_eq!(DOMINANT_SEVENTH.voice_count(), 4);
     }
 
     #[test]
-    fn test_chord_display_minor_triad() {
-        let display = format!("{MINOR_TRIAD}");
-        assert_eq!(display, "R-♭3-5");
+    fn test_chord_is_complete_triad() {
+        assert!(MAJOR_TRIAD.is_complete_triad());
+        assert!(MINOR_TRIAD.is_complete_triad());
+        assert!(DIMINISHED_TRIAD.is_complete_triad());
+        assert!(AUGMENTED_TRIAD.is_complete_triad());
+
+        assert!(!FIFTH_CHORD.is_complete_triad());
+        assert!(!SUSPENDED_SECOND.is_complete_triad());
+        assert!(!SUSPENDED_FOURTH.is_complete_triad());
+        assert!(!DOMINANT_SEVENTH.is_complete_triad());
+        assert!(!MAJOR_TRIAD.omit_fifth().is_complete_triad());
     }
 
     #[test]
-    fn test_chord_display_diminished_triad() {
-        let display = format!("{DIMINISHED_TRIAD}");
-        assert_eq!(display, "R-♭3-♭5");
+    fn test_chord_is_complete_seventh_chord() {
+        assert!(DOMINANT_SEVENTH.is_complete_seventh_chord());
+        assert!(MAJOR_SEVENTH_CHORD.is_complete_seventh_chord());
+        assert!(MINOR_SEVENTH_CHORD.is_complete_seventh_chord());
+        assert!(HALF_DIMINISHED_SEVENTH.is_complete_seventh_chord());
+        assert!(DIMINISHED_SEVENTH.is_complete_seventh_chord());
+
+        assert!(!MAJOR_TRIAD.is_complete_seventh_chord());
+        assert!(!DOMINANT_NINTH.is_complete_seventh_chord());
+        assert!(!DOMINANT_SEVENTH.omit_fifth().is_complete_seventh_chord());
+        assert!(!DOMINANT_SEVENTH.shell_voicing().is_complete_seventh_chord());
     }
 
     #[test]
-    fn test_chord_display_augmented_triad() {
-        let display = format!("{AUGMENTED_TRIAD}");
-        assert_eq!(display, "R-3-♯5");
+    fn test_chord_enharmonic_equivalent() {
+        let diminished = DIMINISHED_SEVENTH.enharmonic_equivalent().unwrap();
+        assert_eq!(diminished.degree_count(), DIMINISHED_SEVENTH.degree_count());
+        assert_eq!(diminished.to_degree_string(), "R-♭3-♭5-6");
+        assert_eq!(diminished.name(), DIMINISHED_SEVENTH.name());
+        assert_eq!(diminished.semitone_set(), DIMINISHED_SEVENTH.semitone_set());
+
+        let augmented = AUGMENTED_TRIAD.enharmonic_equivalent().unwrap();
+        assert_eq!(augmented.to_degree_string(), "R-3-♭6");
+        assert_eq!(augmented.semitone_set(), AUGMENTED_TRIAD.semitone_set());
+
+        let augmented_seventh = AUGMENTED_SEVENTH.enharmonic_equivalent().unwrap();
+        assert_eq!(augmented_seventh.to_degree_string(), "R-3-♭6-♭7");
     }
 
     #[test]
-    fn test_chord_display_major_seventh_chord() {
-        let display = format!("{MAJOR_SEVENTH_CHORD}");
-        assert_eq!(display, "R-3-5-7");
+    fn test_chord_enharmonic_equivalent_none() {
+        assert_eq!(MAJOR_TRIAD.enharmonic_equivalent(), None);
+        assert_eq!(DOMINANT_SEVENTH.enharmonic_equivalent(), None);
+        assert_eq!(HALF_DIMINISHED_SEVENTH.enharmonic_equivalent(), None);
+
+        // The respelled degree would collide with the sixth already present
+        let chord = ChordBuilder::with_root("")
+            .set_degree(THIRD)
+            .set_degree(SIXTH)
+            .set_degree(DOUBLEFLAT_SEVENTH)
+            .build();
+        assert_eq!(chord.enharmonic_equivalent(), None);
     }
 
     #[test]
-    fn test_chord_display_minor_seventh_chord() {
-        let display = format!("{MINOR_SEVENTH_CHORD}");
-        assert_eq!(display, "R-♭3-5-♭7");
+    fn test_chord_enharmonic_equivalent_double_sharp() {
+        let chord = ChordBuilder::with_root("")
+            .set_degree(THIRD)
+            .set_degree(Degree::new(5, DEGREE_DOUBLESHARP))
+            .build();
+        let respelled = chord.enharmonic_equivalent().unwrap();
+        assert_eq!(respelled.to_degree_string(), "R-3-6");
+        assert_eq!(respelled.semitone_set(), chord.semitone_set());
     }
 
     #[test]
-    fn test_chord_display_dominant_seventh() {
-        let display = format!("{DOMINANT_SEVENTH}");
-        assert_eq!(display, "R-3-5-♭7");
+    fn test_chord_to_pitch_classes() {
+        use PitchClass::*;
+
+        assert_eq!(MAJOR_TRIAD.to_pitch_classes(C), vec![C, E, G]);
+        assert_eq!(MINOR_SEVENTH_CHORD.to_pitch_classes(D), vec![D, F, A, C]);
+        assert_eq!(DOMINANT_SEVENTH.to_pitch_classes(G), vec![G, B, D, F]);
+        assert_eq!(DIMINISHED_SEVENTH.to_pitch_classes(B), vec![B, D, F, Gs]);
+        assert_eq!(DOMINANT_NINTH.to_pitch_classes(C), vec![C, E, G, As, D]);
     }
 
     #[test]
-    fn test_chord_display_half_diminished_seventh() {
-        let display = format!("{HALF_DIMINISHED_SEVENTH}");
-        assert_eq!(display, "R-♭3-♭5-♭7");
+    fn test_chord_contains_pitch_class() {
+        use PitchClass::*;
+
+        assert!(DOMINANT_SEVENTH.contains_pitch_class(G, F));
+        assert!(DOMINANT_SEVENTH.contains_pitch_class(G, G));
+        assert!(!DOMINANT_SEVENTH.contains_pitch_class(G, C));
+        assert!(MAJOR_NINTH.contains_pitch_class(C, D));
+
+        for chord in ALL_CHORDS {
+            for root in PitchClass::ALL {
+                for pc in chord.to_pitch_classes(root) {
+                    assert!(chord.contains_pitch_class(root, pc));
+                }
+            }
+        }
     }
 
     #[test]
-    fn test_chord_display_diminished_seventh() {
-        let display = format!("{DIMINISHED_SEVENTH}");
-        assert_eq!(display, "R-♭3-♭5-♭♭7");
+    fn test_chord_voice_leading_distance() {
+        assert_eq!(MAJOR_TRIAD.voice_leading_distance(&MAJOR_TRIAD), 0);
+        assert_eq!(MAJOR_TRIAD.voice_leading_distance(&MINOR_TRIAD), 1);
+        assert_eq!(MINOR_TRIAD.voice_leading_distance(&MAJOR_TRIAD), 1);
+        assert_eq!(MAJOR_TRIAD.voice_leading_distance(&AUGMENTED_TRIAD), 1);
+        assert_eq!(MAJOR_TRIAD.voice_leading_distance(&DIMINISHED_TRIAD), 2);
+        assert_eq!(
+            MAJOR_SEVENTH_CHORD.voice_leading_distance(&DOMINANT_SEVENTH),
+            1
+        );
+
+        // The seventh of the larger chord pairs with the fifth of the triad
+        assert_eq!(DOMINANT_SEVENTH.voice_leading_distance(&MAJOR_TRIAD), 3);
+        assert_eq!(MAJOR_TRIAD.voice_leading_distance(&DOMINANT_SEVENTH), 3);
+
+        for chord in ALL_CHORDS {
+            assert_eq!(chord.voice_leading_distance(&chord), 0);
+        }
     }
 
     #[test]
-    fn test_chord_display_augmented_seventh() {
-        let display = format!("{AUGMENTED_SEVENTH}");
-        assert_eq!(display, "R-3-♯5-♭7");
+    fn test_chord_default() {
+        assert_eq!(Chord::default(), MAJOR_TRIAD);
+        assert_eq!(Chord::default().name(), MAJOR_TRIAD.name());
+        assert_eq!(
+            Chord::from_intervals(&[30]).unwrap_or_default(),
+            MAJOR_TRIAD
+        );
+
+        let mut chords = Vec::new();
+        chords.resize_with(3, Chord::default);
+        assert_eq!(chords, vec![MAJOR_TRIAD; 3]);
     }
 
     #[test]
-    fn test_chord_display_suspended_fourth() {
-        let display = format!("{SUSPENDED_FOURTH}");
-        assert_eq!(display, "R-4-5");
+    fn test_chord_functional_tension() {
+        assert_eq!(MAJOR_TRIAD.functional_tension(), 11);
+        assert_eq!(DIMINISHED_TRIAD.functional_tension(), 43);
+        assert_eq!(MAJOR_SEVENTH_CHORD.functional_tension(), 21);
+        assert_eq!(DIMINISHED_SEVENTH.functional_tension(), 42);
+
+        assert!(MAJOR_TRIAD.functional_tension() < DIMINISHED_TRIAD.functional_tension());
+        assert!(DIMINISHED_SEVENTH.functional_tension() > MAJOR_SEVENTH_CHORD.functional_tension());
+        assert!(DOMINANT_SEVENTH.functional_tension() > MAJOR_TRIAD.functional_tension());
+
+        for chord in ALL_CHORDS {
+            assert!(chord.functional_tension() <= 100);
+        }
     }
 
     #[test]
-    fn test_chord_display_minor_major_seventh() {
-        let display = format!("{MINOR_MAJOR_SEVENTH}");
-        assert_eq!(display, "R-♭3-5-7");
+    fn test_chord_functional_tension_without_pairs() {
+        assert_eq!(Chord::from_u64(0).functional_tension(), 0);
+        let root_only = ChordBuilder::with_root("").build();
+        assert_eq!(root_only.functional_tension(), 0);
+    }
+
+    /// Returns the span in semitones of voices played in order, each one above the previous
+    fn stacked_span(voicing: &[Interval]) -> u8 {
+        let mut top = 0;
+        for interval in voicing.iter().skip(1) {
+            let mut note = interval.inner();
+            while note <= top {
+                note += 12;
+            }
+            top = note;
+        }
+        top - voicing.first().map_or(0, Interval::inner)
     }
 
     #[test]
-    fn test_chord_display_sixth_chord() {
-        let display = format!("{SIXTH_CHORD}");
-        assert_eq!(display, "R-3-5-6");
+    fn test_chord_tight_voicing() {
+        let semitones = |chord: Chord| {
+            chord
+                .tight_voicing()
+                .iter()
+                .map(Interval::inner)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(semitones(MAJOR_TRIAD), vec![0, 4, 7]);
+        assert_eq!(semitones(MAJOR_SEVENTH_CHORD), vec![0, 4, 7, 11]);
+        assert_eq!(semitones(DOMINANT_NINTH), vec![0, 4, 7, 10, 14]);
     }
 
     #[test]
-    fn test_chord_display_sixth_minor_chord() {
-        let display = format!("{SIXTH_MINOR_CHORD}");
-        assert_eq!(display, "R-♭3-5-6");
+    fn test_chord_spread_voicing() {
+        let spread = MAJOR_SEVENTH_CHORD.spread_voicing();
+        let tight = MAJOR_SEVENTH_CHORD.tight_voicing();
+        assert_eq!(
+            spread.iter().map(Interval::inner).collect::<Vec<_>>(),
+            vec![0, 11, 4, 7]
+        );
+
+        let mut sorted = spread.clone();
+        sorted.sort_by_key(Interval::inner);
+        assert_eq!(sorted, tight);
+        assert!(stacked_span(&spread) > stacked_span(&tight));
+
+        assert_eq!(
+            DOMINANT_NINTH
+                .spread_voicing()
+                .iter()
+                .map(Interval::inner)
+                .collect::<Vec<_>>(),
+            vec![0, 14, 4, 10, 7]
+        );
+        assert!(Chord::from_u64(0).spread_voicing().is_empty());
     }
 
     #[test]
-    fn test_chord_display_sixth_ninth_chord() {
-        let display = format!("{SIXTH_NINTH_CHORD}");
-        assert_eq!(display, "R-3-5-6-9");
+    fn test_chord_has_tritone() {
+        assert!(DOMINANT_SEVENTH.has_tritone());
+        assert!(HALF_DIMINISHED_SEVENTH.has_tritone());
+        assert!(DIMINISHED_TRIAD.has_tritone());
+        assert!(DIMINISHED_SEVENTH.has_tritone());
+        assert!(DOMINANT_NINTH.has_tritone());
+
+        assert!(!MAJOR_TRIAD.has_tritone());
+        assert!(!MINOR_TRIAD.has_tritone());
+        assert!(!AUGMENTED_TRIAD.has_tritone());
+        assert!(!MAJOR_SEVENTH_CHORD.has_tritone());
+        assert!(!MINOR_SEVENTH_CHORD.has_tritone());
     }
 
     #[test]
-    fn test_chord_display_fifth_chord() {
-        let display = format!("{FIFTH_CHORD}");
-        assert_eq!(display, "R-5");
+    fn test_chord_tritone_pair() {
+        use crate::{DIMINISHED_FIFTH, MAJOR_THIRD, MINOR_SEVENTH, UNISON};
+
+        assert_eq!(
+            DOMINANT_SEVENTH.tritone_pair(),
+            Some((MAJOR_THIRD, MINOR_SEVENTH))
+        );
+        assert_eq!(
+            HALF_DIMINISHED_SEVENTH.tritone_pair(),
+            Some((UNISON, DIMINISHED_FIFTH))
+        );
+        assert_eq!(MAJOR_TRIAD.tritone_pair(), None);
+
+        // The ♯11 forms a tritone with the root across the octave
+        let lydian = MAJOR_TRIAD.add_tension(SHARP_ELEVENTH);
+        assert_eq!(lydian.tritone_pair(), Some((UNISON, Interval::from(18))));
     }
 
     #[test]
-    fn test_chord_display_dominant_ninth() {
-        let display = format!("{DOMINANT_NINTH}");
-        assert_eq!(display, "R-3-5-♭7-9");
+    fn test_chord_is_quartal() {
+        assert!(Chord::from_intervals(&[0, 5, 10]).unwrap().is_quartal());
+        assert!(Chord::from_intervals(&[0, 5, 10, 15]).unwrap().is_quartal());
+        assert!(Chord::from_intervals(&[0, 6, 11]).unwrap().is_quartal());
+        assert!(!Chord::from_intervals(&[0, 5]).unwrap().is_quartal());
+        assert!(!MAJOR_TRIAD.is_quartal());
+        assert!(!SUSPENDED_FOURTH.is_quartal());
     }
 
     #[test]
-    fn test_chord_display_minor_ninth() {
-        let display = format!("{MINOR_NINTH}");
-        assert_eq!(display, "R-♭3-5-♭7-9");
+    fn test_chord_is_quintal() {
+        assert!(Chord::from_intervals(&[0, 7, 14]).unwrap().is_quintal());
+        assert!(!FIFTH_CHORD.is_quintal());
+        assert!(!MAJOR_TRIAD.is_quintal());
     }
 
     #[test]
-    fn test_chord_display_major_ninth() {
-        let display = format!("{MAJOR_NINTH}");
-        assert_eq!(display, "R-3-5-7-9");
+    fn test_chord_is_cluster() {
+        assert!(Chord::from_intervals(&[0, 2, 3]).unwrap().is_cluster());
+        assert!(Chord::from_intervals(&[0, 2, 3, 5]).unwrap().is_cluster());
+        assert!(!Chord::from_intervals(&[0, 2, 5]).unwrap().is_cluster());
+        assert!(!MAJOR_TRIAD.is_cluster());
     }
 
     #[test]
-    fn test_chord_display_eleventh_chord() {
-        let display = format!("{ELEVENTH_CHORD}");
-        assert_eq!(display, "R-3-5-7-9-11");
+    fn test_chord_voicing_type() {
+        assert_eq!(MAJOR_TRIAD.voicing_type(), VoicingType::Tertian);
+        assert_eq!(DIMINISHED_SEVENTH.voicing_type(), VoicingType::Tertian);
+        assert_eq!(MAJOR_SEVENTH_CHORD.voicing_type(), VoicingType::Tertian);
+        assert_eq!(
+            Chord::from_intervals(&[0, 5, 10]).unwrap().voicing_type(),
+            VoicingType::Quartal
+        );
+        assert_eq!(
+            Chord::from_intervals(&[0, 7, 14]).unwrap().voicing_type(),
+            VoicingType::Quintal
+        );
+        assert_eq!(
+            Chord::from_intervals(&[0, 2, 3]).unwrap().voicing_type(),
+            VoicingType::Cluster
+        );
+        assert_eq!(SUSPENDED_SECOND.voicing_type(), VoicingType::Mixed);
+        assert_eq!(FIFTH_CHORD.voicing_type(), VoicingType::Mixed);
     }
 
     #[test]
-    fn test_chord_display_minor_eleventh() {
-        let display = format!("{MINOR_ELEVENTH}");
-        assert_eq!(display, "R-♭3-5-♭7-9-11");
+    fn test_chord_tension_intervals() {
+        use crate::{MAJOR_THIRD, MINOR_SEVENTH, MINOR_THIRD, PERFECT_FIFTH};
+
+        let pairs: Vec<_> = MAJOR_TRIAD.tension_intervals().collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (ROOT, THIRD, MAJOR_THIRD),
+                (ROOT, FIFTH, PERFECT_FIFTH),
+                (THIRD, FIFTH, MINOR_THIRD),
+            ]
+        );
+
+        let pairs: Vec<_> = DOMINANT_SEVENTH.tension_intervals().collect();
+        assert_eq!(pairs.len(), 6);
+        assert_eq!(pairs[2], (ROOT, FLAT_SEVENTH, MINOR_SEVENTH));
+        assert_eq!(pairs[4], (THIRD, FLAT_SEVENTH, Interval::from(6)));
+        assert_eq!(pairs[5], (FIFTH, FLAT_SEVENTH, MINOR_THIRD));
+
+        assert_eq!(FIFTH_CHORD.tension_intervals().count(), 1);
+        assert_eq!(DOMINANT_NINTH.tension_intervals().count(), 10);
     }
 
     #[test]
-    fn test_chord_display_major_eleventh() {
-        let display = format!("{MAJOR_ELEVENTH}");
-        assert_eq!(display, "R-3-5-7-9-11");
+    fn test_chord_tension_intervals_count() {
+        for chord in ALL_CHORDS {
+            let n = chord.degree_count();
+            assert_eq!(
+                chord.tension_intervals().count(),
+                n * n.saturating_sub(1) / 2
+            );
+        }
     }
 
     #[test]
-    fn test_chord_display_thirteenth_chord() {
-        let display = format!("{THIRTEENTH_CHORD}");
-        assert_eq!(display, "R-3-5-♭7-9-11-13");
+    fn test_chord_deduplicate_octave_equivalents() {
+        let chord = ChordBuilder::with_root("")
+            .set_degree(THIRD)
+            .set_degree(FIFTH)
+            .set_degree(Degree::new(8, DEGREE_NATURAL))
+            .build();
+        assert_eq!(chord.degree_count(), 4);
+
+        let merged = chord.deduplicate_octave_equivalents();
+        assert_eq!(merged.degree_count(), 3);
+        assert_eq!(merged, MAJOR_TRIAD);
+
+        // Without a root the octave is kept
+        let rootless = chord.remove_degree(1);
+        assert_eq!(rootless.deduplicate_octave_equivalents(), rootless);
+
+        for chord in ALL_CHORDS {
+            assert_eq!(chord.deduplicate_octave_equivalents(), chord);
+        }
     }
 
     #[test]
-    fn test_chord_display_minor_thirteenth() {
-        let display = format!("{MINOR_THIRTEENTH}");
-        assert_eq!(display, "R-♭3-5-♭7-9-11-13");
+    fn test_chord_normalize() {
+        for chord in ALL_CHORDS {
+            assert_eq!(chord.normalize(), chord);
+        }
+
+        let chord = Chord::from_u64(0xF000_0001_0001_01F1);
+        let normalized = chord.normalize();
+        assert_eq!(normalized.to_u64(), 0x0000_0001_0001_0101);
+        assert_eq!(normalized.to_string(), "R-3-5-9");
     }
 
+    #[cfg(feature = "rand")]
     #[test]
-    fn test_chord_display_major_thirteenth() {
-        let display = format!("{MAJOR_THIRTEENTH}");
-        assert_eq!(display, "R-3-5-7-9-11-13");
+    fn test_chord_random() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for (min_voices, max_voices) in [(1, 1), (3, 3), (3, 5), (4, 7), (10, 10), (0, 16)] {
+            for _ in 0..50 {
+                let chord = Chord::random(&mut rng, min_voices, max_voices);
+                let count = chord.degree_count();
+                assert!(count >= min_voices as usize && count <= max_voices as usize);
+                assert!(chord.degrees().any(|degree| degree == ROOT));
+            }
+        }
     }
 
+    #[cfg(feature = "rand")]
     #[test]
-    fn test_chord_display_suspended_second() {
-        let display = format!("{SUSPENDED_SECOND}");
-        assert_eq!(display, "R-2-5");
+    #[should_panic(expected = "The minimum number of voices must not exceed the maximum")]
+    fn test_chord_random_invalid_range() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        Chord::random(&mut StdRng::seed_from_u64(42), 5, 3);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
-    fn test_chord_display_added_second() {
-        let display = format!("{ADDED_SECOND}");
-        assert_eq!(display, "R-2-3-5");
+    fn test_chord_builder_random_extension() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for extra_voices in 0..5 {
+            let chord = ChordBuilder::with_root("")
+                .with_degrees([THIRD, FIFTH, FLAT_SEVENTH])
+                .random_extension(&mut rng, extra_voices)
+                .build();
+            assert_eq!(chord.degree_count(), 4 + extra_voices.min(3) as usize);
+            assert!(chord.degrees().any(|degree| degree == ROOT));
+            assert!(chord.degrees().any(|degree| degree == FLAT_SEVENTH));
+        }
+
+        // Tensions already set are kept
+        let chord = ChordBuilder::with_root("")
+            .with_degrees([THIRD, SHARP_NINTH])
+            .random_extension(&mut rng, 3)
+            .build();
+        assert_eq!(chord.degree_count(), 5);
+        assert!(chord.degrees().any(|degree| degree == SHARP_NINTH));
     }
 
     #[test]
-    fn test_chord_display_added_ninth() {
-        let display = format!("{ADDED_NINTH}");
-        assert_eq!(display, "R-3-5-9");
+    fn test_chord_substitutions_dominant() {
+        let subs = DOMINANT_SEVENTH.substitutions();
+        assert!(subs.contains(&(AUGMENTED_FOURTH, DOMINANT_SEVENTH)));
+        assert!(subs.contains(&(PERFECT_FIFTH, MINOR_SEVENTH_CHORD)));
+        assert!(subs.contains(&(MAJOR_THIRD, DIMINISHED_SEVENTH)));
+        assert!(subs.contains(&(PERFECT_FIFTH, DOMINANT_SEVENTH)));
+
+        // The tritone substitution keeps the extensions
+        assert_eq!(
+            DOMINANT_NINTH.substitutions()[0],
+            (AUGMENTED_FOURTH, DOMINANT_NINTH)
+        );
     }
 
     #[test]
-    fn test_chord_display_added_eleventh() {
-        let display = format!("{ADDED_ELEVENTH}");
-        assert_eq!(display, "R-3-5-11");
+    fn test_chord_substitutions_major_and_minor() {
+        let subs = MAJOR_TRIAD.substitutions();
+        assert!(!subs.is_empty());
+        assert!(subs.contains(&(MAJOR_THIRD, MINOR_TRIAD)));
+        assert!(subs.contains(&(MAJOR_SIXTH, MINOR_TRIAD)));
+
+        let subs = MINOR_TRIAD.substitutions();
+        assert!(subs.contains(&(MINOR_THIRD, MAJOR_TRIAD)));
+        assert!(subs.contains(&(UNISON, MAJOR_TRIAD)));
+
+        assert!(MAJOR_SEVENTH_CHORD
+            .substitutions()
+            .iter()
+            .all(|(_, chord)| chord.degree_count() == 4));
+        assert!(MINOR_SEVENTH_CHORD
+            .substitutions()
+            .contains(&(MINOR_THIRD, MAJOR_SEVENTH_CHORD)));
     }
 
     #[test]
-    fn test_chord_display_dominant_seventh_flat_five() {
-        let display = format!("{DOMINANT_SEVENTH_FLAT_FIVE}");
-        assert_eq!(display, "R-3-♭5-♭7");
+    fn test_chord_substitutions_other_qualities() {
+        assert_eq!(
+            HALF_DIMINISHED_SEVENTH.substitutions(),
+            vec![(MINOR_SIXTH, DOMINANT_NINTH)]
+        );
+        assert_eq!(
+            DIMINISHED_TRIAD.substitutions(),
+            vec![(MINOR_SIXTH, DOMINANT_SEVENTH)]
+        );
+        assert_eq!(AUGMENTED_TRIAD.substitutions().len(), 2);
+        assert_eq!(DIMINISHED_SEVENTH.substitutions().len(), 4);
+        assert!(SUSPENDED_FOURTH.substitutions().is_empty());
+        assert!(FIFTH_CHORD.substitutions().is_empty());
     }
 
     #[test]
-    fn test_chord_display_dominant_seventh_sharp_five() {
-        let display = format!("{DOMINANT_SEVENTH_SHARP_FIVE}");
-        assert_eq!(display, "R-3-♯5-♭7");
+    fn test_chord_all_predefined() {
+        let catalog = Chord::all_predefined();
+        // Every chord of ALL_CHORDS but the empty one
+        assert_eq!(catalog.len(), ALL_CHORDS.len() - 1);
+        assert!(catalog.iter().all(|(name, _)| !name.is_empty()));
+        for chord in &ALL_CHORDS[..ALL_CHORDS.len() - 1] {
+            assert!(catalog
+                .iter()
+                .any(|(_, candidate)| candidate.name() == chord.name() && candidate == chord));
+        }
+
+        let names = catalog
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(names.len(), catalog.len());
+
+        let dominant = catalog
+            .iter()
+            .find(|(name, _)| *name == "Dominant Seventh")
+            .map(|(_, chord)| *chord);
+        assert_eq!(dominant, Some(DOMINANT_SEVENTH));
     }
 
     #[test]
-    fn test_chord_display_custom_chord() {
-        let chord = ChordBuilder::with_root("test")
-            .set_degree(Degree::new(2, DEGREE_SHARP))
-            .set_degree(FLAT_THIRD)
-            .set_degree(Degree::new(4, DEGREE_DOUBLEFLAT))
-            .set_degree(SHARP_FIFTH)
-            .set_degree(FLAT_SEVENTH)
-            .build();
+    fn test_chord_degree_at() {
+        assert_eq!(
+            MAJOR_SEVENTH_CHORD.degree_at(7),
+            Some(DegreeAccidental::Natural)
+        );
+        assert_eq!(MAJOR_SEVENTH_CHORD.degree_at(9), None);
+        assert_eq!(MINOR_TRIAD.degree_at(3), Some(DegreeAccidental::Flat));
+        assert_eq!(
+            DIMINISHED_SEVENTH.degree_at(7),
+            Some(DegreeAccidental::DoubleFlat)
+        );
+        assert_eq!(MAJOR_TRIAD.degree_at(0), None);
+        assert_eq!(MAJOR_TRIAD.degree_at(17), None);
+
+        for chord in ALL_CHORDS {
+            for degree in chord.degrees() {
+                assert_eq!(chord.degree_at(degree.degree()), Some(degree.accidental()));
+            }
+        }
+    }
 
-        let display = format!("{chord}");
-        assert_eq!(display, "R-♯2-♭3-♭♭4-♯5-♭7");
+    #[test]
+    fn test_chord_to_lilypond() {
+        assert_eq!(DOMINANT_SEVENTH.to_lilypond(PitchClass::G), "g:7");
+        assert_eq!(MAJOR_TRIAD.to_lilypond(PitchClass::C), "c");
+        assert_eq!(MINOR_TRIAD.to_lilypond(PitchClass::A), "a:m");
+        assert_eq!(MAJOR_SEVENTH_CHORD.to_lilypond(PitchClass::C), "c:maj7");
+        assert_eq!(DIMINISHED_TRIAD.to_lilypond(PitchClass::B), "b:dim");
+        assert_eq!(AUGMENTED_TRIAD.to_lilypond(PitchClass::Ds), "dis:aug");
+        assert_eq!(
+            HALF_DIMINISHED_SEVENTH.to_lilypond(PitchClass::B),
+            "b:m7.5-"
+        );
+        assert_eq!(SUSPENDED_FOURTH.to_lilypond(PitchClass::D), "d:sus4");
     }
 
     #[test]
-    fn test_chord_display_all_accidentals() {
-        let chord = ChordBuilder::with_root("test")
-            .set_degree(Degree::new(2, DEGREE_FLAT))
-            .set_degree(Degree::new(3, DEGREE_DOUBLEFLAT))
-            .set_degree(Degree::new(4, DEGREE_SHARP))
-            .build();
+    fn test_chord_to_lilypond_fallback() {
+        assert_eq!(
+            DOMINANT_SHARP_NINE.to_lilypond(PitchClass::E),
+            "e:3.5.7-.9+"
+        );
+        assert_eq!(
+            DOMINANT_SEVENTH_FLAT_FIVE.to_lilypond(PitchClass::C),
+            "c:3.5-.7-"
+        );
+        // The name of the chord does not matter, only its degrees
+        let unnamed = Chord::from_u64(MINOR_TRIAD.to_u64());
+        assert_eq!(unnamed.to_lilypond(PitchClass::E), "e:m");
+    }
 
-        let display = format!("{chord}");
-        assert_eq!(display, "R-♭2-♭♭3-♯4");
+    #[test]
+    fn test_chord_from_scale_degrees() {
+        use crate::{HARMONIC_MINOR, MAJOR};
+
+        let triads = (0..7)
+            .map(|i| Chord::from_scale_degrees(MAJOR, &[i, (i + 2) % 7, (i + 4) % 7]).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(triads, MAJOR.diatonic_triads());
+
+        assert_eq!(
+            Chord::from_scale_degrees(MAJOR, &[4, 6, 1, 3]),
+            Some(DOMINANT_SEVENTH)
+        );
+        assert_eq!(
+            Chord::from_scale_degrees(HARMONIC_MINOR, &[2, 4, 6]),
+            Some(AUGMENTED_TRIAD)
+        );
     }
 
     #[test]
-    fn test_chord_display_edge_degrees() {
-        let chord = ChordBuilder::with_root("test")
-            .set_degree(Degree::new(16, DEGREE_SHARP))
-            .build();
+    fn test_chord_from_scale_degrees_invalid() {
+        use crate::{HARMONIC_MINOR, MAJOR, PENTATONIC_MAJOR};
+
+        assert_eq!(Chord::from_scale_degrees(MAJOR, &[]), None);
+        assert_eq!(Chord::from_scale_degrees(MAJOR, &[0, 7]), None);
+        assert_eq!(
+            Chord::from_scale_degrees(PENTATONIC_MAJOR, &[0, 2, 5]),
+            None
+        );
+        // Above G♯, both B and C of A harmonic minor need to be spelled as a third
+        assert_eq!(Chord::from_scale_degrees(HARMONIC_MINOR, &[6, 1, 2]), None);
+    }
 
-        let display = format!("{chord}");
-        assert_eq!(display, "R-♯16");
+    #[test]
+    fn test_slash_chord_display() {
+        assert_eq!(SlashChord::new(MAJOR_TRIAD, 3).to_string(), "R-3-5/3");
+        assert_eq!(SlashChord::new(MAJOR_TRIAD, 5).to_string(), "R-3-5/5");
+        assert_eq!(SlashChord::new(MAJOR_TRIAD, 1).to_string(), "R-3-5/R");
+        assert_eq!(DOMINANT_SEVENTH.add_bass(7).to_string(), "R-3-5-♭7/♭7");
+        assert_eq!(MAJOR_TRIAD.add_bass(2).to_string(), "R-3-5/2");
     }
 
     #[test]
-    fn test_chord_display_complex_jazz_chord() {
-        let chord = ChordBuilder::with_root("test")
-            .set_degree(Degree::new(2, DEGREE_SHARP))
-            .set_degree(THIRD)
-            .set_degree(Degree::new(4, DEGREE_SHARP))
-            .set_degree(SHARP_FIFTH)
-            .set_degree(Degree::new(6, DEGREE_FLAT))
-            .set_degree(FLAT_SEVENTH)
-            .set_degree(Degree::new(9, DEGREE_NATURAL))
-            .build();
+    fn test_slash_chord_is_inversion() {
+        for degree in [1, 3, 5, 7] {
+            let slash = DOMINANT_SEVENTH.add_bass(degree);
+            assert!(slash.is_inversion());
+            assert!(!slash.is_pedal_point());
+            assert_eq!(slash.bass_degree(), degree);
+            assert_eq!(slash.chord(), &DOMINANT_SEVENTH);
+        }
+        for degree in [2, 4, 6, 9, 16] {
+            let slash = MAJOR_TRIAD.add_bass(degree);
+            assert!(!slash.is_inversion());
+            assert!(slash.is_pedal_point());
+        }
+    }
 
-        let display = format!("{chord}");
-        assert_eq!(display, "R-♯2-3-♯4-♯5-♭6-♭7-9");
+    #[test]
+    #[should_panic(expected = "bass degree out of range")]
+    fn test_slash_chord_out_of_range() {
+        SlashChord::new(MAJOR_TRIAD, 17);
     }
 
     #[test]
-    fn test_chord_display_sparse_chord() {
-        let chord = ChordBuilder::with_root("test")
-            .set_degree(SHARP_FIFTH)
-            .set_degree(FLAT_SEVENTH)
+    fn test_chord_builder_from_quality() {
+        assert_eq!(
+            ChordBuilder::from_quality(ChordQuality::Major).build(),
+            MAJOR_TRIAD
+        );
+        assert_eq!(
+            ChordBuilder::from_quality(ChordQuality::Diminished).build(),
+            DIMINISHED_TRIAD
+        );
+        assert_eq!(
+            ChordBuilder::from_quality(ChordQuality::Dominant).build(),
+            DOMINANT_SEVENTH
+        );
+        assert_eq!(
+            ChordBuilder::from_quality(ChordQuality::HalfDiminished).build(),
+            HALF_DIMINISHED_SEVENTH
+        );
+        assert_eq!(
+            ChordBuilder::from_quality(ChordQuality::DiminishedSeventh).build(),
+            DIMINISHED_SEVENTH
+        );
+        assert_eq!(
+            ChordBuilder::from_quality(ChordQuality::Other).build(),
+            ChordBuilder::with_root("").build()
+        );
+    }
+
+    #[test]
+    fn test_chord_builder_from_quality_round_trip() {
+        let qualities = [
+            ChordQuality::Major,
+            ChordQuality::Minor,
+            ChordQuality::Diminished,
+            ChordQuality::Augmented,
+            ChordQuality::Dominant,
+            ChordQuality::HalfDiminished,
+            ChordQuality::DiminishedSeventh,
+            ChordQuality::MajorSeventh,
+            ChordQuality::MinorSeventh,
+        ];
+        for quality in qualities {
+            assert_eq!(
+                ChordBuilder::from_quality(quality).build().quality(),
+                quality
+            );
+        }
+
+        let extended = ChordBuilder::from_quality(ChordQuality::MinorSeventh)
+            .set_degree(NINTH)
             .build();
+        assert_eq!(extended, MINOR_NINTH);
+    }
 
-        let display = format!("{chord}");
-        assert_eq!(display, "R-♯5-♭7");
+    #[test]
+    fn test_chord_fits_scale() {
+        use crate::{HARMONIC_MINOR, LYDIAN, MAJOR, NATURAL_MINOR};
+
+        assert!(MAJOR_TRIAD.fits_scale(MAJOR));
+        assert!(MAJOR_SEVENTH_CHORD.fits_scale(MAJOR));
+        assert!(!DOMINANT_SEVENTH.fits_scale(MAJOR));
+        assert!(!MINOR_TRIAD.fits_scale(MAJOR));
+        assert!(MINOR_TRIAD.fits_scale(NATURAL_MINOR));
+        assert!(MINOR_MAJOR_SEVENTH.fits_scale(HARMONIC_MINOR));
+        // Extensions are checked like their simple intervals
+        assert!(MAJOR_NINTH.fits_scale(MAJOR));
+        assert!(!ELEVENTH_CHORD.fits_scale(LYDIAN));
+        assert!(Chord::from_u64(0).fits_scale(MAJOR));
     }
 
     #[test]
-    fn test_chord_display_alterations() {
-        let chord = ChordBuilder::with_root("test")
-            .set_degree(THIRD)
-            .set_degree(FLAT_FIFTH)
-            .set_degree(SHARP_FIFTH) // This should overwrite the flat fifth
-            .set_degree(SEVENTH)
-            .set_degree(FLAT_SEVENTH) // This should overwrite the natural seventh
-            .build();
+    fn test_chord_stack_thirds() {
+        use crate::{MAJOR, MIXOLYDIAN, NATURAL_MINOR};
+
+        assert_eq!(MAJOR_TRIAD.stack_thirds(MAJOR), MAJOR_SEVENTH_CHORD);
+        assert_eq!(MINOR_TRIAD.stack_thirds(NATURAL_MINOR), MINOR_SEVENTH_CHORD);
+        assert_eq!(MAJOR_TRIAD.stack_thirds(MIXOLYDIAN), DOMINANT_SEVENTH);
+        assert_eq!(
+            MAJOR_TRIAD.stack_thirds(MAJOR).stack_thirds(MAJOR),
+            MAJOR_NINTH
+        );
+        assert_eq!(
+            MINOR_TRIAD
+                .stack_thirds(NATURAL_MINOR)
+                .stack_thirds(NATURAL_MINOR),
+            MINOR_NINTH
+        );
+        assert_eq!(
+            DOMINANT_NINTH
+                .stack_thirds(MIXOLYDIAN)
+                .stack_thirds(MIXOLYDIAN),
+            THIRTEENTH_CHORD
+        );
+    }
 
-        let display = format!("{chord}");
-        assert_eq!(display, "R-3-♯5-♭7");
+    #[test]
+    fn test_chord_stack_thirds_closest_tone() {
+        use crate::{MAJOR, NATURAL_MINOR, PENTATONIC_MAJOR};
+
+        // The augmented triad is not part of C major, but its seventh is
+        assert_eq!(AUGMENTED_TRIAD.stack_thirds(MAJOR).to_string(), "R-3-♯5-7");
+        // Natural minor has no major seventh, so the minor one is taken
+        assert_eq!(MAJOR_TRIAD.stack_thirds(NATURAL_MINOR), DOMINANT_SEVENTH);
+        // The pentatonic scale has no seventh at all, and the sharp seventh
+        // would double the root, so the sixth is taken as a double-flat seventh
+        assert_eq!(
+            MAJOR_TRIAD.stack_thirds(PENTATONIC_MAJOR).to_string(),
+            "R-3-5-♭♭7"
+        );
+        // Nothing is stacked above the 13th or on an empty chord
+        assert_eq!(THIRTEENTH_CHORD.stack_thirds(MAJOR), THIRTEENTH_CHORD);
+        assert_eq!(Chord::from_u64(0).stack_thirds(MAJOR), Chord::from_u64(0));
     }
 
     #[test]
-    fn test_chord_display_unicode_symbols() {
-        let chord = ChordBuilder::with_root("test")
-            .set_degree(Degree::new(2, DEGREE_FLAT))
-            .set_degree(Degree::new(3, DEGREE_DOUBLEFLAT))
-            .set_degree(Degree::new(4, DEGREE_SHARP))
+    fn test_chord_to_intervals() {
+        let intervals = |chord: Chord| chord.to_intervals().map(|i| i.inner()).collect::<Vec<_>>();
+        assert_eq!(intervals(MAJOR_TRIAD), vec![0, 4, 7]);
+        assert_eq!(intervals(MINOR_SEVENTH_CHORD), vec![0, 3, 7, 10]);
+        assert_eq!(intervals(THIRTEENTH_CHORD), vec![0, 4, 7, 10, 14, 17, 21]);
+        assert_eq!(Chord::from_u64(0).to_intervals().count(), 0);
+
+        // Degrees without an interval are skipped
+        let chord = ChordBuilder::with_root("")
+            .set_degree(Degree::new(15, DegreeAccidental::Natural))
             .build();
+        assert_eq!(intervals(chord), vec![0]);
+    }
 
-        let display = format!("{chord}");
-        // Verify that Unicode symbols are properly displayed
-        assert!(display.contains("♭")); // Flat symbol
-        assert!(display.contains("♭♭")); // Double flat symbol
-        assert!(display.contains("♯")); // Sharp symbol
-        assert!(!display.contains("b")); // Should not contain ASCII 'b'
-        assert!(!display.contains("#")); // Should not contain ASCII '#'
+    #[test]
+    fn test_chord_remove_degree_out_of_range() {
+        assert_eq!(DOMINANT_SEVENTH.remove_degree(0), DOMINANT_SEVENTH);
+        assert_eq!(DOMINANT_SEVENTH.remove_degree(17), DOMINANT_SEVENTH);
+        assert_eq!(
+            DOMINANT_SEVENTH.remove_degree(u8::MAX).name(),
+            DOMINANT_SEVENTH.name()
+        );
+
+        let chord = ChordBuilder::with_root("")
+            .with_degrees([THIRD, FIFTH])
+            .remove_degree(0)
+            .remove_degree(17)
+            .build();
+        assert_eq!(chord, MAJOR_TRIAD);
     }
 }
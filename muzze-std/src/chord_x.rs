@@ -0,0 +1,271 @@
+//! Extended Chord Types
+//!
+//! This module provides the `ChordX` struct, a chord representation that
+//! stores each degree together with its accidental, so the same degree number
+//! can appear more than once.
+
+use std::fmt::Display;
+
+use crate::{Chord, Degree, DegreeAccidental};
+use muzze_bitflags::{u4vec16::U4Vec16Builder, U4x2, U4x2Seq16};
+
+/// Represents a chord as a list of degrees stored in a `U4x2Seq16`
+///
+/// A `Chord` keeps one accidental per degree number, so it cannot hold two
+/// spellings of the same degree, such as the ♭9 and the ♯9 of an altered
+/// dominant. `ChordX` stores up to 16 (degree, accidental) pairs instead:
+/// each `U4x2` holds the degree number minus one in its first value and the
+/// accidental in its second value, and an accidental of 0 marks an empty slot.
+/// The degrees are kept sorted (see the ordering of `Degree`).
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::{ChordX, DOMINANT_SEVENTH, FLAT_NINTH, SHARP_NINTH};
+///
+/// let altered = ChordX::from_chord(DOMINANT_SEVENTH)
+///     .add_degree(SHARP_NINTH)
+///     .add_degree(FLAT_NINTH);
+/// assert_eq!(altered.to_string(), "R-3-5-♭7-♭9-♯9");
+/// assert_eq!(altered.len(), 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChordX {
+    degrees: U4x2Seq16,
+}
+
+impl ChordX {
+    /// The maximum number of degrees in a ChordX
+    const CAPACITY: usize = 16;
+
+    /// Creates a new ChordX with no degrees
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::ChordX;
+    ///
+    /// assert!(ChordX::new().is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            degrees: U4x2Seq16::new(),
+        }
+    }
+
+    /// Adds a degree to the chord, keeping the degrees sorted
+    ///
+    /// Adding a degree that is already part of the chord, with the same
+    /// accidental, leaves the chord unchanged.
+    ///
+    /// # Arguments
+    /// * `degree` - The degree to add
+    ///
+    /// # Returns
+    /// The chord with the degree added
+    ///
+    /// # Panics
+    /// This method will panic if the chord already holds 16 degrees
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordX, FIFTH, ROOT, THIRD};
+    ///
+    /// let triad = ChordX::new().add_degree(FIFTH).add_degree(ROOT).add_degree(THIRD);
+    /// assert_eq!(triad.to_string(), "R-3-5");
+    /// ```
+    pub fn add_degree(self, degree: Degree) -> Self {
+        let mut degrees = self.degrees().collect::<Vec<_>>();
+        if let Err(index) = degrees.binary_search(&degree) {
+            assert!(
+                degrees.len() < Self::CAPACITY,
+                "a ChordX holds at most 16 degrees"
+            );
+            degrees.insert(index, degree);
+        }
+
+        let mut seq = U4x2Seq16::new();
+        for (index, degree) in degrees.into_iter().enumerate() {
+            seq.set(
+                index,
+                U4x2::new(degree.degree() - 1, degree.accidental() as u8),
+            );
+        }
+        Self { degrees: seq }
+    }
+
+    /// Returns an iterator over the degrees of the chord, in ascending order
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordX, FLAT_THIRD, MINOR_TRIAD, ROOT};
+    ///
+    /// let chord = ChordX::from_chord(MINOR_TRIAD);
+    /// assert_eq!(chord.degrees().take(2).collect::<Vec<_>>(), vec![ROOT, FLAT_THIRD]);
+    /// ```
+    pub fn degrees(&self) -> impl Iterator<Item = Degree> + '_ {
+        self.degrees.iter().map_while(|item| {
+            DegreeAccidental::try_from(item.second())
+                .ok()
+                .map(|accidental| Degree::new(item.first() + 1, accidental))
+        })
+    }
+
+    /// Returns the number of degrees in the chord
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.degrees().count()
+    }
+
+    /// Returns true if the chord has no degrees
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.degrees.get(0).second() == 0
+    }
+
+    /// Creates a ChordX holding the degrees of a chord
+    ///
+    /// # Arguments
+    /// * `chord` - The chord to convert
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordX, DOMINANT_SEVENTH};
+    ///
+    /// assert_eq!(ChordX::from_chord(DOMINANT_SEVENTH).to_string(), "R-3-5-♭7");
+    /// ```
+    pub fn from_chord(chord: Chord) -> ChordX {
+        chord.degrees().fold(ChordX::new(), ChordX::add_degree)
+    }
+
+    /// Converts the ChordX back into an unnamed chord
+    ///
+    /// The conversion is lossless for a ChordX built from a chord. When a
+    /// degree number appears more than once, the last spelling in ascending
+    /// order wins, since a `Chord` holds a single accidental per degree.
+    ///
+    /// # Returns
+    /// The `Chord` with the degrees of the ChordX
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordX, DOMINANT_SHARP_NINE, DOMINANT_SEVENTH, FLAT_NINTH, SHARP_NINTH};
+    ///
+    /// let altered = ChordX::from_chord(DOMINANT_SEVENTH)
+    ///     .add_degree(FLAT_NINTH)
+    ///     .add_degree(SHARP_NINTH);
+    /// assert_eq!(altered.to_chord(), DOMINANT_SHARP_NINE);
+    /// ```
+    pub fn to_chord(&self) -> Chord {
+        self.degrees()
+            .fold(U4Vec16Builder::new(), |bldr, degree| {
+                bldr.set_item(degree.degree() as usize - 1, degree.accidental() as u8)
+            })
+            .build()
+            .try_into()
+            .expect("ChordX only holds accidental codes")
+    }
+}
+
+impl Default for ChordX {
+    /// Returns a ChordX with no degrees
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Chord> for ChordX {
+    /// Converts a chord into a ChordX, see `ChordX::from_chord`
+    #[inline]
+    fn from(chord: Chord) -> Self {
+        ChordX::from_chord(chord)
+    }
+}
+
+impl Display for ChordX {
+    /// Formats the chord as a hyphen-separated list of degrees, like `Chord`
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{ChordX, MAJOR_SEVENTH_CHORD};
+    ///
+    /// assert_eq!(ChordX::from(MAJOR_SEVENTH_CHORD).to_string(), "R-3-5-7");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let degrees = self
+            .degrees()
+            .map(|degree| degree.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "{}", degrees.join("-"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Chord, ChordBuilder, AUGMENTED_TRIAD, DOMINANT_SEVENTH, DOMINANT_SHARP_NINE, FLAT_NINTH,
+        MAJOR_TRIAD, NINTH, SHARP_ELEVENTH, SHARP_NINTH, THIRTEENTH_CHORD,
+    };
+
+    #[test]
+    fn test_chord_x_round_trip() {
+        for (_, chord) in Chord::all_predefined().iter().copied() {
+            let chord_x = ChordX::from_chord(chord);
+            assert_eq!(chord_x.to_chord(), chord);
+            assert_eq!(chord_x.to_string(), chord.to_string());
+            assert_eq!(chord_x.len(), chord.degrees().count());
+        }
+        assert_eq!(ChordX::from_chord(Chord::from_u64(0)), ChordX::new());
+        assert_eq!(ChordX::new().to_chord(), Chord::from_u64(0));
+    }
+
+    #[test]
+    fn test_chord_x_double_sharp() {
+        let degree = Degree::new(11, DegreeAccidental::DoubleSharp);
+        let chord = ChordBuilder::with_root("").set_degree(degree).build();
+        let chord_x = ChordX::from_chord(chord);
+        assert_eq!(chord_x.to_string(), "R-♯♯11");
+        assert_eq!(chord_x.to_chord(), chord);
+    }
+
+    #[test]
+    fn test_chord_x_repeated_degrees() {
+        let altered = ChordX::from_chord(DOMINANT_SEVENTH)
+            .add_degree(SHARP_NINTH)
+            .add_degree(FLAT_NINTH)
+            .add_degree(NINTH)
+            .add_degree(SHARP_ELEVENTH);
+        assert_eq!(altered.to_string(), "R-3-5-♭7-♭9-9-♯9-♯11");
+        assert_eq!(altered.len(), 8);
+
+        // A chord keeps the last spelling of a degree
+        let altered = ChordX::from_chord(DOMINANT_SEVENTH)
+            .add_degree(FLAT_NINTH)
+            .add_degree(SHARP_NINTH);
+        assert_eq!(altered.to_chord(), DOMINANT_SHARP_NINE);
+
+        // The augmented and the major triad share all but the fifth
+        let both_fifths =
+            ChordX::from_chord(MAJOR_TRIAD).add_degree(Degree::new(5, DegreeAccidental::Sharp));
+        assert_eq!(both_fifths.to_chord(), AUGMENTED_TRIAD);
+    }
+
+    #[test]
+    fn test_chord_x_add_degree_twice() {
+        let chord = ChordX::from_chord(THIRTEENTH_CHORD);
+        assert_eq!(chord.add_degree(NINTH), chord);
+        assert!(!chord.is_empty());
+        assert!(ChordX::default().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "a ChordX holds at most 16 degrees")]
+    fn test_chord_x_full() {
+        let chord = (1..=16).fold(ChordX::new(), |chord, degree| {
+            chord.add_degree(Degree::new(degree, DegreeAccidental::Natural))
+        });
+        assert_eq!(chord.len(), 16);
+        chord.add_degree(FLAT_NINTH);
+    }
+}
@@ -5,6 +5,9 @@
 //! and can be modified with accidentals (natural, flat, sharp, double flat).
 
 use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::Interval;
 
 /// Represents the accidental modification for a chord degree
 ///
@@ -33,6 +36,83 @@ pub enum DegreeAccidental {
     DoubleFlat = 3,
     /// Sharp accidental - raises pitch by one semitone
     Sharp = 4,
+    /// Double sharp accidental - raises pitch by two semitones
+    DoubleSharp = 5,
+}
+
+impl DegreeAccidental {
+    /// Returns the number of semitones the accidental moves a degree by
+    ///
+    /// # Returns
+    /// The offset in semitones: negative values lower the degree and
+    /// positive values raise it
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DEGREE_DOUBLEFLAT, DEGREE_NATURAL, DEGREE_SHARP};
+    ///
+    /// assert_eq!(DEGREE_NATURAL.semitone_offset(), 0);
+    /// assert_eq!(DEGREE_DOUBLEFLAT.semitone_offset(), -2);
+    /// assert_eq!(DEGREE_SHARP.semitone_offset(), 1);
+    /// ```
+    #[inline]
+    pub const fn semitone_offset(&self) -> i8 {
+        match self {
+            DegreeAccidental::Natural => 0,
+            DegreeAccidental::Flat => -1,
+            DegreeAccidental::DoubleFlat => -2,
+            DegreeAccidental::Sharp => 1,
+            DegreeAccidental::DoubleSharp => 2,
+        }
+    }
+
+    /// Returns the ASCII notation of the accidental
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DEGREE_DOUBLEFLAT, DEGREE_NATURAL, DEGREE_SHARP};
+    ///
+    /// assert_eq!(DEGREE_NATURAL.to_ascii(), "");
+    /// assert_eq!(DEGREE_DOUBLEFLAT.to_ascii(), "bb");
+    /// assert_eq!(DEGREE_SHARP.to_ascii(), "#");
+    /// ```
+    #[inline]
+    pub const fn to_ascii(&self) -> &'static str {
+        match self {
+            DegreeAccidental::Natural => "",
+            DegreeAccidental::Flat => "b",
+            DegreeAccidental::DoubleFlat => "bb",
+            DegreeAccidental::Sharp => "#",
+            DegreeAccidental::DoubleSharp => "##",
+        }
+    }
+
+    /// Returns the accidental that moves a degree by the given number of semitones
+    ///
+    /// # Arguments
+    /// * `offset` - The offset in semitones (-2 to +2)
+    ///
+    /// # Returns
+    /// The matching accidental, or `None` if the offset is outside -2..=2
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{DegreeAccidental, DEGREE_FLAT};
+    ///
+    /// assert_eq!(DegreeAccidental::from_semitone_offset(-1), Some(DEGREE_FLAT));
+    /// assert_eq!(DegreeAccidental::from_semitone_offset(3), None);
+    /// ```
+    #[inline]
+    pub const fn from_semitone_offset(offset: i8) -> Option<DegreeAccidental> {
+        match offset {
+            -2 => Some(DegreeAccidental::DoubleFlat),
+            -1 => Some(DegreeAccidental::Flat),
+            0 => Some(DegreeAccidental::Natural),
+            1 => Some(DegreeAccidental::Sharp),
+            2 => Some(DegreeAccidental::DoubleSharp),
+            _ => None,
+        }
+    }
 }
 
 impl Display for DegreeAccidental {
@@ -42,6 +122,7 @@ impl Display for DegreeAccidental {
             DegreeAccidental::Flat => write!(f, "♭"),
             DegreeAccidental::DoubleFlat => write!(f, "♭♭"),
             DegreeAccidental::Sharp => write!(f, "♯"),
+            DegreeAccidental::DoubleSharp => write!(f, "♯♯"),
         }
     }
 }
@@ -63,6 +144,7 @@ impl TryFrom<u8> for DegreeAccidental {
             2 => Ok(DegreeAccidental::Flat),
             3 => Ok(DegreeAccidental::DoubleFlat),
             4 => Ok(DegreeAccidental::Sharp),
+            5 => Ok(DegreeAccidental::DoubleSharp),
             _ => Err("Invalid degree accidental value: {value}"),
         }
     }
@@ -92,6 +174,12 @@ pub const DEGREE_DOUBLEFLAT: DegreeAccidental = DegreeAccidental::DoubleFlat;
 /// It's equivalent to `DegreeAccidental::Sharp`.
 pub const DEGREE_SHARP: DegreeAccidental = DegreeAccidental::Sharp;
 
+/// Double sharp accidental constant for chord degrees
+///
+/// This represents a double sharp accidental that raises the pitch by two semitones.
+/// It's equivalent to `DegreeAccidental::DoubleSharp`.
+pub const DEGREE_DOUBLESHARP: DegreeAccidental = DegreeAccidental::DoubleSharp;
+
 /// Represents a chord degree with its accidental modification
 ///
 /// A `Degree` represents a specific position within a chord (1st, 3rd, 5th, etc.)
@@ -136,6 +224,76 @@ impl Degree {
     pub const fn accidental(&self) -> DegreeAccidental {
         self.accidental
     }
+
+    /// Returns the interval above the root for a chord degree
+    ///
+    /// This is the same conversion as `Interval::from_degree`: the natural
+    /// degree is taken from the major scale and shifted by the accidental, so
+    /// ♯4 and ♭6 are as valid as ♭5 and ♯5.
+    ///
+    /// # Returns
+    /// The interval above the root, or `None` for degrees above the 13th and
+    /// for a flattened root
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Degree, Interval, FLAT_NINTH, FLAT_SEVENTH, MINOR_SEVENTH};
+    ///
+    /// assert_eq!(FLAT_SEVENTH.to_interval(), Some(MINOR_SEVENTH));
+    /// assert_eq!(FLAT_NINTH.to_interval(), Some(Interval::from(13)));
+    /// ```
+    #[inline]
+    pub const fn to_interval(&self) -> Option<Interval> {
+        Interval::from_degree(self)
+    }
+}
+
+impl From<Degree> for Option<Interval> {
+    /// Converts a `Degree` to its standard interval with `Degree::to_interval`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, PERFECT_FIFTH, FIFTH};
+    ///
+    /// assert_eq!(Option::<Interval>::from(FIFTH), Some(PERFECT_FIFTH));
+    /// ```
+    fn from(degree: Degree) -> Self {
+        degree.to_interval()
+    }
+}
+
+impl PartialOrd for Degree {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Degree {
+    /// Orders degrees by degree number, then by accidental
+    ///
+    /// Degrees with the same number are ordered by the `semitone_offset` of
+    /// their accidental, so a flat degree sorts before the natural one and the
+    /// natural one before the sharp one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{FIFTH, FLAT_THIRD, ROOT, SHARP_FIFTH, THIRD};
+    ///
+    /// assert!(FLAT_THIRD < THIRD);
+    /// assert!(THIRD < FIFTH);
+    /// assert!(FIFTH < SHARP_FIFTH);
+    /// assert_eq!([FIFTH, ROOT, THIRD].iter().copied().max(), Some(FIFTH));
+    /// ```
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.degree.cmp(&other.degree).then_with(|| {
+            self.accidental
+                .semitone_offset()
+                .cmp(&other.accidental.semitone_offset())
+        })
+    }
 }
 
 impl Display for Degree {
@@ -164,6 +322,80 @@ impl Display for Degree {
     }
 }
 
+impl FromStr for Degree {
+    type Err = DegreeParseError;
+
+    /// Parses a degree from its Unicode or ASCII notation
+    ///
+    /// The degree number (1-16) may be prefixed by an accidental: "♭" or "b"
+    /// for flat, "♭♭" or "bb" for double flat, "♯" or "#" for sharp, and
+    /// "♯♯" or "##" for double sharp. "R" is accepted for the root, so the
+    /// output of `Display` parses back to the same degree.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Degree, DegreeParseError, FLAT_SEVENTH, ROOT, SHARP_FIFTH};
+    ///
+    /// assert_eq!("#5".parse::<Degree>(), Ok(SHARP_FIFTH));
+    /// assert_eq!("♭7".parse::<Degree>(), Ok(FLAT_SEVENTH));
+    /// assert_eq!("R".parse::<Degree>(), Ok(ROOT));
+    /// assert_eq!("17".parse::<Degree>(), Err(DegreeParseError::DegreeOutOfRange(17)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "R" {
+            return Ok(ROOT);
+        }
+
+        let digits = s.find(|c: char| c.is_ascii_digit()).unwrap_or(s.len());
+        let (prefix, number) = s.split_at(digits);
+        if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+            return Err(DegreeParseError::InvalidFormat(s.to_string()));
+        }
+
+        let accidental = match prefix {
+            "" => DEGREE_NATURAL,
+            "♭" | "b" => DEGREE_FLAT,
+            "♭♭" | "bb" => DEGREE_DOUBLEFLAT,
+            "♯" | "#" => DEGREE_SHARP,
+            "♯♯" | "##" => DEGREE_DOUBLESHARP,
+            _ => return Err(DegreeParseError::UnknownAccidental(prefix.to_string())),
+        };
+
+        match number.parse::<u32>() {
+            Ok(degree @ 1..=16) => Ok(Degree::new(degree as u8, accidental)),
+            Ok(degree) => Err(DegreeParseError::DegreeOutOfRange(degree)),
+            Err(_) => Err(DegreeParseError::InvalidFormat(s.to_string())),
+        }
+    }
+}
+
+/// Errors that can occur when parsing a `Degree` from a string
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DegreeParseError {
+    /// The string is not an optional accidental followed by a degree number
+    InvalidFormat(String),
+    /// The prefix of the degree number is not a known accidental symbol
+    UnknownAccidental(String),
+    /// The degree number is outside the range 1-16
+    DegreeOutOfRange(u32),
+}
+
+impl Display for DegreeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DegreeParseError::InvalidFormat(s) => write!(f, "invalid degree: {s:?}"),
+            DegreeParseError::UnknownAccidental(symbol) => {
+                write!(f, "unknown degree accidental: {symbol:?}")
+            }
+            DegreeParseError::DegreeOutOfRange(degree) => {
+                write!(f, "degree out of range: {degree}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DegreeParseError {}
+
 /// Root degree constant - 1st degree with natural accidental
 ///
 /// This represents the root note of a chord, which is the fundamental
@@ -240,18 +472,42 @@ pub const DOUBLEFLAT_SEVENTH: Degree = Degree::new(7, DEGREE_DOUBLEFLAT);
 /// in major ninth chords and creates a bright, tense sound.
 pub const NINTH: Degree = Degree::new(9, DEGREE_NATURAL);
 
+/// Flat ninth degree constant - 9th degree with flat accidental
+///
+/// This represents a minor ninth interval from the root. It is the typical
+/// altered tension of dominant chords resolving to a minor chord.
+pub const FLAT_NINTH: Degree = Degree::new(9, DEGREE_FLAT);
+
+/// Sharp ninth degree constant - 9th degree with sharp accidental
+///
+/// This represents an augmented ninth interval from the root, the bluesy
+/// tension of the "Hendrix" dominant seventh sharp ninth chord.
+pub const SHARP_NINTH: Degree = Degree::new(9, DEGREE_SHARP);
+
 /// Eleventh degree constant - 11th degree with natural accidental
 ///
 /// This represents a major eleventh interval from the root. This is used
 /// in major eleventh chords and creates a bright, tense sound.
 pub const ELEVENTH: Degree = Degree::new(11, DEGREE_NATURAL);
 
+/// Sharp eleventh degree constant - 11th degree with sharp accidental
+///
+/// This represents an augmented eleventh interval from the root. It is the
+/// characteristic tension of lydian and lydian dominant voicings.
+pub const SHARP_ELEVENTH: Degree = Degree::new(11, DEGREE_SHARP);
+
 /// Thirteenth degree constant - 13th degree with natural accidental
 ///
 /// This represents a major thirteenth interval from the root. This is used
 /// in major thirteenth chords and creates a bright, tense sound.
 pub const THIRTEENTH: Degree = Degree::new(13, DEGREE_NATURAL);
 
+/// Flat thirteenth degree constant - 13th degree with flat accidental
+///
+/// This represents a minor thirteenth interval from the root, an altered
+/// tension commonly found on dominant chords in minor keys.
+pub const FLAT_THIRTEENTH: Degree = Degree::new(13, DEGREE_FLAT);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -274,10 +530,11 @@ mod tests {
     }
 
     #[test]
-    // #[should_panic(expected = "Invalid degree accidental value: 5")]
+    // #[should_panic(expected = "Invalid degree accidental value: 6")]
     fn test_accidental_from_invalid_u8() {
-        let res = DegreeAccidental::try_from(5);
+        let res = DegreeAccidental::try_from(6);
         assert!(res.is_err());
+        assert!(DegreeAccidental::try_from(0).is_err());
     }
 
     #[test]
@@ -290,7 +547,7 @@ mod tests {
 
     #[test]
     fn test_degree_accidental_roundtrip() {
-        for i in 1..=4 {
+        for i in 1..=5 {
             let accidental = DegreeAccidental::try_from(i);
             assert!(accidental.is_ok());
             assert_eq!(u8::from(accidental.unwrap()), i);
@@ -360,4 +617,294 @@ mod tests {
         assert_eq!(THIRTEENTH.degree, 13);
         assert_eq!(THIRTEENTH.accidental, DEGREE_NATURAL);
     }
+
+    #[test]
+    fn test_altered_tension_constants() {
+        assert_eq!(format!("{FLAT_NINTH}"), "♭9");
+        assert_eq!(format!("{SHARP_NINTH}"), "♯9");
+        assert_eq!(format!("{SHARP_ELEVENTH}"), "♯11");
+        assert_eq!(format!("{FLAT_THIRTEENTH}"), "♭13");
+        assert_eq!(SHARP_ELEVENTH.degree(), 11);
+        assert_eq!(FLAT_THIRTEENTH.accidental(), DEGREE_FLAT);
+    }
+
+    #[test]
+    fn test_double_sharp() {
+        assert_eq!(DegreeAccidental::try_from(5), Ok(DEGREE_DOUBLESHARP));
+        assert_eq!(u8::from(DEGREE_DOUBLESHARP), 5);
+        assert_eq!(format!("{DEGREE_DOUBLESHARP}"), "♯♯");
+    }
+
+    #[test]
+    fn test_semitone_offset_round_trip() {
+        let accidentals = [
+            DEGREE_DOUBLEFLAT,
+            DEGREE_FLAT,
+            DEGREE_NATURAL,
+            DEGREE_SHARP,
+            DEGREE_DOUBLESHARP,
+        ];
+        for accidental in accidentals {
+            assert_eq!(
+                DegreeAccidental::from_semitone_offset(accidental.semitone_offset()),
+                Some(accidental)
+            );
+        }
+        assert_eq!(
+            accidentals.map(|accidental| accidental.semitone_offset()),
+            [-2, -1, 0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_from_semitone_offset_out_of_range() {
+        for offset in [i8::MIN, -3, 3, i8::MAX] {
+            assert_eq!(DegreeAccidental::from_semitone_offset(offset), None);
+        }
+    }
+
+    #[test]
+    fn test_semitone_offset_matches_accidental() {
+        use crate::{DOUBLE_FLAT, DOUBLE_SHARP, FLAT, NATURAL, SHARP};
+
+        assert_eq!(DEGREE_NATURAL.semitone_offset(), NATURAL.semitone_offset());
+        assert_eq!(DEGREE_FLAT.semitone_offset(), FLAT.semitone_offset());
+        assert_eq!(
+            DEGREE_DOUBLEFLAT.semitone_offset(),
+            DOUBLE_FLAT.semitone_offset()
+        );
+        assert_eq!(DEGREE_SHARP.semitone_offset(), SHARP.semitone_offset());
+        assert_eq!(
+            DEGREE_DOUBLESHARP.semitone_offset(),
+            DOUBLE_SHARP.semitone_offset()
+        );
+    }
+
+    #[test]
+    fn test_degree_accidental_to_ascii() {
+        use crate::Accidental;
+
+        let accidentals = [
+            DEGREE_NATURAL,
+            DEGREE_FLAT,
+            DEGREE_DOUBLEFLAT,
+            DEGREE_SHARP,
+            DEGREE_DOUBLESHARP,
+        ];
+        for accidental in accidentals {
+            // The ASCII notation parses to the accidental with the same offset
+            let parsed = Accidental::try_from_str(accidental.to_ascii()).unwrap();
+            assert_eq!(parsed.semitone_offset(), accidental.semitone_offset());
+        }
+    }
+
+    #[test]
+    fn test_degree_to_interval() {
+        let expected = [
+            (ROOT, 0),
+            (SECOND, 2),
+            (FLAT_THIRD, 3),
+            (THIRD, 4),
+            (FOURTH, 5),
+            (FLAT_FIFTH, 6),
+            (FIFTH, 7),
+            (SHARP_FIFTH, 8),
+            (SIXTH, 9),
+            (DOUBLEFLAT_SEVENTH, 9),
+            (FLAT_SEVENTH, 10),
+            (SEVENTH, 11),
+            (FLAT_NINTH, 13),
+            (NINTH, 14),
+            (SHARP_NINTH, 15),
+            (ELEVENTH, 17),
+            (SHARP_ELEVENTH, 18),
+            (FLAT_THIRTEENTH, 20),
+            (THIRTEENTH, 21),
+        ];
+        for (degree, semitones) in expected {
+            assert_eq!(degree.to_interval(), Some(Interval::from(semitones)));
+            assert_eq!(
+                Option::<Interval>::from(degree),
+                Some(Interval::from(semitones))
+            );
+            assert_eq!(degree.to_interval(), Interval::from_degree(&degree));
+        }
+        assert_eq!(
+            Degree::new(2, DEGREE_FLAT).to_interval(),
+            Some(Interval::from(1))
+        );
+    }
+
+    #[test]
+    fn test_degree_to_interval_any_accidental() {
+        assert_eq!(
+            Degree::new(1, DEGREE_SHARP).to_interval(),
+            Some(Interval::from(1))
+        );
+        assert_eq!(
+            Degree::new(4, DEGREE_SHARP).to_interval(),
+            Some(Interval::from(6))
+        );
+        assert_eq!(
+            Degree::new(6, DEGREE_FLAT).to_interval(),
+            Some(Interval::from(8))
+        );
+        assert_eq!(
+            Degree::new(8, DEGREE_NATURAL).to_interval(),
+            Some(Interval::from(12))
+        );
+        assert_eq!(
+            Degree::new(3, DEGREE_DOUBLESHARP).to_interval(),
+            Some(Interval::from(6))
+        );
+        // Enharmonic spellings share their interval
+        assert_eq!(
+            Degree::new(6, DEGREE_FLAT).to_interval(),
+            SHARP_FIFTH.to_interval()
+        );
+        assert_eq!(Degree::new(1, DEGREE_FLAT).to_interval(), None);
+        assert_eq!(
+            Option::<Interval>::from(Degree::new(15, DEGREE_NATURAL)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_degree_to_interval_matches_from_degree() {
+        let accidentals = [
+            DEGREE_NATURAL,
+            DEGREE_FLAT,
+            DEGREE_DOUBLEFLAT,
+            DEGREE_SHARP,
+            DEGREE_DOUBLESHARP,
+        ];
+        for number in 1..=16 {
+            for accidental in accidentals {
+                let degree = Degree::new(number, accidental);
+                assert_eq!(degree.to_interval(), Interval::from_degree(&degree));
+            }
+        }
+    }
+
+    #[test]
+    fn test_degree_to_interval_const() {
+        const FLAT_SEVENTH_INTERVAL: Option<Interval> = FLAT_SEVENTH.to_interval();
+        assert_eq!(FLAT_SEVENTH_INTERVAL, Some(crate::MINOR_SEVENTH));
+    }
+
+    #[test]
+    fn test_degree_ordering() {
+        assert!(FLAT_THIRD < THIRD);
+        assert!(THIRD < SHARP_FIFTH);
+        assert!(SHARP_FIFTH < FLAT_SEVENTH);
+        assert!(FLAT_SEVENTH < SEVENTH);
+        assert!(DOUBLEFLAT_SEVENTH < FLAT_SEVENTH);
+        assert!(Degree::new(3, DEGREE_DOUBLESHARP) < FLAT_FIFTH);
+        assert!(Degree::new(3, DEGREE_SHARP) < Degree::new(3, DEGREE_DOUBLESHARP));
+        assert_eq!(FIFTH.cmp(&FIFTH), std::cmp::Ordering::Equal);
+        assert_eq!([FIFTH, ROOT, THIRD].iter().copied().max(), Some(FIFTH));
+        assert_eq!([FIFTH, ROOT, THIRD].iter().copied().min(), Some(ROOT));
+    }
+
+    #[test]
+    fn test_degree_sorting() {
+        let mut degrees = vec![SEVENTH, FLAT_THIRD, ROOT, FLAT_SEVENTH, FIFTH, THIRD];
+        degrees.sort();
+        assert_eq!(
+            degrees,
+            vec![ROOT, FLAT_THIRD, THIRD, FIFTH, FLAT_SEVENTH, SEVENTH]
+        );
+
+        let set: std::collections::BTreeSet<Degree> =
+            [NINTH, ROOT, NINTH, FLAT_NINTH].into_iter().collect();
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            vec![ROOT, FLAT_NINTH, NINTH]
+        );
+    }
+
+    const ALL_DEGREES: [Degree; 20] = [
+        ROOT,
+        SECOND,
+        THIRD,
+        FLAT_THIRD,
+        FOURTH,
+        FIFTH,
+        SIXTH,
+        FLAT_FIFTH,
+        SHARP_FIFTH,
+        SEVENTH,
+        FLAT_SEVENTH,
+        DOUBLEFLAT_SEVENTH,
+        NINTH,
+        FLAT_NINTH,
+        SHARP_NINTH,
+        ELEVENTH,
+        SHARP_ELEVENTH,
+        THIRTEENTH,
+        FLAT_THIRTEENTH,
+        Degree::new(3, DEGREE_DOUBLESHARP),
+    ];
+
+    #[test]
+    fn test_degree_display_from_str_round_trip() {
+        for degree in ALL_DEGREES {
+            assert_eq!(degree.to_string().parse::<Degree>(), Ok(degree));
+        }
+        assert_eq!(Degree::new(3, DEGREE_DOUBLESHARP).to_string(), "♯♯3");
+        assert_eq!(DOUBLEFLAT_SEVENTH.to_string(), "♭♭7");
+    }
+
+    #[test]
+    fn test_degree_from_str_ascii() {
+        assert_eq!("#5".parse::<Degree>(), Ok(SHARP_FIFTH));
+        assert_eq!("b3".parse::<Degree>(), Ok(FLAT_THIRD));
+        assert_eq!("bb7".parse::<Degree>(), Ok(DOUBLEFLAT_SEVENTH));
+        assert_eq!(
+            "##3".parse::<Degree>(),
+            Ok(Degree::new(3, DEGREE_DOUBLESHARP))
+        );
+        assert_eq!("1".parse::<Degree>(), Ok(ROOT));
+        assert_eq!("13".parse::<Degree>(), Ok(THIRTEENTH));
+        for degree in ALL_DEGREES {
+            let ascii = format!("{}{}", degree.accidental().to_ascii(), degree.degree());
+            assert_eq!(ascii.parse::<Degree>(), Ok(degree));
+        }
+    }
+
+    #[test]
+    fn test_degree_from_str_errors() {
+        assert_eq!(
+            "".parse::<Degree>(),
+            Err(DegreeParseError::InvalidFormat("".to_string()))
+        );
+        assert_eq!(
+            "♭".parse::<Degree>(),
+            Err(DegreeParseError::InvalidFormat("♭".to_string()))
+        );
+        assert_eq!(
+            "5th".parse::<Degree>(),
+            Err(DegreeParseError::InvalidFormat("5th".to_string()))
+        );
+        assert_eq!(
+            "x5".parse::<Degree>(),
+            Err(DegreeParseError::UnknownAccidental("x".to_string()))
+        );
+        assert_eq!(
+            "b#5".parse::<Degree>(),
+            Err(DegreeParseError::UnknownAccidental("b#".to_string()))
+        );
+        assert_eq!(
+            "0".parse::<Degree>(),
+            Err(DegreeParseError::DegreeOutOfRange(0))
+        );
+        assert_eq!(
+            "17".parse::<Degree>(),
+            Err(DegreeParseError::DegreeOutOfRange(17))
+        );
+        assert_eq!(
+            DegreeParseError::UnknownAccidental("x".to_string()).to_string(),
+            "unknown degree accidental: \"x\""
+        );
+    }
 }
@@ -0,0 +1,163 @@
+//! Functional Harmony
+//!
+//! This module provides helpers for functional harmony, such as computing
+//! secondary dominants and the resolution of dominant chords.
+//!
+//! Pitch classes returned for a `Scale` are expressed relative to a tonic of C,
+//! since a `Scale` only describes intervals above an implicit root.
+
+pub mod circle_of_fifths;
+
+use crate::{
+    Chord, KeySignature, PitchClass, Scale, DOMINANT_SEVENTH, PERFECT_FIFTH, PERFECT_FOURTH,
+};
+
+/// Computes the secondary dominant of a scale degree
+///
+/// The secondary dominant of a degree is the dominant seventh chord built a
+/// perfect fifth above that degree (written V7/x). For example the secondary
+/// dominant of the second degree of C major is A7 (V7/ii).
+///
+/// # Arguments
+/// * `target_degree` - The one-based scale degree being tonicized (1 is the tonic)
+/// * `scale` - The scale the degree belongs to, taken with a tonic of C
+///
+/// # Returns
+/// The root of the secondary dominant and its dominant seventh chord
+///
+/// # Panics
+/// This method will panic if `target_degree` is 0 or greater than the number
+/// of tones in the scale
+///
+/// # Example
+/// ```rust
+/// use muzze_std::{harmony::secondary_dominant, PitchClass, MAJOR};
+///
+/// let (root, chord) = secondary_dominant(2, MAJOR);
+/// assert_eq!(root, PitchClass::A);
+/// assert_eq!(chord.to_string(), "R-3-5-♭7");
+/// ```
+pub fn secondary_dominant(target_degree: u8, scale: Scale) -> (PitchClass, Chord) {
+    let target = (target_degree as usize)
+        .checked_sub(1)
+        .and_then(|index| scale.get_interval_at(index))
+        .expect("The target degree must be a degree of the scale");
+
+    let root = PitchClass::from(target.inner()).transpose(PERFECT_FIFTH.inner());
+    (root, DOMINANT_SEVENTH)
+}
+
+/// Computes the pitch class a dominant chord resolves to
+///
+/// A dominant chord resolves down a perfect fifth (up a perfect fourth) to its
+/// tonic, which is the standard authentic resolution. When that target is not
+/// diatonic to the key, the dominant is a chromatic dominant (such as the tritone
+/// substitute ♭II7 or the backdoor dominant ♭VII7) and resolves to the tonic of
+/// the key instead.
+///
+/// # Arguments
+/// * `dominant_root` - The root of the dominant chord
+/// * `key` - The key the progression is in
+///
+/// # Returns
+/// The pitch class the dominant chord resolves to
+///
+/// # Example
+/// ```rust
+/// use muzze_std::{harmony::resolve_dominant, KeySignature, PitchClass};
+///
+/// let c_major = KeySignature::new(0);
+/// assert_eq!(resolve_dominant(PitchClass::G, c_major), PitchClass::C);
+/// assert_eq!(resolve_dominant(PitchClass::A, c_major), PitchClass::D);
+/// assert_eq!(resolve_dominant(PitchClass::Cs, c_major), PitchClass::C);
+/// ```
+pub fn resolve_dominant(dominant_root: PitchClass, key: KeySignature) -> PitchClass {
+    let target = dominant_root.transpose(PERFECT_FOURTH.inner());
+    if key.contains(target) {
+        target
+    } else {
+        key.tonic()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HARMONIC_MINOR, MAJOR, NATURAL_MINOR};
+
+    #[test]
+    fn test_secondary_dominant_of_the_dominant() {
+        // V7/V in C major is D7: D is a fifth above G, the fifth degree
+        let (root, chord) = secondary_dominant(5, MAJOR);
+        assert_eq!(root, PitchClass::D);
+        assert_eq!(chord.to_string(), DOMINANT_SEVENTH.to_string());
+    }
+
+    #[test]
+    fn test_secondary_dominants_major() {
+        let roots = (1..=7)
+            .map(|degree| secondary_dominant(degree, MAJOR).0)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            roots,
+            vec![
+                PitchClass::G,  // V7/I
+                PitchClass::A,  // V7/ii
+                PitchClass::B,  // V7/iii
+                PitchClass::C,  // V7/IV
+                PitchClass::D,  // V7/V
+                PitchClass::E,  // V7/vi
+                PitchClass::Fs, // V7/vii
+            ]
+        );
+    }
+
+    #[test]
+    fn test_secondary_dominant_minor() {
+        // V7/III in C minor: E♭ is the third degree and B♭7 its dominant
+        let (root, _) = secondary_dominant(3, NATURAL_MINOR);
+        assert_eq!(root, PitchClass::As);
+        let (root, _) = secondary_dominant(6, HARMONIC_MINOR);
+        assert_eq!(root, PitchClass::Ds);
+    }
+
+    #[test]
+    #[should_panic(expected = "The target degree must be a degree of the scale")]
+    fn test_secondary_dominant_invalid_degree() {
+        let _ = secondary_dominant(8, MAJOR);
+    }
+
+    #[test]
+    #[should_panic(expected = "The target degree must be a degree of the scale")]
+    fn test_secondary_dominant_zero_degree() {
+        let _ = secondary_dominant(0, MAJOR);
+    }
+
+    #[test]
+    fn test_resolve_dominant_authentic() {
+        let c_major = KeySignature::new(0);
+        assert_eq!(resolve_dominant(PitchClass::G, c_major), PitchClass::C);
+
+        // Every secondary dominant resolves to the degree it tonicizes
+        for degree in 2..=6 {
+            let (root, _) = secondary_dominant(degree, MAJOR);
+            let target = MAJOR.get_interval_at(degree as usize - 1).unwrap();
+            assert_eq!(
+                resolve_dominant(root, c_major),
+                PitchClass::from(target.inner())
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve_dominant_chromatic() {
+        let c_major = KeySignature::new(0);
+        // Tritone substitute D♭7 and backdoor dominant B♭7 both resolve to C
+        assert_eq!(resolve_dominant(PitchClass::Cs, c_major), PitchClass::C);
+        assert_eq!(resolve_dominant(PitchClass::As, c_major), PitchClass::C);
+
+        let g_major = KeySignature::new(1);
+        assert_eq!(resolve_dominant(PitchClass::D, g_major), PitchClass::G);
+        assert_eq!(resolve_dominant(PitchClass::Gs, g_major), PitchClass::G);
+    }
+}
@@ -0,0 +1,174 @@
+//! Circle of Fifths
+//!
+//! The circle of fifths orders the twelve major keys by ascending perfect
+//! fifths: C, G, D, A, E, B, F♯, D♭, A♭, E♭, B♭ and F. Keys next to each other
+//! on the circle differ by a single accidental, so the distance between two
+//! keys on the circle measures how closely related they are.
+
+use crate::{PitchClass, PERFECT_FIFTH, PERFECT_FOURTH};
+
+/// Returns the position of a tonic on the circle of fifths
+///
+/// # Arguments
+/// * `tonic` - The tonic of the major key
+///
+/// # Returns
+/// The position of the key, from 0 for C, 1 for G, up to 11 for F
+///
+/// # Example
+/// ```rust
+/// use muzze_std::{harmony::circle_of_fifths::position, PitchClass};
+///
+/// assert_eq!(position(PitchClass::C), 0);
+/// assert_eq!(position(PitchClass::G), 1);
+/// assert_eq!(position(PitchClass::F), 11);
+/// ```
+pub const fn position(tonic: PitchClass) -> u8 {
+    // Seven semitones is a fifth, and 7 * 7 = 49 = 1 (mod 12)
+    tonic.inner() * PERFECT_FIFTH.inner() % 12
+}
+
+/// Returns the tonic at a position of the circle of fifths
+///
+/// This is the inverse of `position`. Positions beyond 11 wrap around the
+/// circle.
+///
+/// # Arguments
+/// * `position` - The position on the circle, 0 being C
+///
+/// # Returns
+/// The tonic of the major key at that position
+///
+/// # Example
+/// ```rust
+/// use muzze_std::{harmony::circle_of_fifths::tonic_at, PitchClass};
+///
+/// assert_eq!(tonic_at(0), PitchClass::C);
+/// assert_eq!(tonic_at(6), PitchClass::Fs);
+/// assert_eq!(tonic_at(13), PitchClass::G);
+/// ```
+pub const fn tonic_at(position: u8) -> PitchClass {
+    PitchClass::from_u8(position % 12 * PERFECT_FIFTH.inner())
+}
+
+/// Returns the next key clockwise on the circle of fifths, a fifth up
+///
+/// # Example
+/// ```rust
+/// use muzze_std::{harmony::circle_of_fifths::clockwise_neighbor, PitchClass};
+///
+/// assert_eq!(clockwise_neighbor(PitchClass::C), PitchClass::G);
+/// assert_eq!(clockwise_neighbor(PitchClass::F), PitchClass::C);
+/// ```
+pub const fn clockwise_neighbor(tonic: PitchClass) -> PitchClass {
+    tonic.transpose(PERFECT_FIFTH.inner())
+}
+
+/// Returns the next key counterclockwise on the circle of fifths, a fifth down
+///
+/// # Example
+/// ```rust
+/// use muzze_std::{harmony::circle_of_fifths::counterclockwise_neighbor, PitchClass};
+///
+/// assert_eq!(counterclockwise_neighbor(PitchClass::C), PitchClass::F);
+/// assert_eq!(counterclockwise_neighbor(PitchClass::G), PitchClass::C);
+/// ```
+pub const fn counterclockwise_neighbor(tonic: PitchClass) -> PitchClass {
+    tonic.transpose(PERFECT_FOURTH.inner())
+}
+
+/// Returns the number of steps between two keys on the circle of fifths
+///
+/// The shortest way around the circle is taken, in either direction.
+///
+/// # Arguments
+/// * `a` - The tonic of the first major key
+/// * `b` - The tonic of the second major key
+///
+/// # Returns
+/// The number of fifths between the keys, from 0 to 6
+///
+/// # Example
+/// ```rust
+/// use muzze_std::{harmony::circle_of_fifths::distance, PitchClass};
+///
+/// assert_eq!(distance(PitchClass::C, PitchClass::G), 1);
+/// assert_eq!(distance(PitchClass::C, PitchClass::F), 1);
+/// assert_eq!(distance(PitchClass::C, PitchClass::Fs), 6);
+/// ```
+pub const fn distance(a: PitchClass, b: PitchClass) -> u8 {
+    let steps = (position(b) + 12 - position(a)) % 12;
+    if steps > 6 {
+        12 - steps
+    } else {
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CIRCLE: [PitchClass; 12] = [
+        PitchClass::C,
+        PitchClass::G,
+        PitchClass::D,
+        PitchClass::A,
+        PitchClass::E,
+        PitchClass::B,
+        PitchClass::Fs,
+        PitchClass::Cs,
+        PitchClass::Gs,
+        PitchClass::Ds,
+        PitchClass::As,
+        PitchClass::F,
+    ];
+
+    #[test]
+    fn test_position() {
+        for (index, tonic) in CIRCLE.iter().enumerate() {
+            assert_eq!(position(*tonic), index as u8);
+        }
+        assert_eq!(position(PitchClass::G), 1);
+    }
+
+    #[test]
+    fn test_tonic_at() {
+        for (index, tonic) in CIRCLE.iter().enumerate() {
+            assert_eq!(tonic_at(index as u8), *tonic);
+            assert_eq!(tonic_at(index as u8 + 12), *tonic);
+        }
+        for tonic in PitchClass::ALL {
+            assert_eq!(tonic_at(position(tonic)), tonic);
+        }
+    }
+
+    #[test]
+    fn test_neighbors() {
+        assert_eq!(clockwise_neighbor(PitchClass::C), PitchClass::G);
+        assert_eq!(counterclockwise_neighbor(PitchClass::C), PitchClass::F);
+        for tonic in PitchClass::ALL {
+            assert_eq!(
+                position(clockwise_neighbor(tonic)),
+                (position(tonic) + 1) % 12
+            );
+            assert_eq!(counterclockwise_neighbor(clockwise_neighbor(tonic)), tonic);
+        }
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance(PitchClass::C, PitchClass::C), 0);
+        assert_eq!(distance(PitchClass::C, PitchClass::Fs), 6);
+        assert_eq!(distance(PitchClass::Fs, PitchClass::C), 6);
+        assert_eq!(distance(PitchClass::C, PitchClass::D), 2);
+        assert_eq!(distance(PitchClass::C, PitchClass::As), 2);
+        assert_eq!(distance(PitchClass::E, PitchClass::Ds), 5);
+        for a in PitchClass::ALL {
+            for b in PitchClass::ALL {
+                assert!(distance(a, b) <= 6);
+                assert_eq!(distance(a, b), distance(b, a));
+            }
+        }
+    }
+}
@@ -5,7 +5,7 @@
 
 use std::fmt::Display;
 
-use crate::Step;
+use crate::{Degree, Step};
 
 /// Represents a musical interval in semitones
 ///
@@ -289,6 +289,468 @@ impl Interval {
     pub const fn add_step(self, step: Step) -> Self {
         Self(self.0 + step.inner())
     }
+
+    /// Returns the compound form of the interval, one octave higher
+    ///
+    /// # Returns
+    /// * `Some(Interval)` - A new Interval with 12 semitones added
+    /// * `None` - When the result does not fit in 255 semitones
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_THIRD, OCTAVE, UNISON};
+    /// assert_eq!(MAJOR_THIRD.compound(), Some(Interval::from(16)));
+    /// assert_eq!(UNISON.compound(), Some(OCTAVE));
+    /// assert_eq!(Interval::from(250).compound(), None);
+    /// ```
+    #[inline]
+    pub const fn compound(&self) -> Option<Interval> {
+        match self.0.checked_add(12) {
+            Some(semitones) => Some(Self(semitones)),
+            None => None,
+        }
+    }
+
+    /// Builds an interval from a number of octaves and a simple interval
+    ///
+    /// # Arguments
+    /// * `octaves` - The number of full octaves to span
+    /// * `simple` - The interval to add on top of the octaves
+    ///
+    /// # Returns
+    /// * `Some(Interval)` - The interval spanning `octaves` octaves plus `simple`
+    /// * `None` - When the result does not fit in 255 semitones
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_THIRD, PERFECT_FIFTH, UNISON};
+    /// assert_eq!(Interval::from_octave_and_simple(1, MAJOR_THIRD), Some(Interval::from(16)));
+    /// assert_eq!(Interval::from_octave_and_simple(0, PERFECT_FIFTH), Some(PERFECT_FIFTH));
+    /// assert_eq!(Interval::from_octave_and_simple(22, UNISON), None);
+    /// ```
+    #[inline]
+    pub const fn from_octave_and_simple(octaves: u8, simple: Interval) -> Option<Interval> {
+        match octaves.checked_mul(12) {
+            Some(semitones) => match semitones.checked_add(simple.0) {
+                Some(semitones) => Some(Self(semitones)),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Returns the number of full octaves spanned by the interval
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_SEVENTH, OCTAVE};
+    /// assert_eq!(MAJOR_SEVENTH.octave_count(), 0);
+    /// assert_eq!(OCTAVE.octave_count(), 1);
+    /// assert_eq!(Interval::from(24).octave_count(), 2);
+    /// ```
+    #[inline]
+    pub const fn octave_count(&self) -> u8 {
+        self.0 / 12
+    }
+
+    /// Returns the interval reduced by whole octaves
+    ///
+    /// Whole octaves reduce to a unison, so the result is always between
+    /// 0 and 11 semitones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_THIRD, OCTAVE, UNISON};
+    /// assert_eq!(Interval::from(16).simple_part(), MAJOR_THIRD);
+    /// assert_eq!(OCTAVE.simple_part(), UNISON);
+    /// ```
+    #[inline]
+    pub const fn simple_part(&self) -> Self {
+        Self(self.0 % 12)
+    }
+
+    /// Returns the inversion of the interval within the octave
+    ///
+    /// The inversion of an interval is the interval that completes it to an
+    /// octave, so a major third inverts to a minor sixth and a unison to an
+    /// octave. Compound intervals are first reduced by whole octaves until
+    /// they fit in a single octave.
+    ///
+    /// # Returns
+    /// The interval that adds up to an octave with the (reduced) interval
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_THIRD, MINOR_SIXTH, OCTAVE, UNISON};
+    /// assert_eq!(MAJOR_THIRD.inversion(), MINOR_SIXTH);
+    /// assert_eq!(UNISON.inversion(), OCTAVE);
+    /// assert_eq!(Interval::from(16).inversion(), MINOR_SIXTH);
+    /// ```
+    #[inline]
+    pub const fn inversion(&self) -> Self {
+        let simple = if self.0 > 12 {
+            (self.0 - 1) % 12 + 1
+        } else {
+            self.0
+        };
+        Self(12 - simple)
+    }
+
+    /// Returns true if the two intervals add up to an octave
+    ///
+    /// # Arguments
+    /// * `other` - The interval to check against
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{MAJOR_THIRD, PERFECT_FIFTH, PERFECT_FOURTH};
+    /// assert!(PERFECT_FIFTH.is_inverse_of(PERFECT_FOURTH));
+    /// assert!(!PERFECT_FIFTH.is_inverse_of(MAJOR_THIRD));
+    /// ```
+    #[inline]
+    pub const fn is_inverse_of(&self, other: Interval) -> bool {
+        self.0 as u16 + other.0 as u16 == 12
+    }
+
+    /// Returns the interval widened by one semitone
+    ///
+    /// Intervals are stored as semitones, so the result is displayed with the
+    /// abbreviation of its semitone count: the augmented fifth of a perfect
+    /// fifth shows as "m6".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{AUGMENTED_FOURTH, MINOR_SIXTH, PERFECT_FIFTH, PERFECT_FOURTH};
+    /// assert_eq!(PERFECT_FOURTH.augmented(), AUGMENTED_FOURTH);
+    /// assert_eq!(PERFECT_FIFTH.augmented(), MINOR_SIXTH);
+    /// ```
+    #[inline]
+    pub const fn augmented(self) -> Interval {
+        Self(self.0.saturating_add(1))
+    }
+
+    /// Returns the interval narrowed by one semitone
+    ///
+    /// The unison cannot be narrowed and is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{DIMINISHED_FIFTH, PERFECT_FIFTH, UNISON};
+    /// assert_eq!(PERFECT_FIFTH.diminished(), DIMINISHED_FIFTH);
+    /// assert_eq!(UNISON.diminished(), UNISON);
+    /// ```
+    #[inline]
+    pub const fn diminished(self) -> Interval {
+        Self(self.0.saturating_sub(1))
+    }
+
+    /// Returns the next perfect interval above this one
+    ///
+    /// The perfect intervals are the unison, the fourth, the fifth and the
+    /// octave, and their compounds (11th, 12th, double octave, ...).
+    ///
+    /// # Returns
+    /// The smallest perfect interval wider than this one, or `None` if it
+    /// does not fit in 255 semitones
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_THIRD, OCTAVE, PERFECT_FIFTH, PERFECT_FOURTH};
+    /// assert_eq!(MAJOR_THIRD.perfect_above(), Some(PERFECT_FOURTH));
+    /// assert_eq!(PERFECT_FOURTH.perfect_above(), Some(PERFECT_FIFTH));
+    /// assert_eq!(PERFECT_FIFTH.perfect_above(), Some(OCTAVE));
+    /// assert_eq!(OCTAVE.perfect_above(), Some(Interval::from(17)));
+    /// ```
+    pub const fn perfect_above(&self) -> Option<Interval> {
+        let next = match self.0 % 12 {
+            0..=4 => 5,
+            5 | 6 => 7,
+            _ => 12,
+        };
+        let semitones = (self.0 / 12) as u16 * 12 + next;
+        if semitones > u8::MAX as u16 {
+            None
+        } else {
+            Some(Self(semitones as u8))
+        }
+    }
+
+    /// Returns the simple interval between two MIDI notes
+    ///
+    /// The interval is reduced to a single octave. When `high` is lower than
+    /// `low`, the result wraps around the octave, which yields the inversion
+    /// of the descending interval.
+    ///
+    /// # Arguments
+    /// * `low` - The lower MIDI note
+    /// * `high` - The higher MIDI note
+    ///
+    /// # Returns
+    /// The interval from `low` up to `high`, between 0 and 11 semitones
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_THIRD, MINOR_SIXTH, UNISON};
+    /// assert_eq!(Interval::between(60, 64), MAJOR_THIRD);
+    /// assert_eq!(Interval::between(64, 60), MINOR_SIXTH);
+    /// assert_eq!(Interval::between(60, 72), UNISON);
+    /// ```
+    #[inline]
+    pub const fn between(low: u8, high: u8) -> Self {
+        Self((high as i16 - low as i16).rem_euclid(12) as u8)
+    }
+
+    /// Returns the MIDI note an interval above a root note
+    ///
+    /// # Arguments
+    /// * `root` - The MIDI note to start from
+    /// * `interval` - The interval to move up
+    ///
+    /// # Returns
+    /// The MIDI note above the root, clamped to 127
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, PERFECT_FIFTH};
+    /// assert_eq!(Interval::above(60, PERFECT_FIFTH), 67);
+    /// assert_eq!(Interval::above(125, PERFECT_FIFTH), 127);
+    /// ```
+    #[inline]
+    pub const fn above(root: u8, interval: Interval) -> u8 {
+        let note = root.saturating_add(interval.0);
+        if note > 127 {
+            127
+        } else {
+            note
+        }
+    }
+
+    /// Returns the MIDI note an interval below a root note
+    ///
+    /// # Arguments
+    /// * `root` - The MIDI note to start from
+    /// * `interval` - The interval to move down
+    ///
+    /// # Returns
+    /// The MIDI note below the root, clamped to 0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_THIRD};
+    /// assert_eq!(Interval::below(60, MAJOR_THIRD), 56);
+    /// assert_eq!(Interval::below(2, MAJOR_THIRD), 0);
+    /// ```
+    #[inline]
+    pub const fn below(root: u8, interval: Interval) -> u8 {
+        root.saturating_sub(interval.0)
+    }
+
+    /// Returns the interval from the root to a chord degree
+    ///
+    /// Natural degrees follow the major scale (2 is a major second, 7 a major
+    /// seventh, 9 a major ninth and so on), and the accidental lowers or raises
+    /// the interval by its `semitone_offset`: a flat lowers it by one semitone,
+    /// a double flat by two, and a sharp or double sharp raises it by one or two.
+    ///
+    /// # Arguments
+    /// * `degree` - The chord degree to convert
+    ///
+    /// # Returns
+    /// The interval above the root, or `None` for degrees above the 13th and
+    /// for altered roots that would fall below the root
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, FLAT_SEVENTH, SHARP_ELEVENTH, MINOR_SEVENTH};
+    /// assert_eq!(Interval::from_degree(&FLAT_SEVENTH), Some(MINOR_SEVENTH));
+    /// assert_eq!(Interval::from_degree(&SHARP_ELEVENTH), Some(Interval::from(18)));
+    /// ```
+    pub const fn from_degree(degree: &Degree) -> Option<Interval> {
+        let natural: i8 = match degree.degree() {
+            1 => 0,
+            2 => 2,
+            3 => 4,
+            4 => 5,
+            5 => 7,
+            6 => 9,
+            7 => 11,
+            8 => 12,
+            9 => 14,
+            10 => 16,
+            11 => 17,
+            12 => 19,
+            13 => 21,
+            _ => return None,
+        };
+        let semitones = natural + degree.accidental().semitone_offset();
+        if semitones < 0 {
+            None
+        } else {
+            Some(Interval(semitones as u8))
+        }
+    }
+
+    /// Parses an interval from one of several common notations
+    ///
+    /// The accepted notations are:
+    /// - the case sensitive abbreviations of `Display` ("M3", "m3", "P5", "A4", ...)
+    /// - spelled-out names, with ordinal numbers or words ("major third", "major 3rd")
+    /// - short names ("maj3", "min3", "perf5", "aug4", "dim5") and the German
+    ///   abbreviations ("gr3" for große Terz, "kl3", "r5")
+    /// - raw semitone counts ("4", "7 semitones")
+    ///
+    /// Names are matched case insensitively and extra whitespace is ignored.
+    /// This is more permissive than a strict parser, so it only reports
+    /// whether the input was understood.
+    ///
+    /// # Arguments
+    /// * `s` - The text to parse
+    ///
+    /// # Returns
+    /// The interval, or `None` if the input is not recognized
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{Interval, MAJOR_THIRD, MINOR_THIRD, PERFECT_FIFTH};
+    /// assert_eq!(Interval::from_str_lenient("major third"), Some(MAJOR_THIRD));
+    /// assert_eq!(Interval::from_str_lenient("m3"), Some(MINOR_THIRD));
+    /// assert_eq!(Interval::from_str_lenient("7 semitones"), Some(PERFECT_FIFTH));
+    /// assert_eq!(Interval::from_str_lenient("third"), None);
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Option<Interval> {
+        let s = s.trim();
+        if let Some((_, interval)) = INTERVAL_ABBREVIATIONS.iter().find(|(name, _)| *name == s) {
+            return Some(*interval);
+        }
+
+        let name = s
+            .to_lowercase()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if let Ok(index) = INTERVAL_NAMES.binary_search_by(|(key, _)| (*key).cmp(name.as_str())) {
+            return Some(INTERVAL_NAMES[index].1);
+        }
+
+        let count = name
+            .strip_suffix("semitones")
+            .or_else(|| name.strip_suffix("semitone"))
+            .unwrap_or(&name);
+        count.trim_end().parse::<u8>().ok().map(Interval)
+    }
+}
+
+/// Case sensitive interval abbreviations accepted by `Interval::from_str_lenient`
+const INTERVAL_ABBREVIATIONS: [(&str, Interval); 14] = [
+    ("P1", UNISON),
+    ("m2", MINOR_SECOND),
+    ("M2", MAJOR_SECOND),
+    ("m3", MINOR_THIRD),
+    ("M3", MAJOR_THIRD),
+    ("P4", PERFECT_FOURTH),
+    ("A4", AUGMENTED_FOURTH),
+    ("d5", DIMINISHED_FIFTH),
+    ("P5", PERFECT_FIFTH),
+    ("m6", MINOR_SIXTH),
+    ("M6", MAJOR_SIXTH),
+    ("m7", MINOR_SEVENTH),
+    ("M7", MAJOR_SEVENTH),
+    ("P8", OCTAVE),
+];
+
+/// Lowercase interval names accepted by `Interval::from_str_lenient`, sorted by name
+const INTERVAL_NAMES: [(&str, Interval); 64] = [
+    ("aug4", AUGMENTED_FOURTH),
+    ("augmented 4th", AUGMENTED_FOURTH),
+    ("augmented fourth", AUGMENTED_FOURTH),
+    ("dim5", DIMINISHED_FIFTH),
+    ("diminished 5th", DIMINISHED_FIFTH),
+    ("diminished fifth", DIMINISHED_FIFTH),
+    ("fifth", PERFECT_FIFTH),
+    ("fourth", PERFECT_FOURTH),
+    ("gr2", MAJOR_SECOND),
+    ("gr3", MAJOR_THIRD),
+    ("gr6", MAJOR_SIXTH),
+    ("gr7", MAJOR_SEVENTH),
+    ("half step", MINOR_SECOND),
+    ("kl2", MINOR_SECOND),
+    ("kl3", MINOR_THIRD),
+    ("kl6", MINOR_SIXTH),
+    ("kl7", MINOR_SEVENTH),
+    ("maj2", MAJOR_SECOND),
+    ("maj3", MAJOR_THIRD),
+    ("maj6", MAJOR_SIXTH),
+    ("maj7", MAJOR_SEVENTH),
+    ("major 2nd", MAJOR_SECOND),
+    ("major 3rd", MAJOR_THIRD),
+    ("major 6th", MAJOR_SIXTH),
+    ("major 7th", MAJOR_SEVENTH),
+    ("major second", MAJOR_SECOND),
+    ("major seventh", MAJOR_SEVENTH),
+    ("major sixth", MAJOR_SIXTH),
+    ("major third", MAJOR_THIRD),
+    ("min2", MINOR_SECOND),
+    ("min3", MINOR_THIRD),
+    ("min6", MINOR_SIXTH),
+    ("min7", MINOR_SEVENTH),
+    ("minor 2nd", MINOR_SECOND),
+    ("minor 3rd", MINOR_THIRD),
+    ("minor 6th", MINOR_SIXTH),
+    ("minor 7th", MINOR_SEVENTH),
+    ("minor second", MINOR_SECOND),
+    ("minor seventh", MINOR_SEVENTH),
+    ("minor sixth", MINOR_SIXTH),
+    ("minor third", MINOR_THIRD),
+    ("octave", OCTAVE),
+    ("perf1", UNISON),
+    ("perf4", PERFECT_FOURTH),
+    ("perf5", PERFECT_FIFTH),
+    ("perf8", OCTAVE),
+    ("perfect 4th", PERFECT_FOURTH),
+    ("perfect 5th", PERFECT_FIFTH),
+    ("perfect 8th", OCTAVE),
+    ("perfect fifth", PERFECT_FIFTH),
+    ("perfect fourth", PERFECT_FOURTH),
+    ("perfect octave", OCTAVE),
+    ("perfect unison", UNISON),
+    ("prime", UNISON),
+    ("r4", PERFECT_FOURTH),
+    ("r5", PERFECT_FIFTH),
+    ("r8", OCTAVE),
+    ("semitone", MINOR_SECOND),
+    ("tone", MAJOR_SECOND),
+    ("tritone", AUGMENTED_FOURTH),
+    ("tt", AUGMENTED_FOURTH),
+    ("unison", UNISON),
+    ("v5", DIMINISHED_FIFTH),
+    ("whole step", MAJOR_SECOND),
+];
+
+impl Default for Interval {
+    /// Returns the unison (0 semitones)
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Interval, UNISON};
+    /// assert_eq!(Interval::default(), UNISON);
+    /// ```
+    fn default() -> Self {
+        UNISON
+    }
 }
 
 impl From<Interval> for u8 {
@@ -659,4 +1121,281 @@ mod tests {
         assert_eq!(Interval::from(15).inner(), 15);
         assert_eq!(Interval::from(255).inner(), 255);
     }
+
+    #[test]
+    fn test_between() {
+        assert_eq!(Interval::between(60, 64), MAJOR_THIRD);
+        assert_eq!(Interval::between(60, 67), PERFECT_FIFTH);
+        assert_eq!(Interval::between(60, 60), UNISON);
+        // Compound intervals are reduced to a single octave
+        assert_eq!(Interval::between(60, 76), MAJOR_THIRD);
+        // Descending intervals wrap around to their inversion
+        assert_eq!(Interval::between(67, 60), PERFECT_FOURTH);
+        assert_eq!(Interval::between(61, 60), MAJOR_SEVENTH);
+    }
+
+    #[test]
+    fn test_above() {
+        assert_eq!(Interval::above(60, PERFECT_FIFTH), 67);
+        assert_eq!(Interval::above(60, OCTAVE), 72);
+        assert_eq!(Interval::above(127, MINOR_SECOND), 127);
+        assert_eq!(Interval::above(255, OCTAVE), 127);
+    }
+
+    #[test]
+    fn test_below() {
+        assert_eq!(Interval::below(60, MAJOR_THIRD), 56);
+        assert_eq!(Interval::below(60, UNISON), 60);
+        assert_eq!(Interval::below(0, MINOR_SECOND), 0);
+    }
+
+    #[test]
+    fn test_from_degree() {
+        use crate::*;
+
+        let degrees = [
+            (ROOT, 0),
+            (SECOND, 2),
+            (FLAT_THIRD, 3),
+            (THIRD, 4),
+            (FOURTH, 5),
+            (FLAT_FIFTH, 6),
+            (FIFTH, 7),
+            (SHARP_FIFTH, 8),
+            (SIXTH, 9),
+            (DOUBLEFLAT_SEVENTH, 9),
+            (FLAT_SEVENTH, 10),
+            (SEVENTH, 11),
+            (FLAT_NINTH, 13),
+            (NINTH, 14),
+            (SHARP_NINTH, 15),
+            (ELEVENTH, 17),
+            (SHARP_ELEVENTH, 18),
+            (FLAT_THIRTEENTH, 20),
+            (THIRTEENTH, 21),
+        ];
+        for (degree, semitones) in degrees {
+            assert_eq!(
+                Interval::from_degree(&degree),
+                Some(Interval::from(semitones)),
+                "degree {degree}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_degree_unmapped() {
+        use crate::{DEGREE_FLAT, DEGREE_NATURAL};
+
+        assert_eq!(
+            Interval::from_degree(&Degree::new(14, DEGREE_NATURAL)),
+            None
+        );
+        assert_eq!(Interval::from_degree(&Degree::new(1, DEGREE_FLAT)), None);
+    }
+
+    #[test]
+    fn test_compound() {
+        assert_eq!(MAJOR_THIRD.compound(), Some(Interval(16)));
+        assert_eq!(PERFECT_FIFTH.compound(), Some(Interval(19)));
+        assert_eq!(UNISON.compound(), Some(OCTAVE));
+        assert_eq!(OCTAVE.compound(), Some(Interval(24)));
+        assert_eq!(Interval(243).compound(), Some(Interval(255)));
+        assert_eq!(Interval(244).compound(), None);
+        assert_eq!(Interval(250).compound(), None);
+    }
+
+    #[test]
+    fn test_from_octave_and_simple() {
+        assert_eq!(
+            Interval::from_octave_and_simple(1, MAJOR_THIRD),
+            Some(Interval(16))
+        );
+        assert_eq!(
+            Interval::from_octave_and_simple(0, MINOR_SIXTH),
+            Some(MINOR_SIXTH)
+        );
+        assert_eq!(
+            Interval::from_octave_and_simple(2, UNISON),
+            Some(Interval(24))
+        );
+        assert_eq!(
+            Interval::from_octave_and_simple(1, MAJOR_THIRD),
+            MAJOR_THIRD.compound()
+        );
+    }
+
+    #[test]
+    fn test_from_octave_and_simple_overflow() {
+        assert_eq!(
+            Interval::from_octave_and_simple(21, Interval(3)),
+            Some(Interval(255))
+        );
+        assert_eq!(Interval::from_octave_and_simple(21, MAJOR_THIRD), None);
+        assert_eq!(Interval::from_octave_and_simple(22, UNISON), None);
+        assert_eq!(Interval::from_octave_and_simple(u8::MAX, UNISON), None);
+    }
+
+    #[test]
+    fn test_octave_count_and_simple_part() {
+        assert_eq!(Interval(16).simple_part(), MAJOR_THIRD);
+        assert_eq!(Interval(16).octave_count(), 1);
+        assert_eq!(Interval(24).octave_count(), 2);
+        assert_eq!(Interval(24).simple_part(), UNISON);
+        assert_eq!(MAJOR_SEVENTH.octave_count(), 0);
+        assert_eq!(MAJOR_SEVENTH.simple_part(), MAJOR_SEVENTH);
+
+        for semitones in 0..=u8::MAX {
+            let interval = Interval(semitones);
+            assert_eq!(
+                Interval::from_octave_and_simple(interval.octave_count(), interval.simple_part()),
+                Some(interval)
+            );
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Interval::default(), UNISON);
+        assert_eq!(Interval::default().inner(), 0);
+    }
+
+    #[test]
+    fn test_inversion() {
+        assert_eq!(MAJOR_THIRD.inversion(), MINOR_SIXTH);
+        assert_eq!(PERFECT_FIFTH.inversion(), PERFECT_FOURTH);
+        assert_eq!(UNISON.inversion(), OCTAVE);
+        assert_eq!(OCTAVE.inversion(), UNISON);
+        assert_eq!(MINOR_SECOND.inversion(), MAJOR_SEVENTH);
+        assert_eq!(AUGMENTED_FOURTH.inversion(), DIMINISHED_FIFTH);
+
+        // Compound intervals are reduced first
+        assert_eq!(Interval(16).inversion(), MINOR_SIXTH);
+        assert_eq!(Interval(19).inversion(), PERFECT_FOURTH);
+        assert_eq!(Interval(24).inversion(), UNISON);
+
+        for semitones in 0..=12 {
+            assert_eq!(
+                Interval(semitones).inversion().inversion(),
+                Interval(semitones)
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_inverse_of() {
+        assert!(PERFECT_FIFTH.is_inverse_of(PERFECT_FOURTH));
+        assert!(PERFECT_FOURTH.is_inverse_of(PERFECT_FIFTH));
+        assert!(UNISON.is_inverse_of(OCTAVE));
+        assert!(AUGMENTED_FOURTH.is_inverse_of(DIMINISHED_FIFTH));
+        assert!(!MAJOR_THIRD.is_inverse_of(MAJOR_SIXTH));
+        assert!(!Interval(255).is_inverse_of(Interval(13)));
+
+        for semitones in 0..=12 {
+            let interval = Interval(semitones);
+            assert!(interval.is_inverse_of(interval.inversion()));
+        }
+    }
+
+    #[test]
+    fn test_from_str_lenient() {
+        for text in [
+            "major third",
+            "M3",
+            "4",
+            "maj3",
+            "Gr3",
+            "Major  3rd",
+            "4 semitones",
+        ] {
+            assert_eq!(
+                Interval::from_str_lenient(text),
+                Some(MAJOR_THIRD),
+                "{text}"
+            );
+        }
+        for text in [
+            "perfect fifth",
+            "P5",
+            "7",
+            "perf5",
+            "7 semitones",
+            " Perfect Fifth ",
+        ] {
+            assert_eq!(
+                Interval::from_str_lenient(text),
+                Some(PERFECT_FIFTH),
+                "{text}"
+            );
+        }
+        assert_eq!(Interval::from_str_lenient("m3"), Some(MINOR_THIRD));
+        assert_eq!(
+            Interval::from_str_lenient("tritone"),
+            Some(AUGMENTED_FOURTH)
+        );
+        assert_eq!(Interval::from_str_lenient("1 semitone"), Some(MINOR_SECOND));
+        assert_eq!(Interval::from_str_lenient("15"), Some(Interval(15)));
+    }
+
+    #[test]
+    fn test_from_str_lenient_covers_intervals() {
+        assert!(INTERVAL_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+        for semitones in 0..=12 {
+            let interval = Interval(semitones);
+            assert!(INTERVAL_ABBREVIATIONS.iter().any(|(_, i)| *i == interval));
+            assert!(
+                INTERVAL_NAMES
+                    .iter()
+                    .filter(|(_, i)| *i == interval)
+                    .count()
+                    >= 3
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str_lenient_unknown() {
+        for text in [
+            "",
+            "third",
+            "major",
+            "M9",
+            "mm3",
+            "-4",
+            "256",
+            "four semitones",
+        ] {
+            assert_eq!(Interval::from_str_lenient(text), None, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_augmented_diminished() {
+        assert_eq!(PERFECT_FOURTH.augmented(), AUGMENTED_FOURTH);
+        assert_eq!(PERFECT_FIFTH.diminished(), DIMINISHED_FIFTH);
+        assert_eq!(UNISON.diminished(), UNISON);
+        assert_eq!(Interval(255).augmented(), Interval(255));
+        assert_eq!(MAJOR_THIRD.diminished(), MINOR_THIRD);
+        assert_eq!(MINOR_SEVENTH.augmented(), MAJOR_SEVENTH);
+
+        assert_eq!(PERFECT_FOURTH.augmented().to_string(), "d5");
+        assert_eq!(PERFECT_FIFTH.diminished().to_string(), "d5");
+        assert_eq!(PERFECT_FIFTH.augmented().to_string(), "m6");
+        assert_eq!(MAJOR_SEVENTH.augmented().to_string(), "P8");
+        assert_eq!(UNISON.diminished().to_string(), "P1");
+    }
+
+    #[test]
+    fn test_perfect_above() {
+        assert_eq!(UNISON.perfect_above(), Some(PERFECT_FOURTH));
+        assert_eq!(MINOR_SECOND.perfect_above(), Some(PERFECT_FOURTH));
+        assert_eq!(PERFECT_FOURTH.perfect_above(), Some(PERFECT_FIFTH));
+        assert_eq!(AUGMENTED_FOURTH.perfect_above(), Some(PERFECT_FIFTH));
+        assert_eq!(PERFECT_FIFTH.perfect_above(), Some(OCTAVE));
+        assert_eq!(MAJOR_SEVENTH.perfect_above(), Some(OCTAVE));
+        assert_eq!(OCTAVE.perfect_above(), Some(Interval(17)));
+        assert_eq!(Interval(19).perfect_above(), Some(Interval(24)));
+        assert_eq!(Interval(247).perfect_above(), Some(Interval(252)));
+        assert_eq!(Interval(252).perfect_above(), None);
+    }
 }
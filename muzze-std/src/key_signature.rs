@@ -0,0 +1,137 @@
+//! Musical Key Signature Types
+//!
+//! This module provides the `KeySignature` struct for representing the number
+//! of sharps or flats of a key, together with the major key it describes.
+
+use crate::{PitchClass, MAJOR};
+
+/// Represents a key signature as a number of sharps or flats
+///
+/// Positive values count sharps and negative values count flats, so `0` is
+/// C major (no accidentals), `1` is G major (one sharp) and `-1` is F major
+/// (one flat). Each additional sharp moves the tonic up a perfect fifth
+/// around the circle of fifths.
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::{KeySignature, PitchClass};
+///
+/// let g_major = KeySignature::new(1);
+/// assert_eq!(g_major.tonic(), PitchClass::G);
+/// assert!(g_major.contains(PitchClass::Fs));
+/// assert!(!g_major.contains(PitchClass::F));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeySignature(i8);
+
+impl KeySignature {
+    /// Creates a new key signature from a number of accidentals
+    ///
+    /// # Arguments
+    /// * `accidentals` - The number of sharps (positive) or flats (negative)
+    ///
+    /// # Returns
+    /// A new `KeySignature` instance
+    ///
+    /// # Panics
+    /// This method will panic if `accidentals` is outside the range -7..=7
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::KeySignature;
+    ///
+    /// let b_flat_major = KeySignature::new(-2);
+    /// assert_eq!(b_flat_major.accidentals(), -2);
+    /// ```
+    #[inline]
+    pub const fn new(accidentals: i8) -> Self {
+        assert!(
+            accidentals >= -7 && accidentals <= 7,
+            "A key signature has at most 7 sharps or flats"
+        );
+        Self(accidentals)
+    }
+
+    /// Returns the number of sharps (positive) or flats (negative)
+    #[inline]
+    pub const fn accidentals(&self) -> i8 {
+        self.0
+    }
+
+    /// Returns the tonic of the major key described by this key signature
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{KeySignature, PitchClass};
+    ///
+    /// assert_eq!(KeySignature::new(0).tonic(), PitchClass::C);
+    /// assert_eq!(KeySignature::new(3).tonic(), PitchClass::A);
+    /// assert_eq!(KeySignature::new(-3).tonic(), PitchClass::Ds);
+    /// ```
+    #[inline]
+    pub const fn tonic(&self) -> PitchClass {
+        PitchClass::from_u8((self.0 as i16 * 7).rem_euclid(12) as u8)
+    }
+
+    /// Checks whether a pitch class is diatonic to the key
+    ///
+    /// # Arguments
+    /// * `pitch_class` - The pitch class to look up
+    ///
+    /// # Returns
+    /// `true` if the pitch class belongs to the major scale of the key
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{KeySignature, PitchClass};
+    ///
+    /// let c_major = KeySignature::new(0);
+    /// assert!(c_major.contains(PitchClass::E));
+    /// assert!(!c_major.contains(PitchClass::Ds));
+    /// ```
+    #[inline]
+    pub const fn contains(&self, pitch_class: PitchClass) -> bool {
+        let offset = (pitch_class.inner() + 12 - self.tonic().inner()) % 12;
+        MAJOR.chroma() & (1 << offset) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_signature_tonic() {
+        let tonics = (-7..=7)
+            .map(|n| KeySignature::new(n).tonic().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tonics,
+            vec![
+                "B", "F♯", "C♯", "G♯", "D♯", "A♯", "F", "C", "G", "D", "A", "E", "B", "F♯", "C♯"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_key_signature_contains() {
+        let c_major = KeySignature::new(0);
+        let diatonic = PitchClass::ALL
+            .iter()
+            .filter(|pc| c_major.contains(**pc))
+            .count();
+        assert_eq!(diatonic, 7);
+
+        let d_major = KeySignature::new(2);
+        assert!(d_major.contains(PitchClass::Fs));
+        assert!(d_major.contains(PitchClass::Cs));
+        assert!(!d_major.contains(PitchClass::C));
+    }
+
+    #[test]
+    #[should_panic(expected = "A key signature has at most 7 sharps or flats")]
+    fn test_key_signature_out_of_range() {
+        let _ = KeySignature::new(8);
+    }
+}
@@ -1,13 +1,26 @@
 mod accidental;
 mod chord;
+mod chord_x;
 mod degree;
+pub mod harmony;
 mod interval;
+mod key_signature;
+mod pitch_class;
+mod progression;
+mod rhythm;
 mod scale;
 mod step;
+mod tuning;
 
 pub use accidental::*;
 pub use chord::*;
+pub use chord_x::*;
 pub use degree::*;
 pub use interval::*;
+pub use key_signature::*;
+pub use pitch_class::*;
+pub use progression::*;
+pub use rhythm::*;
 pub use scale::*;
 pub use step::*;
+pub use tuning::*;
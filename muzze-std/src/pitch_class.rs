@@ -0,0 +1,246 @@
+//! Musical Pitch Class Types
+//!
+//! This module provides the `PitchClass` enum for representing the twelve
+//! pitch classes of the chromatic scale, independently of the octave.
+
+use std::fmt::Display;
+
+/// Represents one of the twelve pitch classes of the chromatic scale
+///
+/// A pitch class groups all notes that are a whole number of octaves apart
+/// (for example every C on the keyboard). Pitch classes are numbered from
+/// 0 (C) to 11 (B) and are spelled with sharps.
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::PitchClass;
+///
+/// let c = PitchClass::C;
+/// assert_eq!(u8::from(c), 0);
+/// assert_eq!(c.transpose(7), PitchClass::G);
+/// assert_eq!(PitchClass::from(61), PitchClass::Cs);
+/// assert_eq!(PitchClass::Fs.to_string(), "F♯");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum PitchClass {
+    /// C
+    C = 0,
+    /// C sharp (D flat)
+    Cs = 1,
+    /// D
+    D = 2,
+    /// D sharp (E flat)
+    Ds = 3,
+    /// E
+    E = 4,
+    /// F
+    F = 5,
+    /// F sharp (G flat)
+    Fs = 6,
+    /// G
+    G = 7,
+    /// G sharp (A flat)
+    Gs = 8,
+    /// A
+    A = 9,
+    /// A sharp (B flat)
+    As = 10,
+    /// B
+    B = 11,
+}
+
+impl PitchClass {
+    /// All twelve pitch classes in ascending order starting from C
+    pub const ALL: [PitchClass; 12] = [
+        PitchClass::C,
+        PitchClass::Cs,
+        PitchClass::D,
+        PitchClass::Ds,
+        PitchClass::E,
+        PitchClass::F,
+        PitchClass::Fs,
+        PitchClass::G,
+        PitchClass::Gs,
+        PitchClass::A,
+        PitchClass::As,
+        PitchClass::B,
+    ];
+
+    /// Creates a pitch class from a semitone value
+    ///
+    /// The value is reduced modulo 12, so MIDI note numbers can be used directly.
+    ///
+    /// # Arguments
+    /// * `value` - Any semitone value (for example a MIDI note number)
+    ///
+    /// # Returns
+    /// The pitch class of the value
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::PitchClass;
+    ///
+    /// assert_eq!(PitchClass::from_u8(60), PitchClass::C);
+    /// assert_eq!(PitchClass::from_u8(14), PitchClass::D);
+    /// ```
+    #[inline]
+    pub const fn from_u8(value: u8) -> Self {
+        Self::ALL[(value % 12) as usize]
+    }
+
+    /// Returns the semitone value of the pitch class (0 for C up to 11 for B)
+    #[inline]
+    pub const fn inner(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Transposes the pitch class up by a number of semitones
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to move up
+    ///
+    /// # Returns
+    /// The pitch class reached after moving up, wrapping around the octave
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::PitchClass;
+    ///
+    /// assert_eq!(PitchClass::A.transpose(3), PitchClass::C);
+    /// ```
+    #[inline]
+    pub const fn transpose(self, semitones: u8) -> Self {
+        Self::from_u8(self.inner() + semitones % 12)
+    }
+
+    /// Returns the LilyPond note name of the pitch class
+    ///
+    /// The names follow the default (Dutch) LilyPond input language, spelled
+    /// with sharps like the rest of the pitch classes: "cis" for C♯, "fis"
+    /// for F♯ and so on.
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::PitchClass;
+    ///
+    /// assert_eq!(PitchClass::C.to_lilypond(), "c");
+    /// assert_eq!(PitchClass::As.to_lilypond(), "ais");
+    /// ```
+    #[inline]
+    pub const fn to_lilypond(&self) -> &'static str {
+        match self {
+            PitchClass::C => "c",
+            PitchClass::Cs => "cis",
+            PitchClass::D => "d",
+            PitchClass::Ds => "dis",
+            PitchClass::E => "e",
+            PitchClass::F => "f",
+            PitchClass::Fs => "fis",
+            PitchClass::G => "g",
+            PitchClass::Gs => "gis",
+            PitchClass::A => "a",
+            PitchClass::As => "ais",
+            PitchClass::B => "b",
+        }
+    }
+}
+
+impl From<u8> for PitchClass {
+    /// Converts a semitone value to its pitch class, reducing it modulo 12
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self::from_u8(value)
+    }
+}
+
+impl From<PitchClass> for u8 {
+    /// Converts a pitch class to its semitone value (0-11)
+    #[inline]
+    fn from(pitch_class: PitchClass) -> Self {
+        pitch_class.inner()
+    }
+}
+
+impl Display for PitchClass {
+    /// Formats the pitch class as its note name, using sharps for black keys
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::PitchClass;
+    ///
+    /// assert_eq!(PitchClass::C.to_string(), "C");
+    /// assert_eq!(PitchClass::As.to_string(), "A♯");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PitchClass::C => write!(f, "C"),
+            PitchClass::Cs => write!(f, "C♯"),
+            PitchClass::D => write!(f, "D"),
+            PitchClass::Ds => write!(f, "D♯"),
+            PitchClass::E => write!(f, "E"),
+            PitchClass::F => write!(f, "F"),
+            PitchClass::Fs => write!(f, "F♯"),
+            PitchClass::G => write!(f, "G"),
+            PitchClass::Gs => write!(f, "G♯"),
+            PitchClass::A => write!(f, "A"),
+            PitchClass::As => write!(f, "A♯"),
+            PitchClass::B => write!(f, "B"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pitch_class_roundtrip() {
+        for value in 0..12u8 {
+            assert_eq!(u8::from(PitchClass::from(value)), value);
+        }
+    }
+
+    #[test]
+    fn test_pitch_class_from_u8_wraps() {
+        assert_eq!(PitchClass::from(12), PitchClass::C);
+        assert_eq!(PitchClass::from(60), PitchClass::C);
+        assert_eq!(PitchClass::from(69), PitchClass::A);
+        assert_eq!(PitchClass::from(255), PitchClass::Ds);
+    }
+
+    #[test]
+    fn test_pitch_class_transpose() {
+        assert_eq!(PitchClass::C.transpose(0), PitchClass::C);
+        assert_eq!(PitchClass::C.transpose(7), PitchClass::G);
+        assert_eq!(PitchClass::G.transpose(7), PitchClass::D);
+        assert_eq!(PitchClass::B.transpose(1), PitchClass::C);
+        assert_eq!(PitchClass::E.transpose(255), PitchClass::G);
+    }
+
+    #[test]
+    fn test_pitch_class_display() {
+        let names = PitchClass::ALL
+            .iter()
+            .map(|pc| pc.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["C", "C♯", "D", "D♯", "E", "F", "F♯", "G", "G♯", "A", "A♯", "B"]
+        );
+    }
+
+    #[test]
+    fn test_pitch_class_to_lilypond() {
+        let names = PitchClass::ALL
+            .iter()
+            .map(|pc| pc.to_lilypond())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["c", "cis", "d", "dis", "e", "f", "fis", "g", "gis", "a", "ais", "b"]
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! Chord Progression Types
+//!
+//! This module provides the `Progression` struct for representing an ordered
+//! sequence of chords, each one built on a root pitch class.
+
+use std::borrow::Cow;
+use std::fmt::Display;
+
+use crate::{
+    Chord, ChordQuality, PitchClass, Scale, DOMINANT_SEVENTH, MAJOR_SEVENTH_CHORD, MAJOR_TRIAD,
+    MINOR_SEVENTH_CHORD,
+};
+
+/// Represents a chord progression as an ordered sequence of rooted chords
+///
+/// Each entry pairs the root of a chord with the chord itself. Predefined
+/// progressions are stored in static memory and are copied on the first
+/// modification.
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::{Progression, PitchClass, MAJOR_TRIAD, MINOR_TRIAD};
+///
+/// let mut progression = Progression::new(vec![(PitchClass::A, MINOR_TRIAD)]);
+/// progression.push(PitchClass::F, MAJOR_TRIAD);
+/// assert_eq!(progression.len(), 2);
+/// assert_eq!(progression.to_string(), "Am - F");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Progression {
+    chords: Cow<'static, [(PitchClass, Chord)]>,
+}
+
+impl Progression {
+    /// Creates a new progression from a sequence of rooted chords
+    ///
+    /// # Arguments
+    /// * `chords` - The (root, chord) pairs in playing order
+    ///
+    /// # Returns
+    /// A new `Progression` instance
+    #[inline]
+    pub fn new(chords: Vec<(PitchClass, Chord)>) -> Self {
+        Self {
+            chords: Cow::Owned(chords),
+        }
+    }
+
+    /// Creates a new progression from a static sequence of rooted chords
+    ///
+    /// This is used to define progression constants.
+    ///
+    /// # Arguments
+    /// * `chords` - The (root, chord) pairs in playing order
+    ///
+    /// # Returns
+    /// A new `Progression` instance borrowing the chords
+    #[inline]
+    pub const fn from_static(chords: &'static [(PitchClass, Chord)]) -> Self {
+        Self {
+            chords: Cow::Borrowed(chords),
+        }
+    }
+
+    /// Returns the number of chords in the progression
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.chords.len()
+    }
+
+    /// Checks whether the progression has no chords
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.chords.is_empty()
+    }
+
+    /// Returns an iterator over the rooted chords of the progression, in order
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &(PitchClass, Chord)> {
+        self.chords.iter()
+    }
+
+    /// Appends a chord to the end of the progression
+    ///
+    /// # Arguments
+    /// * `root` - The root of the chord
+    /// * `chord` - The chord to append
+    #[inline]
+    pub fn push(&mut self, root: PitchClass, chord: Chord) {
+        self.chords.to_mut().push((root, chord));
+    }
+
+    /// Counts the pitch classes shared by each pair of consecutive chords
+    ///
+    /// Common tones are the notes a chord keeps from the previous one, and
+    /// are a measure of how smoothly a progression moves.
+    ///
+    /// # Returns
+    /// One count per pair of adjacent chords, so one less than the number of
+    /// chords (or nothing for an empty progression)
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::II_V_I_MAJOR;
+    ///
+    /// // Dm7 -> G7 keep D and F, G7 -> Cmaj7 keep G and B
+    /// assert_eq!(II_V_I_MAJOR.common_tones_between_adjacent(), vec![2, 2]);
+    /// ```
+    pub fn common_tones_between_adjacent(&self) -> Vec<usize> {
+        self.chords
+            .windows(2)
+            .map(|pair| {
+                let (left, right) = (&pair[0], &pair[1]);
+                (pitch_class_set(left.0, &left.1) & pitch_class_set(right.0, &right.1)).count_ones()
+                    as usize
+            })
+            .collect()
+    }
+}
+
+/// Returns the pitch classes of a chord built on a root, as a 12-bit set
+fn pitch_class_set(root: PitchClass, chord: &Chord) -> u16 {
+    Scale::rotate_chroma(chord.semitone_set(), root.inner() as u32)
+}
+
+/// Returns the chord symbol suffix for a chord quality
+fn quality_symbol(chord: &Chord) -> String {
+    match chord.quality() {
+        ChordQuality::Major => String::new(),
+        ChordQuality::Other => format!("({})", chord.to_degree_string()),
+        quality => quality.to_string(),
+    }
+}
+
+impl Display for Progression {
+    /// Formats the progression as chord symbols separated by " - "
+    ///
+    /// Each chord is shown as its root followed by a symbol for its quality.
+    /// Chords without a recognized quality show their degrees instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use muzze_std::{II_V_I_MAJOR, I_IV_V_I};
+    ///
+    /// assert_eq!(II_V_I_MAJOR.to_string(), "Dm7 - G7 - Cmaj7");
+    /// assert_eq!(I_IV_V_I.to_string(), "C - F - G - C");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbols = self
+            .chords
+            .iter()
+            .map(|(root, chord)| format!("{root}{}", quality_symbol(chord)))
+            .collect::<Vec<_>>();
+        write!(f, "{}", symbols.join(" - "))
+    }
+}
+
+/// The ii-V-I progression in C major: Dm7 - G7 - Cmaj7
+pub const II_V_I_MAJOR: Progression = Progression::from_static(&[
+    (PitchClass::D, MINOR_SEVENTH_CHORD),
+    (PitchClass::G, DOMINANT_SEVENTH),
+    (PitchClass::C, MAJOR_SEVENTH_CHORD),
+]);
+
+/// The I-IV-V-I cadence in C major: C - F - G - C
+pub const I_IV_V_I: Progression = Progression::from_static(&[
+    (PitchClass::C, MAJOR_TRIAD),
+    (PitchClass::F, MAJOR_TRIAD),
+    (PitchClass::G, MAJOR_TRIAD),
+    (PitchClass::C, MAJOR_TRIAD),
+]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DIMINISHED_SEVENTH, HALF_DIMINISHED_SEVENTH, MINOR_TRIAD, SUSPENDED_FOURTH};
+
+    #[test]
+    fn test_progression_constants() {
+        assert_eq!(II_V_I_MAJOR.len(), 3);
+        assert_eq!(I_IV_V_I.len(), 4);
+        assert!(!II_V_I_MAJOR.is_empty());
+        assert_eq!(
+            II_V_I_MAJOR
+                .iter()
+                .map(|(root, _)| *root)
+                .collect::<Vec<_>>(),
+            vec![PitchClass::D, PitchClass::G, PitchClass::C]
+        );
+    }
+
+    #[test]
+    fn test_common_tones_between_adjacent() {
+        assert_eq!(II_V_I_MAJOR.common_tones_between_adjacent(), vec![2, 2]);
+        assert_eq!(I_IV_V_I.common_tones_between_adjacent(), vec![1, 0, 1]);
+        assert!(Progression::new(vec![])
+            .common_tones_between_adjacent()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_progression_push() {
+        let mut progression = II_V_I_MAJOR;
+        progression.push(PitchClass::A, MINOR_SEVENTH_CHORD);
+        assert_eq!(progression.len(), 4);
+        assert_eq!(progression.to_string(), "Dm7 - G7 - Cmaj7 - Am7");
+        // The constant itself is left untouched
+        assert_eq!(II_V_I_MAJOR.len(), 3);
+        assert_eq!(progression.common_tones_between_adjacent(), vec![2, 2, 3]);
+    }
+
+    #[test]
+    fn test_progression_display() {
+        let progression = Progression::new(vec![
+            (PitchClass::B, HALF_DIMINISHED_SEVENTH),
+            (PitchClass::Gs, DIMINISHED_SEVENTH),
+            (PitchClass::A, MINOR_TRIAD),
+            (PitchClass::E, SUSPENDED_FOURTH),
+        ]);
+        assert_eq!(progression.to_string(), "Bm7♭5 - G♯dim7 - Am - E(R-4-5)");
+        assert_eq!(Progression::new(vec![]).to_string(), "");
+    }
+}
@@ -0,0 +1,177 @@
+//! Musical Rhythm Implementation
+//!
+//! This module provides a representation of rhythmic patterns using BitVec16.
+//! A measure is divided into a 16-step grid (sixteenth-note resolution) and each
+//! set bit marks a note onset on the corresponding step.
+
+use std::fmt::Display;
+
+use muzze_bitflags::BitVec16;
+
+/// Represents a beat pattern within a measure using a 16-bit vector
+///
+/// Each bit position (0-15) is one sixteenth-note step of a 4/4 measure. Bit 0
+/// is the downbeat, bit 4 the second beat, and so on. A set bit means a note
+/// starts on that step, an unset bit is a rest (or a held note).
+///
+/// # Examples
+/// ```
+/// use muzze_std::{Rhythm, FOUR_FOUR};
+/// let rhythm = Rhythm::new(0b0001_0001_0001_0001);
+/// assert_eq!(rhythm, FOUR_FOUR);
+/// assert_eq!(rhythm.onset_count(), 4);
+/// assert_eq!(rhythm.to_string(), "X...X...X...X...");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rhythm(BitVec16);
+
+impl Rhythm {
+    /// Creates a new Rhythm from a u16 pattern
+    ///
+    /// # Arguments
+    /// * `pattern` - The onset pattern, where bit `n` is the `n`-th sixteenth step
+    ///
+    /// # Returns
+    /// A new Rhythm instance with the specified onsets
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::Rhythm;
+    /// let rhythm = Rhythm::new(0b0000_0000_0000_0101);
+    /// assert!(rhythm.is_beat_active(2));
+    /// ```
+    #[inline]
+    pub const fn new(pattern: u16) -> Self {
+        Self(BitVec16::from_u16(pattern))
+    }
+
+    /// Checks whether a note starts on the given step
+    ///
+    /// # Arguments
+    /// * `step` - The sixteenth step to check (0-15)
+    ///
+    /// # Returns
+    /// `true` if there is an onset on the step
+    ///
+    /// # Panics
+    /// This method will panic if the step is out of bounds (> 15)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::FOUR_FOUR;
+    /// assert!(FOUR_FOUR.is_beat_active(4));
+    /// assert!(!FOUR_FOUR.is_beat_active(5));
+    /// ```
+    #[inline]
+    pub const fn is_beat_active(&self, step: usize) -> bool {
+        self.0.bit(step)
+    }
+
+    /// Returns the number of onsets in the measure
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::CLAVE_SON;
+    /// assert_eq!(CLAVE_SON.onset_count(), 5);
+    /// ```
+    #[inline]
+    pub const fn onset_count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Returns an iterator over the steps that have an onset
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::FOUR_FOUR;
+    /// let steps: Vec<usize> = FOUR_FOUR.onset_positions().collect();
+    /// assert_eq!(steps, vec![0, 4, 8, 12]);
+    /// ```
+    #[inline]
+    pub fn onset_positions(&self) -> impl Iterator<Item = usize> {
+        self.0.indeces_on()
+    }
+}
+
+impl Display for Rhythm {
+    /// Formats the rhythm as a 16-character grid
+    ///
+    /// Onsets are shown as "X" and rests as ".", starting from the downbeat.
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::BOSSA_NOVA;
+    /// assert_eq!(BOSSA_NOVA.to_string(), "X..X..X...X..X..");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let grid = self
+            .0
+            .iter_bits()
+            .map(|onset| if onset { 'X' } else { '.' })
+            .collect::<String>();
+        write!(f, "{grid}")
+    }
+}
+
+/// Four on the floor: an onset on each of the four quarter-note beats
+pub const FOUR_FOUR: Rhythm = Rhythm::new(0b0001_0001_0001_0001);
+
+/// Bossa nova clave: onsets on steps 0, 3, 6, 10 and 13
+pub const BOSSA_NOVA: Rhythm = Rhythm::new(0b0010_0100_0100_1001);
+
+/// Son clave (3-2): onsets on steps 0, 3, 6, 10 and 12
+pub const CLAVE_SON: Rhythm = Rhythm::new(0b0001_0100_0100_1001);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_four_four() {
+        assert_eq!(FOUR_FOUR.onset_count(), 4);
+        assert_eq!(
+            FOUR_FOUR.onset_positions().collect::<Vec<_>>(),
+            vec![0, 4, 8, 12]
+        );
+        assert_eq!(FOUR_FOUR.to_string(), "X...X...X...X...");
+    }
+
+    #[test]
+    fn test_bossa_nova() {
+        assert_eq!(BOSSA_NOVA.onset_count(), 5);
+        assert_eq!(
+            BOSSA_NOVA.onset_positions().collect::<Vec<_>>(),
+            vec![0, 3, 6, 10, 13]
+        );
+        assert_eq!(BOSSA_NOVA.to_string(), "X..X..X...X..X..");
+    }
+
+    #[test]
+    fn test_clave_son() {
+        assert_eq!(CLAVE_SON.onset_count(), 5);
+        assert_eq!(
+            CLAVE_SON.onset_positions().collect::<Vec<_>>(),
+            vec![0, 3, 6, 10, 12]
+        );
+        assert_eq!(CLAVE_SON.to_string(), "X..X..X...X.X...");
+    }
+
+    #[test]
+    fn test_is_beat_active() {
+        let rhythm = Rhythm::new(0b1000_0000_0000_0001);
+        assert!(rhythm.is_beat_active(0));
+        assert!(rhythm.is_beat_active(15));
+        assert!(!rhythm.is_beat_active(7));
+    }
+
+    #[test]
+    fn test_empty_and_full_rhythm() {
+        let rest = Rhythm::new(0);
+        assert_eq!(rest.onset_count(), 0);
+        assert_eq!(rest.to_string(), "................");
+
+        let sixteenths = Rhythm::new(u16::MAX);
+        assert_eq!(sixteenths.onset_count(), 16);
+        assert_eq!(sixteenths.to_string(), "XXXXXXXXXXXXXXXX");
+    }
+}
@@ -5,8 +5,106 @@
 //! The scales are defined using standard Western music theory patterns.
 
 use muzze_bitflags::{BitVec16, BitVec16Builder};
+#[cfg(feature = "rand")]
+use rand::Rng;
 
-use crate::{Interval, Step, UNISON};
+use std::fmt::Display;
+
+use crate::{
+    Chord, ChordBuilder, ChordType, Degree, DegreeAccidental, Interval, PitchClass, Step, HALF,
+    UNISON, WHOLE, WHOLE_HALF,
+};
+
+/// Errors that can occur when constructing a Scale from runtime data
+///
+/// # Examples
+/// ```
+/// use muzze_std::{Scale, ScaleError};
+/// assert_eq!(Scale::from_steps_slice(&[]), Err(ScaleError::EmptySteps));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScaleError {
+    /// No steps were given
+    EmptySteps,
+    /// The steps add up to more than an octave
+    StepOverflow {
+        /// The total number of semitones of all steps
+        total: u8,
+    },
+    /// A step of zero semitones was found
+    ZeroStep {
+        /// The position of the zero step in the input
+        index: usize,
+    },
+    /// No intervals were given
+    EmptyIntervals,
+    /// An interval is not between 1 and 12 semitones
+    IntervalOutOfRange(u8),
+    /// The intervals are not in ascending order
+    IntervalsNotAscending,
+    /// The same interval was given twice
+    DuplicateInterval(u8),
+}
+
+impl Display for ScaleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaleError::EmptySteps => write!(f, "a scale needs at least one step"),
+            ScaleError::StepOverflow { total } => {
+                write!(
+                    f,
+                    "the steps add up to {total} semitones, more than an octave"
+                )
+            }
+            ScaleError::ZeroStep { index } => write!(f, "the step at index {index} is zero"),
+            ScaleError::EmptyIntervals => write!(f, "a scale needs at least one interval"),
+            ScaleError::IntervalOutOfRange(interval) => {
+                write!(
+                    f,
+                    "the interval {interval} is not between 1 and 12 semitones"
+                )
+            }
+            ScaleError::IntervalsNotAscending => {
+                write!(f, "the intervals are not in ascending order")
+            }
+            ScaleError::DuplicateInterval(interval) => {
+                write!(f, "the interval {interval} is given more than once")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaleError {}
+
+/// The function of a note within a scale
+///
+/// The function only names the scale degree, so altered degrees keep the
+/// function of the natural degree: a minor third is still the `Third`.
+///
+/// # Examples
+/// ```
+/// use muzze_std::{ScaleDegreeFunction, MAJOR};
+/// assert_eq!(MAJOR.get_note_function(7), ScaleDegreeFunction::Fifth);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScaleDegreeFunction {
+    /// The tonic of the scale
+    Root,
+    /// The second degree of the scale
+    Second,
+    /// The third degree of the scale
+    Third,
+    /// The fourth degree of the scale
+    Fourth,
+    /// The fifth degree of the scale
+    Fifth,
+    /// The sixth degree of the scale
+    Sixth,
+    /// The seventh degree of the scale
+    Seventh,
+    /// A note that is not part of the scale
+    NonDiatonic,
+}
 
 /// Represents a musical scale using a 16-bit vector
 ///
@@ -21,14 +119,16 @@ use crate::{Interval, Step, UNISON};
 /// let major_scale = Scale::from_u16(0b0000_0000_0000_1111);
 /// let intervals: Vec<Interval> = major_scale.intervals().collect();
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Scale(BitVec16);
 
 impl Scale {
     /// Creates a new Scale from a u16 value
     ///
     /// The u16 value represents the bit pattern where each bit position
-    /// corresponds to a semitone interval from the root note.
+    /// corresponds to a semitone interval from the root note. Together with
+    /// `to_u16` it can be used to serialize a scale; note that the encoding is
+    /// not guaranteed to be stable across library versions.
     ///
     /// # Arguments
     /// * `value` - The u16 value representing the scale pattern
@@ -46,6 +146,351 @@ impl Scale {
         Self(BitVec16::from_u16(value))
     }
 
+    /// Returns the bit pattern of the scale as a u16 value
+    ///
+    /// This is the inverse of `from_u16` and is meant for compact
+    /// serialization. The encoding is not guaranteed to be stable across
+    /// library versions.
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{Scale, MAJOR};
+    /// assert_eq!(MAJOR.to_u16(), 0b0000_1101_0101_1010);
+    /// assert_eq!(Scale::from_u16(MAJOR.to_u16()), MAJOR);
+    /// ```
+    #[inline]
+    pub const fn to_u16(&self) -> u16 {
+        self.0.inner()
+    }
+
+    /// Returns the name of the church mode matching the scale
+    ///
+    /// The bit pattern of the scale is looked up among the seven diatonic
+    /// modes, so `MAJOR` is reported as "Ionian" and `NATURAL_MINOR` as
+    /// "Aeolian".
+    ///
+    /// # Returns
+    /// The name of the mode, or `None` if the scale is not a church mode
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{DORIAN, MAJOR, PENTATONIC_MAJOR};
+    /// assert_eq!(MAJOR.church_mode_name(), Some("Ionian"));
+    /// assert_eq!(DORIAN.church_mode_name(), Some("Dorian"));
+    /// assert_eq!(PENTATONIC_MAJOR.church_mode_name(), None);
+    /// ```
+    pub fn church_mode_name(&self) -> Option<&'static str> {
+        Self::lookup_name(&CHURCH_MODE_NAMES, self.to_u16())
+    }
+
+    /// Returns the common name of the scale
+    ///
+    /// The bit pattern of the scale is looked up among all the predefined
+    /// scale constants. `MAJOR` and `NATURAL_MINOR` are reported under these
+    /// names rather than as modes.
+    ///
+    /// # Returns
+    /// The name of the scale, or `None` if it is not a predefined scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{Scale, BLUES_MINOR, LYDIAN, MAJOR};
+    /// assert_eq!(MAJOR.common_name(), Some("Major"));
+    /// assert_eq!(LYDIAN.common_name(), Some("Lydian"));
+    /// assert_eq!(BLUES_MINOR.common_name(), Some("Blues Minor"));
+    /// assert_eq!(Scale::from_u16(0).common_name(), None);
+    /// ```
+    pub fn common_name(&self) -> Option<&'static str> {
+        Self::lookup_name(&COMMON_NAMES, self.to_u16())
+    }
+
+    /// Returns the catalog of all the predefined scales
+    ///
+    /// Each predefined scale constant is paired with its name, including the
+    /// `IONIAN` and `AEOLIAN` aliases of `MAJOR` and `NATURAL_MINOR`. Every
+    /// name is recognized by `from_name`.
+    ///
+    /// # Returns
+    /// The (name, scale) pairs of the predefined scales
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{Scale, HARMONIC_MINOR};
+    /// assert!(Scale::all_predefined().contains(&("Harmonic Minor", HARMONIC_MINOR)));
+    /// ```
+    #[inline]
+    pub const fn all_predefined() -> &'static [(&'static str, Scale)] {
+        &PREDEFINED_SCALES
+    }
+
+    /// Looks up a predefined scale by name
+    ///
+    /// The lookup ignores case and surrounding whitespace. Every name returned
+    /// by `common_name` and `church_mode_name` is recognized, along with a few
+    /// aliases such as "minor", "diminished" and "jazz whole tone".
+    ///
+    /// # Arguments
+    /// * `name` - The name of the scale
+    ///
+    /// # Returns
+    /// The scale with the given name, or `None` if the name is not recognized
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{Scale, HARMONIC_MINOR, JAZZ_WHOLE_TONE};
+    /// assert_eq!(Scale::from_name("Harmonic Minor"), Some(HARMONIC_MINOR));
+    /// assert_eq!(Scale::from_name("  whole tone "), Some(JAZZ_WHOLE_TONE));
+    /// assert_eq!(Scale::from_name("unknown scale"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Scale> {
+        let name = name.trim().to_ascii_lowercase();
+        SCALE_NAMES
+            .binary_search_by(|(key, _)| (*key).cmp(name.as_str()))
+            .ok()
+            .map(|index| SCALE_NAMES[index].1)
+    }
+
+    /// Generates a random heptatonic scale
+    ///
+    /// Six of the eleven intervals between the root and the octave are chosen
+    /// at random and the octave is always set, so the scale has 7 degrees and
+    /// a complete octave. The result is not guaranteed to be a mode of any
+    /// named scale.
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator
+    ///
+    /// # Returns
+    /// A random scale with 7 degrees
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::Scale;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// assert_eq!(Scale::random(&mut rng).degree_count(), 7);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random(rng: &mut impl Rng) -> Scale {
+        Self::random_with_degree_count(rng, 7)
+    }
+
+    /// Generates a random pentatonic scale
+    ///
+    /// Works like `random`, with four random intervals besides the octave.
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator
+    ///
+    /// # Returns
+    /// A random scale with 5 degrees
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::Scale;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// assert_eq!(Scale::random_pentatonic(&mut rng).degree_count(), 5);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_pentatonic(rng: &mut impl Rng) -> Scale {
+        Self::random_with_degree_count(rng, 5)
+    }
+
+    /// Generates a random hexatonic scale
+    ///
+    /// Works like `random`, with five random intervals besides the octave.
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator
+    ///
+    /// # Returns
+    /// A random scale with 6 degrees
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::Scale;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// assert_eq!(Scale::random_hexatonic(&mut rng).degree_count(), 6);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_hexatonic(rng: &mut impl Rng) -> Scale {
+        Self::random_with_degree_count(rng, 6)
+    }
+
+    /// Generates a random scale with the octave and `degree_count - 1` of the
+    /// intervals between the root and the octave
+    #[cfg(feature = "rand")]
+    fn random_with_degree_count(rng: &mut impl Rng, degree_count: usize) -> Scale {
+        rand::seq::index::sample(rng, 11, degree_count - 1)
+            .into_iter()
+            .fold(BitVec16Builder::new().set_index(11), |builder, index| {
+                builder.set_index(index as u8)
+            })
+            .build()
+            .into()
+    }
+
+    /// Looks up a bit pattern in a name table sorted by bit pattern
+    fn lookup_name(table: &[(u16, &'static str)], bits: u16) -> Option<&'static str> {
+        table
+            .binary_search_by_key(&bits, |(key, _)| *key)
+            .ok()
+            .map(|index| table[index].1)
+    }
+
+    /// Creates a new Scale from a slice of semitone steps
+    ///
+    /// This is the runtime counterpart of `ScaleStepBuilder`, useful when the
+    /// steps come from configuration files or user input. Each step is the
+    /// number of semitones from the previous scale tone.
+    ///
+    /// # Arguments
+    /// * `steps` - The semitone steps of the scale, starting from the root
+    ///
+    /// # Returns
+    /// The Scale, or a `ScaleError` if the slice is empty, contains a zero
+    /// step, or the steps add up to more than 12 semitones
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{Scale, ScaleError, MAJOR};
+    /// assert_eq!(Scale::from_steps_slice(&[2, 2, 1, 2, 2, 2, 1]), Ok(MAJOR));
+    /// assert_eq!(
+    ///     Scale::from_steps_slice(&[2, 0, 1]),
+    ///     Err(ScaleError::ZeroStep { index: 1 })
+    /// );
+    /// ```
+    pub fn from_steps_slice(steps: &[u8]) -> Result<Self, ScaleError> {
+        if steps.is_empty() {
+            return Err(ScaleError::EmptySteps);
+        }
+
+        if let Some(index) = steps.iter().position(|step| *step == 0) {
+            return Err(ScaleError::ZeroStep { index });
+        }
+
+        let total = steps.iter().map(|step| *step as u16).sum::<u16>();
+        if total > 12 {
+            let total = total.min(u8::MAX as u16) as u8;
+            return Err(ScaleError::StepOverflow { total });
+        }
+
+        let scale = steps
+            .iter()
+            .fold(ScaleStepBuilder::default(), |builder, step| {
+                builder.add_step(Step::from(*step))
+            })
+            .build();
+        Ok(scale)
+    }
+
+    /// Creates a new Scale from a slice of semitone intervals above the root
+    ///
+    /// Unlike `ScaleBuilder::set_interval`, the intervals are validated: they
+    /// must be between 1 and 12 semitones and given in strictly ascending
+    /// order.
+    ///
+    /// # Arguments
+    /// * `intervals` - The semitone intervals of the scale, for example
+    ///   `[2, 4, 5, 7, 9, 11, 12]` for the major scale
+    ///
+    /// # Returns
+    /// The Scale, or a `ScaleError` if the slice is empty, an interval is out
+    /// of range, an interval is repeated, or the intervals are not ascending
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{Scale, ScaleError, MAJOR};
+    /// assert_eq!(Scale::from_intervals_checked(&[2, 4, 5, 7, 9, 11, 12]), Ok(MAJOR));
+    /// assert_eq!(
+    ///     Scale::from_intervals_checked(&[2, 4, 13]),
+    ///     Err(ScaleError::IntervalOutOfRange(13))
+    /// );
+    /// ```
+    pub fn from_intervals_checked(intervals: &[u8]) -> Result<Self, ScaleError> {
+        if intervals.is_empty() {
+            return Err(ScaleError::EmptyIntervals);
+        }
+
+        if let Some(interval) = intervals.iter().find(|i| !(1..=12).contains(*i)) {
+            return Err(ScaleError::IntervalOutOfRange(*interval));
+        }
+
+        for pair in intervals.windows(2) {
+            if pair[0] == pair[1] {
+                return Err(ScaleError::DuplicateInterval(pair[0]));
+            }
+            if pair[0] > pair[1] {
+                return Err(ScaleError::IntervalsNotAscending);
+            }
+        }
+
+        Ok(intervals.iter().copied().collect())
+    }
+
+    /// Creates a scale from the Euclidean rhythm of `k` onsets over `n` positions
+    ///
+    /// Bjorklund's algorithm spreads the `k` onsets as evenly as possible over
+    /// a cycle of `n` semitones, starting with an onset on the root. Each onset
+    /// becomes a tone of the scale, and the onset that starts the next cycle is
+    /// stored as the interval `n` (the octave when `n` is 12).
+    ///
+    /// A Euclidean rhythm is only defined up to rotation, and the algorithm
+    /// yields one specific rotation: `euclidean(7, 12)` is the natural minor
+    /// scale, a mode of the major scale, and `euclidean(5, 12)` is a mode of the
+    /// major pentatonic scale.
+    ///
+    /// # Arguments
+    /// * `k` - The number of onsets, clamped to `n`
+    /// * `n` - The number of positions in the cycle, clamped to 16
+    ///
+    /// # Returns
+    /// The scale with the onsets of the rhythm, or an empty scale when `k` or
+    /// `n` is 0
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{Scale, JAZZ_WHOLE_TONE, MAJOR, NATURAL_MINOR};
+    /// assert_eq!(Scale::euclidean(7, 12), NATURAL_MINOR);
+    /// assert!(Scale::euclidean(7, 12).is_transpositionally_equivalent_to(MAJOR));
+    /// assert_eq!(Scale::euclidean(6, 12).degree_count(), JAZZ_WHOLE_TONE.degree_count());
+    /// assert!(Scale::euclidean(6, 12).is_transpositionally_equivalent_to(JAZZ_WHOLE_TONE));
+    /// ```
+    pub fn euclidean(k: u8, n: u8) -> Scale {
+        let n = n.min(16) as usize;
+        let k = (k as usize).min(n);
+        if k == 0 {
+            return Scale::from_u16(0);
+        }
+
+        let mut heads = vec![vec![true]; k];
+        let mut remainders = vec![vec![false]; n - k];
+        while remainders.len() > 1 {
+            let pairs = heads.len().min(remainders.len());
+            let rest = if heads.len() > pairs {
+                heads.split_off(pairs)
+            } else {
+                remainders.split_off(pairs)
+            };
+            for (head, remainder) in heads.iter_mut().zip(remainders) {
+                head.extend(remainder);
+            }
+            remainders = rest;
+        }
+
+        let onsets = heads.into_iter().chain(remainders).flatten();
+        let bits = onsets
+            .enumerate()
+            .skip(1)
+            .filter(|(_, onset)| *onset)
+            .fold(1 << (n - 1), |bits, (position, _)| {
+                bits | 1 << (position - 1)
+            });
+        Scale::from_u16(bits)
+    }
+
     /// Returns an iterator over the semitone intervals in this scale
     ///
     /// This method yields the semitone intervals (1-16) that are part of the scale.
@@ -69,6 +514,63 @@ impl Scale {
         })
     }
 
+    /// Returns the semitone intervals of the scale as a fixed-size array
+    ///
+    /// This is the `const` counterpart of `intervals()`: the intervals are
+    /// stored in ascending order at the start of the array and the remaining
+    /// entries are padded with zeros. Only the first 12 intervals are kept.
+    ///
+    /// # Returns
+    /// An array with the semitone intervals followed by zeros
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::PENTATONIC_MINOR;
+    /// const INTERVALS: [u8; 12] = PENTATONIC_MINOR.as_interval_array();
+    /// assert_eq!(INTERVALS, [3, 5, 7, 10, 0, 0, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    pub const fn as_interval_array(&self) -> [u8; 12] {
+        let mut intervals = [0; 12];
+        let mut count = 0;
+        let mut bit = 0;
+        while bit < 16 && count < 12 {
+            if self.0.bit(bit) {
+                intervals[count] = bit as u8 + 1;
+                count += 1;
+            }
+            bit += 1;
+        }
+        intervals
+    }
+
+    /// Returns the semitone intervals of the scale in ascending order
+    ///
+    /// This is the semitone value of each item of `intervals()`.
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::PENTATONIC_MAJOR;
+    /// let ascending: Vec<u8> = PENTATONIC_MAJOR.ascending().collect();
+    /// assert_eq!(ascending, vec![2, 4, 7, 9]);
+    /// ```
+    #[inline]
+    pub fn ascending(&self) -> impl Iterator<Item = u8> {
+        self.intervals().map(|interval| interval.inner())
+    }
+
+    /// Returns the semitone intervals of the scale in descending order
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::PENTATONIC_MAJOR;
+    /// let descending: Vec<u8> = PENTATONIC_MAJOR.descending().collect();
+    /// assert_eq!(descending, vec![9, 7, 4, 2]);
+    /// ```
+    #[inline]
+    pub fn descending(&self) -> impl Iterator<Item = u8> {
+        self.ascending().collect::<Vec<_>>().into_iter().rev()
+    }
+
     /// Returns an iterator over the step intervals between consecutive scale degrees
     ///
     /// This method calculates the semitone differences between consecutive
@@ -95,6 +597,138 @@ impl Scale {
         })
     }
 
+    /// Returns the step pattern of the scale as a human readable string
+    ///
+    /// The steps are joined with "-", using the `Step` notation
+    /// (H for a half step, W for a whole step, and so on).
+    ///
+    /// # Returns
+    /// The step pattern of the scale, for example "W-W-H-W-W-W-H"
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, PENTATONIC_MINOR};
+    /// assert_eq!(MAJOR.to_step_string(), "W-W-H-W-W-W-H");
+    /// assert_eq!(PENTATONIC_MINOR.to_step_string(), "WH-W-W-WH");
+    /// ```
+    pub fn to_step_string(&self) -> String {
+        self.steps()
+            .map(|step| step.to_string())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    /// Returns the number of whole steps in the step pattern of the scale
+    ///
+    /// A diatonic scale that includes the octave always has exactly 5 whole
+    /// steps and 2 half steps.
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{JAZZ_WHOLE_TONE, MAJOR};
+    /// assert_eq!(MAJOR.count_whole_steps(), 5);
+    /// assert_eq!(JAZZ_WHOLE_TONE.count_whole_steps(), 5);
+    /// ```
+    #[inline]
+    pub fn count_whole_steps(&self) -> u8 {
+        self.count_steps(WHOLE)
+    }
+
+    /// Returns the number of half steps in the step pattern of the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{JAZZ_WHOLE_TONE, MAJOR};
+    /// assert_eq!(MAJOR.count_half_steps(), 2);
+    /// assert_eq!(JAZZ_WHOLE_TONE.count_half_steps(), 0);
+    /// ```
+    #[inline]
+    pub fn count_half_steps(&self) -> u8 {
+        self.count_steps(HALF)
+    }
+
+    /// Returns the number of augmented seconds (3 semitones) in the step
+    /// pattern of the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{HARMONIC_MINOR, MAJOR};
+    /// assert_eq!(MAJOR.count_augmented_steps(), 0);
+    /// assert_eq!(HARMONIC_MINOR.count_augmented_steps(), 1);
+    /// ```
+    #[inline]
+    pub fn count_augmented_steps(&self) -> u8 {
+        self.count_steps(WHOLE_HALF)
+    }
+
+    /// Returns the number of steps of the scale equal to the given step
+    fn count_steps(&self, step: Step) -> u8 {
+        self.steps().filter(|candidate| *candidate == step).count() as u8
+    }
+
+    /// Returns true if the step pattern of the scale reads the same forward
+    /// and backward
+    ///
+    /// Only the steps stored in the scale are compared, so a scale that does
+    /// not include the octave is checked without its closing step.
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{JAZZ_WHOLEHALF_DIMINISHED, JAZZ_WHOLE_TONE, MAJOR};
+    /// assert!(JAZZ_WHOLE_TONE.symmetric());
+    /// assert!(JAZZ_WHOLEHALF_DIMINISHED.symmetric());
+    /// assert!(!MAJOR.symmetric());
+    /// ```
+    pub fn symmetric(&self) -> bool {
+        let steps = self.steps().collect::<Vec<_>>();
+        steps.iter().eq(steps.iter().rev())
+    }
+
+    /// Counts the chromatic clusters of the scale
+    ///
+    /// A chromatic cluster is made of two or more consecutive half steps, that
+    /// is a run of at least three consecutive semitone intervals set in the
+    /// scale pattern. The implicit root is not part of the pattern.
+    ///
+    /// # Returns
+    /// The number of maximal runs of three or more consecutive intervals
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{BLUES_MINOR, MAJOR};
+    /// assert_eq!(MAJOR.count_semitone_clusters(), 0);
+    /// assert_eq!(BLUES_MINOR.count_semitone_clusters(), 1);
+    /// ```
+    pub const fn count_semitone_clusters(&self) -> u32 {
+        // Bit n survives when bits n-2 to n are all set, so every cluster
+        // leaves exactly one shorter run behind and shorter runs vanish
+        let bits = self.0.bits();
+        let ends = bits & (bits << 1) & (bits << 2);
+        BitVec16::from_u16(ends).count_runs().0
+    }
+
+    /// Returns the size of the largest chromatic cluster of the scale
+    ///
+    /// # Returns
+    /// The number of consecutive semitone intervals in the longest cluster,
+    /// or 0 if the scale has no cluster of at least three intervals
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{Scale, BLUES_MINOR, MAJOR};
+    /// assert_eq!(MAJOR.max_cluster_size(), 0);
+    /// assert_eq!(BLUES_MINOR.max_cluster_size(), 3);
+    /// assert_eq!(Scale::from_u16(0xFFFF).max_cluster_size(), 16);
+    /// ```
+    pub const fn max_cluster_size(&self) -> u32 {
+        let size = self.0.max_consecutive_ones();
+        if size >= 3 {
+            size
+        } else {
+            0
+        }
+    }
+
     /// Applies the scale to a root note
     ///
     /// This method applies the scale to a root note, returning an iterator
@@ -120,696 +754,3973 @@ impl Scale {
                 .map(move |interval| interval.inner() + root),
         )
     }
-}
 
-/// Major scale: Whole-Whole-Half-Whole-Whole-Whole-Half
-///
-/// The major scale is one of the most fundamental scales in Western music.
-/// It follows the pattern: W-W-H-W-W-W-H (where W = whole step, H = half step).
-/// This creates a bright, happy sound characteristic of major keys.
-///
-/// # Musical Theory
-/// The major scale contains the intervals: 2nd, 3rd, 4th, 5th, 6th, 7th, octave
-/// from the root note, following the step pattern [WHOLE, WHOLE, HALF, WHOLE, WHOLE, WHOLE, HALF].
-///
-/// # Bit Pattern
-/// The bit pattern `0b0000_1101_0101_1010` represents the semitone intervals
-/// that are part of the major scale structure.
-pub const MAJOR: Scale = Scale::from_u16(0b0000_1101_0101_1010);
-
-/// Natural minor scale: Whole-Half-Whole-Whole-Half-Whole-Whole
-///
-/// The natural minor scale is the relative minor of the major scale.
-/// It follows the pattern: W-H-W-W-H-W-W, creating a darker, more somber sound.
-///
-/// # Musical Theory
-/// The natural minor scale contains the intervals: 2nd, 3rd, 4th, 5th, 6th, 7th, octave
-/// from the root note, following the step pattern [WHOLE, HALF, WHOLE, WHOLE, HALF, WHOLE, WHOLE].
-///
-/// # Bit Pattern
-/// The bit pattern `0b0000_1010_1101_0110` represents the semitone intervals
-/// that are part of the natural minor scale structure.
-pub const NATURAL_MINOR: Scale = Scale::from_u16(0b0000_1010_1101_0110);
-
-/// Harmonic minor scale: Whole-Half-Whole-Whole-Half, WholeHalf, Half
-///
-/// The harmonic minor scale is a variation of the natural minor scale
-/// with a raised 7th degree. This creates a stronger leading tone resolution.
-///
-/// # Musical Theory
-/// The harmonic minor scale follows the step pattern [WHOLE, HALF, WHOLE, WHOLE, HALF, WHOLE_HALF, HALF],
-/// where the 6th to 7th degree is an augmented 2nd (3 semitones).
-///
-/// # Bit Pattern
-/// The bit pattern `0b0000_1100_1101_0110` represents the semitone intervals
-/// that are part of the harmonic minor scale structure.
-pub const HARMONIC_MINOR: Scale = Scale::from_u16(0b0000_1100_1101_0110);
-
-/// Melodic minor scale: Whole-Half-Whole-Whole-Whole-Whole-Half
-///
-/// The melodic minor scale has different ascending and descending forms.
-/// The ascending form raises both the 6th and 7th degrees, while the
-/// descending form is identical to the natural minor scale.
-///
-/// # Musical Theory
-/// The ascending melodic minor follows the step pattern [WHOLE, HALF, WHOLE, WHOLE, WHOLE, WHOLE, HALF],
-/// creating a smooth melodic line without the augmented 2nd of the harmonic minor.
-///
-/// # Bit Pattern
-/// The bit pattern `0b0000_1101_0101_0110` represents the semitone intervals
-/// that are part of the melodic minor scale structure.
-pub const MELODIC_MINOR: Scale = Scale::from_u16(0b0000_1101_0101_0110);
-
-/// Pentatonic major scale: Whole-Whole-WholeHalf-Whole
-pub const PENTATONIC_MAJOR: Scale = Scale::from_u16(0b0000_0001_0100_1010);
-
-/// Pentatonic minor scale: WholeHalft-Whole-Whole-WholeHalf
-pub const PENTATONIC_MINOR: Scale = Scale::from_u16(0b0000_0010_0101_0100);
-
-/// Blues major scale: Whole-Half-Half-WholeHalf-Whole
-pub const BLUES_MAJOR: Scale = Scale::from_u16(0b0000_0001_0100_1110);
-
-/// Blues minor scale: WholeHalf-Whole-Half-Half-WholeHalf-Whole
-pub const BLUES_MINOR: Scale = Scale::from_u16(0b0000_1010_0111_0100);
-
-/// Jazz whole tone scale: Whole-Whole-Whole-Whole-Whole
-pub const JAZZ_WHOLE_TONE: Scale = Scale::from_u16(0b0000_0010_1010_1010);
-
-/// Jazz whole half diminished scale: Whole-Half-Whole-Half-Whole-Half-Whole
-pub const JAZZ_WHOLEHALF_DIMINISHED: Scale = Scale::from_u16(0b0000_0101_1011_0110);
-
-/// Bibop major scale: Whole-Whole-Half-Whole-Half-Half-Whole-Half
-pub const BIBOP_MAJOR: Scale = Scale::from_u16(0b0000_1101_1101_1010);
-
-/// Bibop minor scale: Whole-Half-Half-Half-Whole-Whole-Half-Whole
-pub const BIBOP_MINOR: Scale = Scale::from_u16(0b0000_1011_0101_1110);
-
-/// Bibop dominant scale: Whole-Whole-Half-Whole-Whole-Half-Half-Half
-pub const BIBOP_DOMINANT: Scale = Scale::from_u16(0b0000_1111_0101_1010);
-
-/// Builder for constructing Scale instances
-///
-/// The ScaleBuilder provides a fluent interface for constructing Scale
-/// instances by setting individual semitone intervals. This is useful when
-/// you need to build a scale programmatically or create custom scales
-/// that aren't predefined as constants.
-///
-/// # Examples
-/// ```
-/// use muzze_std::{ScaleBuilder, Interval, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH, MAJOR_SEVENTH, OCTAVE};
-/// let scale = ScaleBuilder::default()
-///     .set_interval(MAJOR_SECOND)  // Major 2nd
-///     .set_interval(MAJOR_THIRD)  // Major 3rd
-///     .set_interval(PERFECT_FOURTH)  // Perfect 4th
-///     .set_interval(PERFECT_FIFTH)  // Perfect 5th
-///     .set_interval(MAJOR_SIXTH)  // Major 6th
-///     .set_interval(MAJOR_SEVENTH) // Major 7th
-///     .set_interval(OCTAVE) // Octave
-///     .build();
-/// // This creates a major scale
-/// ```
-pub struct ScaleBuilder {
-    /// The underlying BitVec16Builder used for bit manipulation
-    vec_builder: BitVec16Builder,
-}
-
-impl ScaleBuilder {
-    /// Creates a new ScaleBuilder with no intervals initially set
+    /// Applies the scale to a MIDI root note, staying within the MIDI range
     ///
-    /// This method initializes the builder with an empty scale (no intervals set).
-    /// The builder can then be used to add specific semitone intervals using
-    /// the `set_interval` method.
+    /// Works like `apply`, but notes that would land above the highest MIDI
+    /// note (127) are skipped instead of overflowing.
+    ///
+    /// # Arguments
+    /// * `root_midi` - The MIDI note of the root of the scale
     ///
     /// # Returns
-    /// A new ScaleBuilder instance ready for configuration
+    /// An iterator that yields the MIDI notes of the scale not above 127
     ///
     /// # Example
     /// ```
-    /// use muzze_std::ScaleBuilder;
-    /// let builder = ScaleBuilder::default();
-    /// let scale = builder.build();
-    /// assert_eq!(scale.intervals().count(), 0);
+    /// use muzze_std::MAJOR;
+    /// let notes: Vec<u8> = MAJOR.apply_with_octave(120).collect();
+    /// assert_eq!(notes, vec![120, 122, 124, 125, 127]);
     /// ```
-    #[inline]
-    const fn new() -> Self {
-        Self {
-            vec_builder: BitVec16Builder::new(),
-        }
+    pub fn apply_with_octave(&self, root_midi: u8) -> impl Iterator<Item = u8> {
+        std::iter::once(0)
+            .chain(self.intervals().map(|interval| interval.inner()))
+            .map(move |interval| root_midi as u16 + interval as u16)
+            .filter(|&note| note <= 127)
+            .map(|note| note as u8)
     }
 
-    /// Adds a semitone interval to the scale being constructed
+    /// Applies the scale across several consecutive octaves
     ///
-    /// This method adds the specified semitone interval to the scale.
-    /// The interval parameter represents the number of semitones from the root note.
-    /// For example, interval 2 represents a major 2nd, interval 12 represents an octave.
+    /// The scale is applied with `apply_with_octave` to the root and then to
+    /// the root raised by 12 semitones for each following octave, so scales
+    /// that include the octave repeat the note shared by two octaves. Octaves
+    /// whose root lies above 127 are not produced.
     ///
     /// # Arguments
-    /// * `interval` - The semitone interval to add (1-16)
+    /// * `root_midi` - The MIDI note of the root of the first octave
+    /// * `octave_count` - The number of octaves to produce
     ///
     /// # Returns
-    /// A new ScaleBuilder with the specified interval added
-    ///
-    /// # Panics
-    /// This method will panic if the interval is out of bounds (< 1 or > 16)
+    /// An iterator that yields the MIDI notes of every octave in order
     ///
     /// # Example
     /// ```
-    /// use muzze_std::{ScaleBuilder, Interval, MAJOR_SECOND, PERFECT_FIFTH, OCTAVE};
-    /// let scale = ScaleBuilder::default()
-    ///     .set_interval(MAJOR_SECOND)  // Add major 2nd
-    ///     .set_interval(PERFECT_FIFTH)  // Add perfect 5th
-    ///     .set_interval(OCTAVE) // Add octave
-    ///     .build();
-    /// let intervals: Vec<u8> = scale.intervals().map(|i| i.inner()).collect();
-    /// assert_eq!(intervals, vec![2, 7, 12]);
+    /// use muzze_std::MAJOR;
+    /// let notes: Vec<u8> = MAJOR.apply_multi_octave(60, 2).collect();
+    /// assert_eq!(notes.len(), 16);
+    /// assert_eq!(notes[8], 72);
     /// ```
-    #[inline]
-    pub const fn set_interval(self, interval: Interval) -> Self {
-        let vec_builder = self.vec_builder.set_index(interval.inner() - 1);
-        Self { vec_builder }
+    pub fn apply_multi_octave(&self, root_midi: u8, octave_count: u8) -> impl Iterator<Item = u8> {
+        let scale = *self;
+        (0..octave_count as u16)
+            .map(move |octave| root_midi as u16 + octave * 12)
+            .take_while(|&root| root <= 127)
+            .flat_map(move |root| scale.apply_with_octave(root as u8))
     }
 
-    /// Finalizes the builder and returns the constructed Scale
+    /// Generates a random melody within one octave of the scale
     ///
-    /// This method consumes the builder and returns the final Scale
-    /// instance with all the intervals that were added during construction.
+    /// The melody starts on the root and walks over the notes of the scale
+    /// between the root and the octave above it: each following note moves to
+    /// an adjacent note of the scale with probability 0.6, or jumps to any note
+    /// of the scale otherwise. The same note is never played three times in a
+    /// row, unless the scale has a single note.
+    ///
+    /// # Arguments
+    /// * `root` - The MIDI note of the root of the scale
+    /// * `length` - The number of notes of the melody
+    /// * `rng` - The random number generator
     ///
     /// # Returns
-    /// The constructed Scale instance
+    /// The MIDI notes of the melody, all within `root..=root + 12`
+    ///
+    /// # Panics
+    /// This method will panic if `length` is not 0 and `root` is above 127
     ///
     /// # Example
     /// ```
-    /// use muzze_std::{ScaleBuilder, Interval, MAJOR,MAJOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH, MAJOR_SEVENTH, OCTAVE};
-    /// let scale = ScaleBuilder::default()
-    ///     .set_interval(MAJOR_SECOND)  // Major 2nd
-    ///     .set_interval(MAJOR_THIRD)  // Major 3rd
-    ///     .set_interval(PERFECT_FOURTH)  // Perfect 4th
-    ///     .set_interval(PERFECT_FIFTH)  // Perfect 5th
-    ///     .set_interval(MAJOR_SIXTH)  // Major 6th
-    ///     .set_interval(MAJOR_SEVENTH) // Major 7th
-    ///     .set_interval(OCTAVE) // Octave
-    ///     .build();
-    /// // This creates a major scale with intervals [2, 4, 5, 7, 9, 11, 12]
-    /// assert_eq!(scale, MAJOR);
+    /// use muzze_std::MAJOR;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let melody = MAJOR.generate_melody(60, 16, &mut rng);
+    /// assert_eq!(melody.len(), 16);
+    /// assert_eq!(melody[0], 60);
+    /// assert!(melody.iter().all(|note| (60..=72).contains(note)));
     /// ```
-    #[inline]
-    pub const fn build(self) -> Scale {
-        Scale::from_u16(self.vec_builder.build().inner())
+    #[cfg(feature = "rand")]
+    pub fn generate_melody(&self, root: u8, length: usize, rng: &mut impl Rng) -> Vec<u8> {
+        let notes = self
+            .apply_with_octave(root)
+            .filter(|&note| note as u16 <= root as u16 + 12)
+            .collect::<Vec<_>>();
+        let last = notes.len().saturating_sub(1);
+
+        let mut melody: Vec<u8> = Vec::with_capacity(length);
+        let mut index = 0;
+        while melody.len() < length {
+            if !melody.is_empty() {
+                index = loop {
+                    let next = if !rng.gen_bool(0.6) {
+                        rng.gen_range(0..notes.len())
+                    } else if index == 0 {
+                        last.min(1)
+                    } else if index == last || rng.gen_bool(0.5) {
+                        index - 1
+                    } else {
+                        index + 1
+                    };
+                    let repeated = melody.len() >= 2
+                        && melody[melody.len() - 2..]
+                            .iter()
+                            .all(|&note| note == notes[next]);
+                    if !repeated || last == 0 {
+                        break next;
+                    }
+                };
+            }
+            melody.push(notes[index]);
+        }
+        melody
     }
-}
 
-impl Default for ScaleBuilder {
-    /// Creates a default ScaleBuilder instance
+    /// Returns the pitch classes of a scale built on a root note
     ///
-    /// This implementation provides a convenient way to create a new builder
-    /// using the `Default` trait, which is equivalent to calling `ScaleBuilder::new()`.
+    /// A Scale only stores intervals relative to its root, so the root is
+    /// supplied here. The root and every interval are folded into a single
+    /// octave, which makes the octave collapse onto the root.
+    ///
+    /// # Arguments
+    /// * `root` - The root note, as a MIDI note or pitch class
+    /// * `scale` - The scale to build on the root
     ///
     /// # Returns
-    /// A new ScaleBuilder instance with no intervals initially set
-    fn default() -> Self {
-        Self::new()
+    /// The distinct pitch classes (0-11) of the scale in ascending order
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{Scale, MAJOR};
+    /// // D major
+    /// assert_eq!(Scale::transpose_from_root(62, MAJOR), vec![1, 2, 4, 6, 7, 9, 11]);
+    /// ```
+    pub fn transpose_from_root(root: u8, scale: Scale) -> Vec<u8> {
+        scale
+            .to_chromatic_set(PitchClass::from_u8(root))
+            .indeces_on()
+            .map(|index| index as u8)
+            .collect()
     }
-}
-
-/// Builder for constructing Scale instances using step intervals
-///
-/// The ScaleStepBuilder provides a fluent interface for constructing Scale
-/// instances by adding consecutive step intervals. This is useful when you
-/// want to build a scale by specifying the step pattern (e.g., whole steps,
-/// half steps) rather than absolute semitone intervals.
-///
-/// # Examples
-/// ```
-/// use muzze_std::{MAJOR, ScaleStepBuilder, WHOLE, HALF};
-/// let scale = ScaleStepBuilder::default()
-///     .add_step(WHOLE)  // Whole step
-///     .add_step(WHOLE)  // Whole step
-///     .add_step(HALF)   // Half step
-///     .add_step(WHOLE)  // Whole step
-///     .add_step(WHOLE)  // Whole step
-///     .add_step(WHOLE)  // Whole step
-///     .add_step(HALF)   // Half step
-///     .build();
-/// // This creates a major scale with step pattern [2, 2, 1, 2, 2, 2, 1]
-/// assert_eq!(scale, MAJOR);
-/// ```
-pub struct ScaleStepBuilder {
-    /// The underlying ScaleBuilder used for bit manipulation
-    scale_builder: ScaleBuilder,
-    /// The last interval that was added to track cumulative position
-    last_interval: Interval,
-}
 
-impl ScaleStepBuilder {
-    /// Creates a new ScaleStepBuilder with no intervals initially set
+    /// Returns the pitch-class set of the scale built on a root
     ///
-    /// This method initializes the builder with an empty scale (no intervals set)
-    /// and resets the interval tracking. The builder can then be used to add
-    /// step intervals using the `add_step` method.
+    /// Bit `n` is set when pitch class `n` belongs to the scale; only the
+    /// lowest 12 bits are used.
+    ///
+    /// # Arguments
+    /// * `root` - The pitch class of the root of the scale
     ///
     /// # Returns
-    /// A new ScaleStepBuilder instance ready for configuration
+    /// A BitVec16 with one bit set for each pitch class of the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{PitchClass, MAJOR};
+    /// let set = MAJOR.to_chromatic_set(PitchClass::C);
+    /// assert_eq!(set.inner(), 0b1010_1011_0101);
+    /// ```
     #[inline]
-    const fn new() -> Self {
-        Self {
-            scale_builder: ScaleBuilder::new(),
-            last_interval: UNISON,
-        }
+    pub const fn to_chromatic_set(&self, root: PitchClass) -> BitVec16 {
+        BitVec16::from_u16(Self::rotate_chroma(self.chroma(), root.inner() as u32))
     }
 
-    /// Adds a step interval to the scale being constructed
+    /// Returns the pitch classes of the scale laid out over the chromatic scale
     ///
-    /// This method adds a step interval to the scale, where the step represents
-    /// the number of semitones to move from the last added interval. This allows
-    /// building scales by specifying step patterns (e.g., whole steps, half steps)
-    /// rather than absolute semitone positions.
+    /// Index `i` of the array stands for the note `i` semitones above the
+    /// tonic. It holds the pitch class of that note when it belongs to the
+    /// scale, and `None` otherwise. Unlike `degree_names`, the gaps of the
+    /// scale are kept in place.
     ///
     /// # Arguments
-    /// * `step` - The step interval to add from the last interval
+    /// * `tonic` - The pitch class of the root of the scale
     ///
     /// # Returns
-    /// A new ScaleStepBuilder with the specified step interval added
-    ///
-    /// # Panics
-    /// This method will panic if the resulting interval exceeds 16 semitones
+    /// A 12-element array of the pitch classes of the scale
     ///
     /// # Example
     /// ```
-    /// use muzze_std::{ScaleStepBuilder, WHOLE, HALF};
-    /// let scale = ScaleStepBuilder::default()
-    ///     .add_step(WHOLE)  // Add whole step (major 2nd)
-    ///     .add_step(WHOLE)  // Add whole step (major 3rd)
-    ///     .add_step(HALF)   // Add half step (perfect 4th)
-    ///     .build();
-    /// let intervals: Vec<u8> = scale.intervals().map(|i| i.inner()).collect();
-    /// assert_eq!(intervals, vec![2, 4, 5]);
+    /// use muzze_std::{PitchClass, MAJOR};
+    /// let pitch_classes = MAJOR.pitch_classes(PitchClass::G);
+    /// assert_eq!(pitch_classes[0], Some(PitchClass::G));
+    /// assert_eq!(pitch_classes[1], None);
+    /// assert_eq!(pitch_classes[11], Some(PitchClass::Fs));
     /// ```
-    pub const fn add_step(self, step: Step) -> Self {
-        let last_interval = self.last_interval.add_step(step);
-        Self {
-            scale_builder: self.scale_builder.set_interval(last_interval),
-            last_interval,
+    pub const fn pitch_classes(&self, tonic: PitchClass) -> [Option<PitchClass>; 12] {
+        let chroma = self.chroma();
+        let mut pitch_classes = [None; 12];
+        let mut index = 0;
+        while index < 12 {
+            if chroma & (1 << index) != 0 {
+                pitch_classes[index] = Some(tonic.transpose(index as u8));
+            }
+            index += 1;
         }
+        pitch_classes
     }
 
-    /// Finalizes the builder and returns the constructed Scale
+    /// Draws the scale on a one-octave piano keyboard, with C as the tonic
     ///
-    /// This method consumes the builder and returns the final Scale
-    /// instance with all the step intervals that were added during construction.
+    /// The keyboard is drawn on four lines: the names of the black keys, the
+    /// black keys as `[ ]`, the white keys as `| |`, and the names of the
+    /// white keys. The keys that belong to the scale are marked with a `*`.
     ///
     /// # Returns
-    /// The constructed Scale instance
+    /// The multi-line drawing of the keyboard
     ///
     /// # Example
     /// ```
-    /// use muzze_std::{MAJOR, ScaleStepBuilder, WHOLE, HALF};
-    /// let scale = ScaleStepBuilder::default()
-    ///     .add_step(WHOLE)
-    ///     .add_step(WHOLE)
-    ///     .add_step(HALF)
-    ///     .add_step(WHOLE)
-    ///     .add_step(WHOLE)
-    ///     .add_step(WHOLE)
-    ///     .add_step(HALF)
-    ///     .build();
-    /// // This creates a major scale with intervals [2, 4, 5, 7, 9, 11, 12]
-    /// assert_eq!(scale, MAJOR);
+    /// use muzze_std::PENTATONIC_MINOR;
+    /// assert_eq!(
+    ///     PENTATONIC_MINOR.to_ascii_art(),
+    ///     concat!(
+    ///         "  C# D#    F# G# A#\n",
+    ///         "  [ ][*]   [ ][ ][*]\n",
+    ///         "|*|| || ||*||*|| || |\n",
+    ///         " C  D  E  F  G  A  B",
+    ///     )
+    /// );
+    /// ```
+    pub fn to_ascii_art(&self) -> String {
+        const WHITE_KEYS: [(usize, &str); 7] = [
+            (0, "C"),
+            (2, "D"),
+            (4, "E"),
+            (5, "F"),
+            (7, "G"),
+            (9, "A"),
+            (11, "B"),
+        ];
+        const BLACK_KEYS: [(usize, usize, &str); 5] = [
+            (1, 2, "C#"),
+            (3, 5, "D#"),
+            (6, 11, "F#"),
+            (8, 14, "G#"),
+            (10, 17, "A#"),
+        ];
+
+        let chroma = self.chroma();
+        let mark = |semitone: usize| {
+            if chroma & (1 << semitone) != 0 {
+                '*'
+            } else {
+                ' '
+            }
+        };
+
+        let mut black_names = String::new();
+        let mut black_keys = String::new();
+        for (semitone, column, name) in BLACK_KEYS {
+            let padding = column - black_keys.len();
+            black_names.push_str(&format!("{:padding$}{name:<3}", ""));
+            black_keys.push_str(&format!("{:padding$}[{}]", "", mark(semitone)));
+        }
+
+        let white_keys = WHITE_KEYS
+            .iter()
+            .map(|(semitone, _)| format!("|{}|", mark(*semitone)))
+            .collect::<String>();
+        let white_names = WHITE_KEYS
+            .iter()
+            .map(|(_, name)| format!(" {name} "))
+            .collect::<String>();
+
+        [black_names, black_keys, white_keys, white_names]
+            .iter()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Formats the key of the scale as a LilyPond key signature
+    ///
+    /// `MAJOR` and `NATURAL_MINOR` are written as `\major` and `\minor`, and
+    /// the other church modes with their own mode names. Other scales use
+    /// `\minor` when they have a minor third and no major third, and
+    /// `\major` otherwise.
+    ///
+    /// # Arguments
+    /// * `root` - The pitch class of the root of the scale
+    ///
+    /// # Returns
+    /// The LilyPond key command for the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{PitchClass, DORIAN, HARMONIC_MINOR, MAJOR};
+    /// assert_eq!(MAJOR.to_lilypond_key(PitchClass::C), "\\key c \\major");
+    /// assert_eq!(DORIAN.to_lilypond_key(PitchClass::D), "\\key d \\dorian");
+    /// assert_eq!(HARMONIC_MINOR.to_lilypond_key(PitchClass::A), "\\key a \\minor");
+    /// ```
+    pub fn to_lilypond_key(&self, root: PitchClass) -> String {
+        let chroma = self.chroma();
+        let mode = if *self == MAJOR {
+            "major"
+        } else if *self == NATURAL_MINOR {
+            "minor"
+        } else if let Some(name) = self.church_mode_name() {
+            return format!("\\key {} \\{}", root.to_lilypond(), name.to_lowercase());
+        } else if chroma & (1 << 3) != 0 && chroma & (1 << 4) == 0 {
+            "minor"
+        } else {
+            "major"
+        };
+        format!("\\key {} \\{mode}", root.to_lilypond())
+    }
+
+    /// Returns the function of a note within the scale
+    ///
+    /// In a heptatonic scale the function follows the position of the note
+    /// among the scale tones, so the augmented fourth of `LYDIAN` is its
+    /// `Fourth` and the diminished fifth of `LOCRIAN` its `Fifth`. In other
+    /// scales the function follows the usual spelling of the interval above
+    /// the tonic: 1 and 2 semitones are a second, 3 and 4 a third, 5 a fourth,
+    /// 6 and 7 a fifth, 8 and 9 a sixth, and 10 and 11 a seventh.
+    ///
+    /// # Arguments
+    /// * `semitone` - The distance of the note above the tonic, taken modulo 12
+    ///
+    /// # Returns
+    /// The function of the note, or `ScaleDegreeFunction::NonDiatonic` if the
+    /// note is not part of the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{ScaleDegreeFunction, MAJOR, NATURAL_MINOR};
+    /// assert_eq!(MAJOR.get_note_function(4), ScaleDegreeFunction::Third);
+    /// assert_eq!(MAJOR.get_note_function(3), ScaleDegreeFunction::NonDiatonic);
+    /// assert_eq!(NATURAL_MINOR.get_note_function(3), ScaleDegreeFunction::Third);
+    /// ```
+    pub fn get_note_function(&self, semitone: u8) -> ScaleDegreeFunction {
+        const FUNCTIONS: [ScaleDegreeFunction; 7] = [
+            ScaleDegreeFunction::Root,
+            ScaleDegreeFunction::Second,
+            ScaleDegreeFunction::Third,
+            ScaleDegreeFunction::Fourth,
+            ScaleDegreeFunction::Fifth,
+            ScaleDegreeFunction::Sixth,
+            ScaleDegreeFunction::Seventh,
+        ];
+
+        let semitone = semitone % 12;
+        let chroma = self.chroma();
+        if chroma & (1 << semitone) == 0 {
+            return ScaleDegreeFunction::NonDiatonic;
+        }
+
+        if self.degree_count() == 7 {
+            let position = (chroma & ((1 << semitone) - 1)).count_ones() as usize;
+            return FUNCTIONS[position];
+        }
+
+        match semitone {
+            0 => ScaleDegreeFunction::Root,
+            1 | 2 => ScaleDegreeFunction::Second,
+            3 | 4 => ScaleDegreeFunction::Third,
+            5 => ScaleDegreeFunction::Fourth,
+            6 | 7 => ScaleDegreeFunction::Fifth,
+            8 | 9 => ScaleDegreeFunction::Sixth,
+            _ => ScaleDegreeFunction::Seventh,
+        }
+    }
+
+    /// Pairs each scale degree with its pitch class for a given tonic
+    ///
+    /// Degree 1 is the tonic itself, followed by one degree for each interval
+    /// of the scale in ascending order. The pitch class of a degree is the
+    /// tonic transposed by the interval, wrapping around the octave.
+    ///
+    /// # Arguments
+    /// * `tonic` - The pitch class of the root of the scale
+    ///
+    /// # Returns
+    /// An iterator that yields (degree number, pitch class) pairs
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{PitchClass, PENTATONIC_MINOR};
+    /// let names: Vec<_> = PENTATONIC_MINOR.degree_names(PitchClass::A).collect();
+    /// assert_eq!(
+    ///     names,
+    ///     vec![
+    ///         (1, PitchClass::A),
+    ///         (2, PitchClass::C),
+    ///         (3, PitchClass::D),
+    ///         (4, PitchClass::E),
+    ///         (5, PitchClass::G),
+    ///     ]
+    /// );
+    /// ```
+    pub fn degree_names(&self, tonic: PitchClass) -> impl Iterator<Item = (u8, PitchClass)> {
+        std::iter::once(0)
+            .chain(self.intervals().map(|interval| interval.inner()))
+            .enumerate()
+            .map(move |(index, semitones)| (index as u8 + 1, tonic.transpose(semitones)))
+    }
+
+    /// Returns the interval of the scale tone at the given index
+    ///
+    /// The scale tones are the distinct pitch classes of the scale, folded
+    /// into a single octave and sorted in ascending order. Index 0 is always
+    /// the root (unison); tones beyond the octave are folded back, so the
+    /// octave itself is not counted as a separate tone.
+    ///
+    /// # Arguments
+    /// * `index` - The zero-based index of the scale tone
+    ///
+    /// # Returns
+    /// The interval from the root to the scale tone, or `None` if the scale
+    /// has fewer tones than `index + 1`
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, MAJOR_THIRD, UNISON};
+    /// assert_eq!(MAJOR.get_interval_at(0), Some(UNISON));
+    /// assert_eq!(MAJOR.get_interval_at(2), Some(MAJOR_THIRD));
+    /// assert_eq!(MAJOR.get_interval_at(7), None);
+    /// ```
+    pub fn get_interval_at(&self, index: usize) -> Option<Interval> {
+        BitVec16::from_u16(self.chroma())
+            .indeces_on()
+            .nth(index)
+            .map(|pc| Interval::from(pc as u8))
+    }
+
+    /// Returns the number of distinct tones in the scale, including the root
+    ///
+    /// Tones are counted as pitch classes, so the octave is not counted
+    /// separately from the root.
+    ///
+    /// # Returns
+    /// The number of distinct pitch classes in the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, PENTATONIC_MINOR};
+    /// assert_eq!(MAJOR.degree_count(), 7);
+    /// assert_eq!(PENTATONIC_MINOR.degree_count(), 5);
     /// ```
     #[inline]
-    pub const fn build(self) -> Scale {
-        self.scale_builder.build()
+    pub const fn degree_count(&self) -> usize {
+        self.chroma().count_ones() as usize
+    }
+
+    /// Returns the number of notes in the scale, including the root
+    ///
+    /// This is `degree_count` as a `u8`: the octave is not counted as a
+    /// separate note, so the major scale has 7 notes.
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{BLUES_MINOR, MAJOR};
+    /// assert_eq!(MAJOR.note_count(), 7);
+    /// assert_eq!(BLUES_MINOR.note_count(), 6);
+    /// ```
+    #[inline]
+    pub const fn note_count(&self) -> u8 {
+        self.degree_count() as u8
+    }
+
+    /// Returns true if the scale has 5 notes
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, PENTATONIC_MAJOR};
+    /// assert!(PENTATONIC_MAJOR.is_pentatonic());
+    /// assert!(!MAJOR.is_pentatonic());
+    /// ```
+    #[inline]
+    pub const fn is_pentatonic(&self) -> bool {
+        self.note_count() == 5
+    }
+
+    /// Returns true if the scale has 6 notes
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{BLUES_MINOR, JAZZ_WHOLE_TONE};
+    /// assert!(BLUES_MINOR.is_hexatonic());
+    /// assert!(JAZZ_WHOLE_TONE.is_hexatonic());
+    /// ```
+    #[inline]
+    pub const fn is_hexatonic(&self) -> bool {
+        self.note_count() == 6
+    }
+
+    /// Returns true if the scale has 7 notes
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{DORIAN, MAJOR};
+    /// assert!(MAJOR.is_heptatonic());
+    /// assert!(DORIAN.is_heptatonic());
+    /// ```
+    #[inline]
+    pub const fn is_heptatonic(&self) -> bool {
+        self.note_count() == 7
+    }
+
+    /// Returns true if the scale has 8 notes
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{BIBOP_MAJOR, MAJOR};
+    /// assert!(BIBOP_MAJOR.is_octatonic());
+    /// assert!(!MAJOR.is_octatonic());
+    /// ```
+    #[inline]
+    pub const fn is_octatonic(&self) -> bool {
+        self.note_count() == 8
+    }
+
+    /// Compares the brightness of this scale with another scale
+    ///
+    /// The tones of both scales are aligned by position above the root. Each
+    /// tone of this scale that is higher than the matching tone of `base`
+    /// counts as +1, and each lower tone counts as -1. A positive result means
+    /// this scale is brighter than `base`, a negative result means it is darker.
+    ///
+    /// # Arguments
+    /// * `base` - The scale to compare against
+    ///
+    /// # Returns
+    /// The number of raised tones minus the number of lowered tones, or 0 if
+    /// the two scales do not have the same `degree_count()`
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{LYDIAN, MAJOR, MIXOLYDIAN, PENTATONIC_MAJOR};
+    /// assert_eq!(LYDIAN.brightness_relative_to(MAJOR), 1);
+    /// assert_eq!(MIXOLYDIAN.brightness_relative_to(MAJOR), -1);
+    /// assert_eq!(MAJOR.brightness_relative_to(PENTATONIC_MAJOR), 0);
+    /// ```
+    pub const fn brightness_relative_to(&self, base: Scale) -> i8 {
+        if self.degree_count() != base.degree_count() {
+            return 0;
+        }
+
+        let lhs = self.chroma();
+        let rhs = base.chroma();
+        let mut brightness = 0;
+        let mut l = 1;
+        let mut r = 1;
+        loop {
+            while l < 12 && lhs & (1 << l) == 0 {
+                l += 1;
+            }
+            while r < 12 && rhs & (1 << r) == 0 {
+                r += 1;
+            }
+            if l >= 12 || r >= 12 {
+                break;
+            }
+            if l > r {
+                brightness += 1;
+            } else if l < r {
+                brightness -= 1;
+            }
+            l += 1;
+            r += 1;
+        }
+        brightness
+    }
+
+    /// Returns the intervals common to both scales
+    ///
+    /// # Arguments
+    /// * `other` - The scale to intersect with
+    ///
+    /// # Returns
+    /// A new Scale containing only the intervals present in both scales
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, NATURAL_MINOR};
+    /// let common = MAJOR.intersection(NATURAL_MINOR);
+    /// let intervals: Vec<u8> = common.intervals().map(|i| i.inner()).collect();
+    /// assert_eq!(intervals, vec![2, 5, 7, 12]);
+    /// ```
+    #[inline]
+    pub const fn intersection(&self, other: Scale) -> Scale {
+        Self(self.0.intersection(other.0))
+    }
+
+    /// Returns the intervals present in either scale
+    ///
+    /// # Arguments
+    /// * `other` - The scale to unite with
+    ///
+    /// # Returns
+    /// A new Scale containing the intervals of both scales
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{PENTATONIC_MAJOR, PENTATONIC_MINOR};
+    /// let all = PENTATONIC_MAJOR.union(PENTATONIC_MINOR);
+    /// let intervals: Vec<u8> = all.intervals().map(|i| i.inner()).collect();
+    /// assert_eq!(intervals, vec![2, 3, 4, 5, 7, 9, 10]);
+    /// ```
+    #[inline]
+    pub const fn union(&self, other: Scale) -> Scale {
+        Self(self.0.union(other.0))
+    }
+
+    /// Returns the intervals present in exactly one of the two scales
+    ///
+    /// Musically these are the notes that differ between the two scales.
+    ///
+    /// # Arguments
+    /// * `other` - The scale to compare with
+    ///
+    /// # Returns
+    /// A new Scale containing the intervals set in one scale but not in both
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, MELODIC_MINOR};
+    /// // The melodic minor only differs from major by its flat third
+    /// let differ = MAJOR.symmetric_difference(MELODIC_MINOR);
+    /// let intervals: Vec<u8> = differ.intervals().map(|i| i.inner()).collect();
+    /// assert_eq!(intervals, vec![3, 4]);
+    /// ```
+    #[inline]
+    pub const fn symmetric_difference(&self, other: Scale) -> Scale {
+        Self(self.0.symmetric_difference(other.0))
+    }
+
+    /// Alias of `symmetric_difference`
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{NATURAL_MINOR, HARMONIC_MINOR};
+    /// assert_eq!(
+    ///     NATURAL_MINOR.xor_with(HARMONIC_MINOR),
+    ///     NATURAL_MINOR.symmetric_difference(HARMONIC_MINOR)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn xor_with(&self, other: Scale) -> Scale {
+        self.symmetric_difference(other)
+    }
+
+    /// Returns the intervals of this scale that are not part of another scale
+    ///
+    /// # Arguments
+    /// * `other` - The scale whose intervals are removed
+    ///
+    /// # Returns
+    /// A new Scale containing the intervals exclusive to `self`
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, PENTATONIC_MAJOR};
+    /// // The pentatonic constants do not include the octave
+    /// let avoid = MAJOR.subtract(PENTATONIC_MAJOR);
+    /// let intervals: Vec<u8> = avoid.intervals().map(|i| i.inner()).collect();
+    /// assert_eq!(intervals, vec![5, 11, 12]);
+    /// ```
+    #[inline]
+    pub const fn subtract(&self, other: Scale) -> Scale {
+        Self(self.0.difference(other.0))
+    }
+
+    /// Checks whether every interval of this scale is also part of another scale
+    ///
+    /// # Arguments
+    /// * `other` - The candidate superset
+    ///
+    /// # Returns
+    /// `true` if all intervals of `self` are set in `other`
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, PENTATONIC_MAJOR};
+    /// assert!(PENTATONIC_MAJOR.is_subset_of(MAJOR));
+    /// assert!(!MAJOR.is_subset_of(PENTATONIC_MAJOR));
+    /// ```
+    #[inline]
+    pub const fn is_subset_of(&self, other: Scale) -> bool {
+        other.0.contains(self.0)
+    }
+
+    /// Checks whether this scale contains every interval of another scale
+    ///
+    /// # Arguments
+    /// * `other` - The candidate subset
+    ///
+    /// # Returns
+    /// `true` if all intervals of `other` are set in `self`
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, PENTATONIC_MAJOR};
+    /// assert!(MAJOR.is_superset_of(PENTATONIC_MAJOR));
+    /// ```
+    #[inline]
+    pub const fn is_superset_of(&self, other: Scale) -> bool {
+        other.is_subset_of(*self)
+    }
+
+    /// Builds the chord stacked on a degree of the scale
+    ///
+    /// The chord is built by stacking every other scale tone above the degree,
+    /// as in diatonic harmony: a triad takes the scale tones at positions 0, 2
+    /// and 4 above the degree, a seventh chord adds position 6 and a ninth
+    /// chord position 8. Each stacked tone becomes the chord degree it stands
+    /// for (third, fifth, ...) with the accidental given by its distance from
+    /// the chord root.
+    ///
+    /// # Arguments
+    /// * `degree_index` - The zero-based scale degree to harmonize (0 is the tonic)
+    /// * `chord_type` - The shape of the chord to stack
+    ///
+    /// # Returns
+    /// The unnamed chord, or `None` if the degree is out of range, if the scale
+    /// has too few tones to stack the chord, or if a stacked tone cannot be
+    /// spelled as the expected degree (as in some non-heptatonic scales)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{ChordType, MAJOR, MAJOR_TRIAD, MINOR_SEVENTH_CHORD};
+    /// assert_eq!(MAJOR.get_chord_on_degree(0, ChordType::Triad), Some(MAJOR_TRIAD));
+    /// assert_eq!(
+    ///     MAJOR.get_chord_on_degree(1, ChordType::Seventh),
+    ///     Some(MINOR_SEVENTH_CHORD)
+    /// );
+    /// assert_eq!(MAJOR.get_chord_on_degree(7, ChordType::Triad), None);
+    /// ```
+    pub fn get_chord_on_degree(&self, degree_index: u8, chord_type: ChordType) -> Option<Chord> {
+        let tones = self.degree_count();
+        let index = degree_index as usize;
+        let stack = chord_type.stack();
+        let wraps_onto_itself = stack
+            .iter()
+            .enumerate()
+            .any(|(i, (_, a))| stack[..i].iter().any(|(_, b)| a % tones == b % tones));
+        if index >= tones || wraps_onto_itself {
+            return None;
+        }
+
+        let root = self.get_interval_at(index)?.inner();
+        stack
+            .iter()
+            .try_fold(
+                ChordBuilder::with_root(""),
+                |builder, (degree, position)| {
+                    let tone = self.get_interval_at((index + position) % tones)?.inner();
+                    let mut semitones = (tone + 12 - root) % 12;
+                    if *degree > 7 {
+                        semitones += 12;
+                    }
+
+                    let natural =
+                        Interval::from_degree(&Degree::new(*degree, DegreeAccidental::Natural))?;
+                    let accidental = DegreeAccidental::from_semitone_offset(
+                        semitones as i8 - natural.inner() as i8,
+                    )?;
+                    Some(builder.set_degree(Degree::new(*degree, accidental)))
+                },
+            )
+            .map(ChordBuilder::build)
+    }
+
+    /// Applies a chord template to a degree of the scale
+    ///
+    /// Each degree of the chord is looked up in the scale instead of using its
+    /// fixed interval: the n-th degree of the chord is the scale tone n - 1
+    /// positions above `degree_index`, continuing into the next octaves when
+    /// needed. The accidentals of the chord are ignored, so the result is the
+    /// scale-correct version of the chord shape on that degree.
+    ///
+    /// # Arguments
+    /// * `chord` - The chord template to apply
+    /// * `degree_index` - The zero-based scale degree of the chord root (0 is the tonic)
+    ///
+    /// # Returns
+    /// The semitone offsets from the scale root of every chord voice, in
+    /// ascending degree order, or `None` if the degree is out of range
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, MAJOR_SEVENTH_CHORD, MAJOR_TRIAD};
+    /// assert_eq!(MAJOR.apply_chord(&MAJOR_TRIAD, 0), Some(vec![0, 4, 7]));
+    /// // On the second degree the third and seventh follow the scale
+    /// assert_eq!(
+    ///     MAJOR.apply_chord(&MAJOR_SEVENTH_CHORD, 1),
+    ///     Some(vec![2, 5, 9, 12])
+    /// );
+    /// assert_eq!(MAJOR.apply_chord(&MAJOR_TRIAD, 7), None);
+    /// ```
+    pub fn apply_chord(&self, chord: &Chord, degree_index: u8) -> Option<Vec<u8>> {
+        let tones = self.degree_count();
+        let index = degree_index as usize;
+        if index >= tones {
+            return None;
+        }
+
+        chord
+            .degrees()
+            .map(|degree| {
+                let position = index + degree.degree() as usize - 1;
+                let tone = self.get_interval_at(position % tones)?.inner();
+                Some(tone + 12 * (position / tones) as u8)
+            })
+            .collect()
+    }
+
+    /// Returns the triads built on every degree of the scale
+    ///
+    /// The triads are built with `get_chord_on_degree`, in ascending degree
+    /// order. Degrees on which no triad can be built are skipped.
+    ///
+    /// # Returns
+    /// The unnamed diatonic triads of the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{DIMINISHED_TRIAD, MAJOR, MAJOR_TRIAD, MINOR_TRIAD};
+    /// assert_eq!(
+    ///     MAJOR.diatonic_triads(),
+    ///     vec![
+    ///         MAJOR_TRIAD,
+    ///         MINOR_TRIAD,
+    ///         MINOR_TRIAD,
+    ///         MAJOR_TRIAD,
+    ///         MAJOR_TRIAD,
+    ///         MINOR_TRIAD,
+    ///         DIMINISHED_TRIAD
+    ///     ]
+    /// );
+    /// ```
+    pub fn diatonic_triads(&self) -> Vec<Chord> {
+        self.diatonic_chords(ChordType::Triad)
+    }
+
+    /// Returns the seventh chords built on every degree of the scale
+    ///
+    /// The chords are built with `get_chord_on_degree`, in ascending degree
+    /// order. Degrees on which no seventh chord can be built are skipped.
+    ///
+    /// # Returns
+    /// The unnamed diatonic seventh chords of the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{DOMINANT_SEVENTH, MAJOR};
+    /// let sevenths = MAJOR.diatonic_seventh_chords();
+    /// assert_eq!(sevenths.len(), 7);
+    /// assert_eq!(sevenths[4], DOMINANT_SEVENTH);
+    /// ```
+    pub fn diatonic_seventh_chords(&self) -> Vec<Chord> {
+        self.diatonic_chords(ChordType::Seventh)
+    }
+
+    /// Returns the chords of the given type built on every degree of the scale
+    fn diatonic_chords(&self, chord_type: ChordType) -> Vec<Chord> {
+        (0..self.degree_count() as u8)
+            .filter_map(|index| self.get_chord_on_degree(index, chord_type))
+            .collect()
+    }
+
+    /// Finds the diatonic chord closest to a chord
+    ///
+    /// The candidates are the diatonic triads of the scale, or its diatonic
+    /// seventh chords when the chord has more than three voices. The candidate
+    /// with the smallest `Chord::voice_leading_distance` to the chord is
+    /// returned, and ties are broken in favour of the lower scale degree.
+    /// This is useful to suggest a diatonic replacement for a borrowed or
+    /// altered chord.
+    ///
+    /// # Arguments
+    /// * `chord` - The chord to replace
+    ///
+    /// # Returns
+    /// The zero-based scale degree and the unnamed diatonic chord built on
+    /// it, or `None` if no diatonic chord can be built on the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{AUGMENTED_TRIAD, DOMINANT_SEVENTH, MAJOR, MAJOR_TRIAD};
+    /// assert_eq!(MAJOR.closest_chord(&AUGMENTED_TRIAD), Some((0, MAJOR_TRIAD)));
+    /// assert_eq!(MAJOR.closest_chord(&DOMINANT_SEVENTH), Some((4, DOMINANT_SEVENTH)));
+    /// ```
+    pub fn closest_chord(&self, chord: &Chord) -> Option<(u8, Chord)> {
+        let chord_type = if chord.degree_count() > 3 {
+            ChordType::Seventh
+        } else {
+            ChordType::Triad
+        };
+
+        (0..self.degree_count() as u8)
+            .filter_map(|index| Some((index, self.get_chord_on_degree(index, chord_type)?)))
+            .min_by_key(|(_, candidate)| chord.voice_leading_distance(candidate))
+    }
+
+    /// Assigns a diatonic triad to every note of a melody
+    ///
+    /// The candidates are the diatonic triads of the scale, tried in order of
+    /// harmonic function: the tonic (I), the subdominant (IV), the dominant (V)
+    /// and then the remaining degrees in ascending order. Each melody note gets
+    /// the first candidate that contains it, so in a major scale the third is
+    /// harmonized with I rather than with iii or vi. A note outside the scale
+    /// gets the candidate with a chord tone closest to it, with ties broken
+    /// in the same order.
+    ///
+    /// # Arguments
+    /// * `melody` - The melody notes as semitone offsets from the scale root
+    ///
+    /// # Returns
+    /// One (degree index, unnamed diatonic triad) pair per melody note, where
+    /// the degree index is the zero-based scale degree the triad is built on.
+    /// Every entry is `None` when no diatonic triad can be built on the scale,
+    /// as for `CHROMATIC`.
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{CHROMATIC, MAJOR, MAJOR_TRIAD};
+    /// // E, F, D and G in C major: I, IV, V and I
+    /// let chords = MAJOR.harmonize(&[4, 5, 2, 7]);
+    /// assert_eq!(
+    ///     chords,
+    ///     vec![
+    ///         Some((0, MAJOR_TRIAD)),
+    ///         Some((3, MAJOR_TRIAD)),
+    ///         Some((4, MAJOR_TRIAD)),
+    ///         Some((0, MAJOR_TRIAD)),
+    ///     ]
+    /// );
+    /// // B is only found in iii and V
+    /// assert_eq!(MAJOR.harmonize(&[11]), vec![Some((4, MAJOR_TRIAD))]);
+    /// // A is found in IV and vi, in any octave
+    /// assert_eq!(MAJOR.harmonize(&[9]), vec![Some((3, MAJOR_TRIAD))]);
+    /// assert_eq!(MAJOR.harmonize(&[21]), vec![Some((3, MAJOR_TRIAD))]);
+    /// // No triad can be spelled on the chromatic scale
+    /// assert_eq!(CHROMATIC.harmonize(&[0]), vec![None]);
+    /// ```
+    pub fn harmonize(&self, melody: &[u8]) -> Vec<Option<(u8, Chord)>> {
+        let mut candidates: Vec<(u8, u16, Chord)> = (0..self.degree_count())
+            .filter_map(|index| {
+                let root = self.get_interval_at(index)?;
+                let chord = self.get_chord_on_degree(index as u8, ChordType::Triad)?;
+                let set = Self::rotate_chroma(chord.semitone_set(), root.inner() as u32);
+                Some((index as u8, set, chord))
+            })
+            .collect();
+        candidates.sort_by_key(|(index, _, _)| match index {
+            0 => 0,
+            3 => 1,
+            4 => 2,
+            index => index + 3,
+        });
+
+        melody
+            .iter()
+            .map(|note| {
+                let pitch_class = (note % 12) as u32;
+                candidates
+                    .iter()
+                    .min_by_key(|(_, set, _)| Self::chroma_distance(*set, pitch_class))
+                    .map(|(index, _, chord)| (*index, *chord))
+            })
+            .collect()
+    }
+
+    /// Returns the number of semitones from a pitch class to the nearest one
+    /// in a 12-bit pitch-class set, in either direction around the octave
+    fn chroma_distance(set: u16, pitch_class: u32) -> u32 {
+        (0..12)
+            .filter(|candidate| set & (1 << candidate) != 0)
+            .map(|candidate: u32| {
+                let distance = candidate.abs_diff(pitch_class);
+                distance.min(12 - distance)
+            })
+            .min()
+            .unwrap_or(u32::MAX)
+    }
+
+    /// Finds the scale degree a chord is built on
+    ///
+    /// Every scale tone is tried as the root of the chord, in ascending order,
+    /// and the first one for which all the chord tones belong to the scale is
+    /// returned. This is the inverse of `get_chord_on_degree` and is the basis
+    /// of Roman numeral analysis.
+    ///
+    /// # Arguments
+    /// * `chord` - The chord to look for
+    ///
+    /// # Returns
+    /// The zero-based degree the chord is rooted on, or `None` if the chord
+    /// does not fit on any degree of the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, DOMINANT_SEVENTH, MINOR_TRIAD, AUGMENTED_TRIAD};
+    /// assert_eq!(MAJOR.find_chord(&MINOR_TRIAD), Some(1));
+    /// assert_eq!(MAJOR.find_chord(&DOMINANT_SEVENTH), Some(4));
+    /// assert_eq!(MAJOR.find_chord(&AUGMENTED_TRIAD), None);
+    /// ```
+    pub fn find_chord(&self, chord: &Chord) -> Option<u8> {
+        let chroma = self.chroma();
+        let chord_set = chord.semitone_set();
+
+        (0..self.degree_count())
+            .find(|index| {
+                self.get_interval_at(*index).is_some_and(|root| {
+                    let shifted = Self::rotate_chroma(chord_set, root.inner() as u32);
+                    shifted & chroma == shifted
+                })
+            })
+            .map(|index| index as u8)
+    }
+
+    /// Checks whether a chord can be built on one of the scale degrees
+    ///
+    /// This is `Chord::fits_scale` tried on every scale tone instead of the
+    /// tonic only: the chord is diatonic to the scale when, rooted on some
+    /// scale degree, all its tones belong to the scale (see `find_chord`).
+    ///
+    /// # Arguments
+    /// * `chord` - The chord to look for
+    ///
+    /// # Returns
+    /// `true` if the chord is diatonic to the scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{AUGMENTED_TRIAD, DIMINISHED_TRIAD, MAJOR, MAJOR_TRIAD};
+    /// assert!(MAJOR.contains_chord(MAJOR_TRIAD));
+    /// assert!(MAJOR.contains_chord(DIMINISHED_TRIAD));
+    /// assert!(!MAJOR.contains_chord(AUGMENTED_TRIAD));
+    /// ```
+    #[inline]
+    pub fn contains_chord(&self, chord: Chord) -> bool {
+        self.find_chord(&chord).is_some()
+    }
+
+    /// Returns the prime form of the scale's pitch-class set
+    ///
+    /// The prime form is the canonical representative of a set class: all
+    /// transpositions of the pitch-class set and of its inversion are compared
+    /// and the most compact, left-packed one is selected. Two scales share a
+    /// prime form exactly when one can be transposed and/or inverted into the other,
+    /// which makes it useful for set-theory analysis.
+    ///
+    /// The resulting scale always includes the octave.
+    ///
+    /// # Returns
+    /// A new Scale holding the prime form
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, NATURAL_MINOR, PENTATONIC_MAJOR};
+    /// assert_eq!(MAJOR.prime_form(), NATURAL_MINOR.prime_form());
+    /// let intervals: Vec<u8> = PENTATONIC_MAJOR.prime_form().intervals().map(|i| i.inner()).collect();
+    /// assert_eq!(intervals, vec![2, 4, 7, 9, 12]);
+    /// ```
+    pub fn prime_form(&self) -> Scale {
+        let set = self.chroma();
+        let inversion = (0..12)
+            .filter(|pc| set & (1 << pc) != 0)
+            .fold(0u16, |acc, pc| acc | 1 << ((12 - pc) % 12));
+
+        let prime = [set, inversion]
+            .into_iter()
+            .flat_map(|set| {
+                (0..12)
+                    .filter(move |pc| set & (1 << pc) != 0)
+                    .map(move |pc| Self::rotate_chroma(set, 12 - pc))
+            })
+            .min()
+            .unwrap_or(set);
+
+        Self::from_chroma(prime)
+    }
+
+    /// Transposes the pitch-class set of the scale up by a number of semitones
+    ///
+    /// Every tone of the scale, including the root, is moved up and folded
+    /// back into a single octave. The result is measured from the same root,
+    /// and a tone landing on the root is stored as the octave.
+    ///
+    /// # Arguments
+    /// * `semitones` - The number of semitones to move up
+    ///
+    /// # Returns
+    /// A new Scale holding the transposed pitch-class set
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::MAJOR;
+    /// let up = MAJOR.transpose_up(7);
+    /// let intervals: Vec<u8> = up.intervals().map(|i| i.inner()).collect();
+    /// assert_eq!(intervals, vec![2, 4, 6, 7, 9, 11, 12]);
+    /// ```
+    #[inline]
+    pub const fn transpose_up(&self, semitones: u8) -> Scale {
+        Self::from_chroma(Self::rotate_chroma(self.chroma(), semitones as u32))
+    }
+
+    /// Returns the 12 chromatic transpositions of the scale
+    ///
+    /// The transposition at index `n` is `transpose_up(n)`: the pitch-class
+    /// set of the scale moved up by `n` semitones, measured from the same
+    /// root. The transposition at index 0 is the scale folded into a single
+    /// octave, with the root stored as the octave.
+    ///
+    /// # Returns
+    /// An array with the scale transposed by 0 to 11 semitones
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::MAJOR;
+    /// let transpositions = MAJOR.transpositions();
+    /// assert_eq!(transpositions[0], MAJOR);
+    /// assert_eq!(transpositions[7], MAJOR.transpose_up(7));
+    /// ```
+    pub const fn transpositions(&self) -> [Scale; 12] {
+        let mut transpositions = [*self; 12];
+        let mut semitones = 0;
+        while semitones < 12 {
+            transpositions[semitones] = self.transpose_up(semitones as u8);
+            semitones += 1;
+        }
+        transpositions
+    }
+
+    /// Checks whether some transposition of the scale is the other scale
+    ///
+    /// Two scales are transpositionally equivalent when they hold the same
+    /// pitch-class set up to a transposition. Modes of the same scale share
+    /// their pitch-class set, so they are all equivalent to each other: C
+    /// dorian holds the same tones as B♭ major, and `MAJOR` is therefore
+    /// equivalent to `DORIAN`. Likewise `PENTATONIC_MINOR` holds the tones of
+    /// `PENTATONIC_MAJOR` a minor third up.
+    ///
+    /// # Arguments
+    /// * `other` - The scale to compare with
+    ///
+    /// # Returns
+    /// `true` if one of the `transpositions` of the scale has the same
+    /// pitch-class set as `other`
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{DORIAN, HARMONIC_MINOR, MAJOR, PENTATONIC_MAJOR, PENTATONIC_MINOR};
+    /// assert!(MAJOR.is_transpositionally_equivalent_to(DORIAN));
+    /// assert!(PENTATONIC_MAJOR.is_transpositionally_equivalent_to(PENTATONIC_MINOR));
+    /// assert!(!MAJOR.is_transpositionally_equivalent_to(HARMONIC_MINOR));
+    /// ```
+    pub fn is_transpositionally_equivalent_to(&self, other: Scale) -> bool {
+        self.transpositions().contains(&other.transpose_up(0))
+    }
+
+    /// Returns true if transposing the scale by a tritone gives the same
+    /// pitch-class set
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{JAZZ_WHOLEHALF_DIMINISHED, JAZZ_WHOLE_TONE, MAJOR};
+    /// assert!(JAZZ_WHOLE_TONE.has_tritone_symmetry());
+    /// assert!(JAZZ_WHOLEHALF_DIMINISHED.has_tritone_symmetry());
+    /// assert!(!MAJOR.has_tritone_symmetry());
+    /// ```
+    #[inline]
+    pub const fn has_tritone_symmetry(&self) -> bool {
+        self.transpose_up(6).chroma() == self.chroma()
+    }
+
+    /// Returns the modes of the scale, one for each scale tone
+    ///
+    /// The n-th mode starts on the n-th tone of the scale (see
+    /// `get_interval_at`), so the first mode is the scale itself. Each mode
+    /// is built from the pitch-class set of the scale and therefore always
+    /// includes the octave. Symmetric scales yield repeated modes.
+    ///
+    /// # Returns
+    /// An iterator over `degree_count()` scales
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{DORIAN, LOCRIAN, MAJOR};
+    /// let modes: Vec<_> = MAJOR.modes().collect();
+    /// assert_eq!(modes.len(), 7);
+    /// assert_eq!(modes[0], MAJOR);
+    /// assert_eq!(modes[1], DORIAN);
+    /// assert_eq!(modes[6], LOCRIAN);
+    /// ```
+    pub fn modes(&self) -> impl Iterator<Item = Scale> {
+        let chroma = self.chroma();
+        BitVec16::from_u16(chroma)
+            .indeces_on()
+            .map(move |pc| Self::from_chroma(Self::rotate_chroma(chroma, 12 - pc as u32)))
+    }
+
+    /// Returns the distinct modes of the scale
+    ///
+    /// Two scales belong to the same modal family when one is a rotation of
+    /// the other. This is `modes()` with the repeated rotations of symmetric
+    /// scales removed, keeping the order in which each mode first appears.
+    ///
+    /// # Returns
+    /// A vector with every distinct mode of the scale, starting with the
+    /// scale folded into one octave (root stored as the octave)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{JAZZ_WHOLEHALF_DIMINISHED, JAZZ_WHOLE_TONE, MAJOR};
+    /// assert_eq!(MAJOR.modal_family().len(), 7);
+    /// assert_eq!(JAZZ_WHOLEHALF_DIMINISHED.modal_family().len(), 2);
+    /// assert_eq!(JAZZ_WHOLE_TONE.modal_family().len(), 1);
+    /// ```
+    pub fn modal_family(&self) -> Vec<Scale> {
+        let mut family = Vec::with_capacity(self.degree_count());
+        for mode in self.modes() {
+            if !family.contains(&mode) {
+                family.push(mode);
+            }
+        }
+        family
+    }
+
+    /// Returns the pentatonic scale embedded in a heptatonic scale
+    ///
+    /// Every way of removing two tones other than the root is tried, keeping
+    /// the five-tone subsets that are a rotation of the major pentatonic scale
+    /// (the pentatonic family).
+    /// `PENTATONIC_MAJOR` is preferred, then `PENTATONIC_MINOR`, then the first
+    /// subset found. Like the pentatonic constants, the result does not
+    /// include the octave.
+    ///
+    /// # Returns
+    /// The pentatonic subset, or `None` if the scale does not have seven
+    /// tones or if no pentatonic subset exists
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{HARMONIC_MINOR, MAJOR, NATURAL_MINOR, PENTATONIC_MAJOR, PENTATONIC_MINOR};
+    /// assert_eq!(MAJOR.pentatonic_subset(), Some(PENTATONIC_MAJOR));
+    /// assert_eq!(NATURAL_MINOR.pentatonic_subset(), Some(PENTATONIC_MINOR));
+    /// assert_eq!(HARMONIC_MINOR.pentatonic_subset(), None);
+    /// ```
+    pub fn pentatonic_subset(&self) -> Option<Scale> {
+        if self.degree_count() != 7 {
+            return None;
+        }
+
+        let chroma = self.chroma();
+        let tones = BitVec16::from_u16(chroma)
+            .indeces_on()
+            .skip(1)
+            .collect::<Vec<_>>();
+        let subsets = tones.iter().enumerate().flat_map(|(i, a)| {
+            tones[i + 1..]
+                .iter()
+                .map(move |b| chroma & !(1 << a) & !(1 << b))
+        });
+        let pentatonic = PENTATONIC_MAJOR.chroma();
+        let pentatonics = subsets
+            .filter(|set| (0..12).any(|r| Self::rotate_chroma(pentatonic, r) == *set))
+            .map(|set| Self::from_u16(set >> 1))
+            .collect::<Vec<_>>();
+
+        [PENTATONIC_MAJOR, PENTATONIC_MINOR]
+            .into_iter()
+            .find(|preferred| pentatonics.contains(preferred))
+            .or_else(|| pentatonics.first().copied())
+    }
+
+    /// Splits a heptatonic scale into its two tetrachords
+    ///
+    /// The lower tetrachord spans the first four degrees (root to 4th) and the
+    /// upper tetrachord spans the last four degrees (5th to octave), with the
+    /// step from the 4th to the 5th joining the two. Each tetrachord is
+    /// returned as a scale of three intervals measured from its first degree.
+    ///
+    /// # Returns
+    /// The lower and upper tetrachords, or `None` if the scale does not have
+    /// seven tones
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, NATURAL_MINOR, PENTATONIC_MAJOR};
+    /// let (lower, upper) = MAJOR.tetrachords().unwrap();
+    /// assert_eq!(lower.to_step_string(), "W-W-H");
+    /// assert_eq!(upper.to_step_string(), "W-W-H");
+    ///
+    /// let (lower, upper) = NATURAL_MINOR.tetrachords().unwrap();
+    /// assert_eq!(lower.to_step_string(), "W-H-W");
+    /// assert_eq!(upper.to_step_string(), "H-W-W");
+    ///
+    /// assert_eq!(PENTATONIC_MAJOR.tetrachords(), None);
+    /// ```
+    pub fn tetrachords(&self) -> Option<(Scale, Scale)> {
+        if self.degree_count() != 7 {
+            return None;
+        }
+
+        let tones = BitVec16::from_u16(self.chroma())
+            .indeces_on()
+            .chain(std::iter::once(12))
+            .collect::<Vec<_>>();
+        let tetrachord = |tones: &[usize]| {
+            let bits = tones[1..]
+                .iter()
+                .fold(0u16, |bits, tone| bits | 1 << (tone - tones[0] - 1));
+            Self::from_u16(bits)
+        };
+        Some((tetrachord(&tones[0..4]), tetrachord(&tones[4..8])))
+    }
+
+    /// Returns the keys closely related to this scale on a tonic
+    ///
+    /// The candidates are the twelve major keys (`MAJOR`) and the twelve minor
+    /// keys (`NATURAL_MINOR`). A key is closely related when its pitch classes
+    /// differ from those of this scale built on `tonic` by at most one
+    /// accidental, that is when the two pitch-class sets have a Hamming distance
+    /// of at most 2. The relative key has the same pitch classes, so its
+    /// distance is 0. The key itself is not part of the result.
+    ///
+    /// # Arguments
+    /// * `tonic` - The tonic this scale is built on
+    ///
+    /// # Returns
+    /// The tonic and scale of every closely related key, sorted by ascending
+    /// Hamming distance and then by tonic
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{PitchClass, MAJOR, NATURAL_MINOR};
+    /// let related = MAJOR.closely_related_keys(PitchClass::C);
+    /// assert_eq!(related[0], (PitchClass::A, NATURAL_MINOR));
+    /// assert!(related.contains(&(PitchClass::G, MAJOR)));
+    /// assert!(!related.contains(&(PitchClass::C, NATURAL_MINOR)));
+    /// ```
+    pub fn closely_related_keys(&self, tonic: PitchClass) -> Vec<(PitchClass, Scale)> {
+        let pitch_classes = |scale: &Scale, tonic: PitchClass| {
+            Self::rotate_chroma(scale.chroma(), tonic.inner() as u32)
+        };
+        let own = pitch_classes(self, tonic);
+
+        let mut related = PitchClass::ALL
+            .into_iter()
+            .flat_map(|key| [(key, MAJOR), (key, NATURAL_MINOR)])
+            .filter(|(key, scale)| !(*key == tonic && scale.chroma() == self.chroma()))
+            .map(|(key, scale)| ((pitch_classes(&scale, key) ^ own).count_ones(), key, scale))
+            .filter(|(distance, _, _)| *distance <= 2)
+            .collect::<Vec<_>>();
+        related.sort_by_key(|(distance, _, _)| *distance);
+        related
+            .into_iter()
+            .map(|(_, key, scale)| (key, scale))
+            .collect()
+    }
+
+    /// Rotates a 12-bit pitch-class set up by the given number of semitones
+    #[inline]
+    pub(crate) const fn rotate_chroma(set: u16, semitones: u32) -> u16 {
+        let semitones = semitones % 12;
+        ((set << semitones) | (set >> (12 - semitones))) & 0x0FFF
+    }
+
+    /// Creates a Scale from a 12-bit pitch-class set
+    ///
+    /// Pitch class 0 (the root) is stored as the octave, while pitch classes
+    /// 1-11 are stored as the intervals of the same number of semitones.
+    #[inline]
+    pub(crate) const fn from_chroma(set: u16) -> Scale {
+        let octave = if set & 1 != 0 { 1 << 11 } else { 0 };
+        Self::from_u16(((set >> 1) & 0x07FF) | octave)
+    }
+
+    /// Returns the pitch-class set of the scale folded into a single octave
+    ///
+    /// Bit `n` is set when the pitch class `n` semitones above the root is part
+    /// of the scale. The root (bit 0) is always set and intervals above the
+    /// octave are folded back into it.
+    #[inline]
+    pub(crate) const fn chroma(&self) -> u16 {
+        let bits = self.0.bits();
+        (((bits << 1) | (bits >> 11)) & 0x0FFF) | 1
+    }
+}
+
+impl Display for Scale {
+    /// Formats the scale as its step pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use muzze_std::{MAJOR, NATURAL_MINOR};
+    /// assert_eq!(format!("{}", MAJOR), "W-W-H-W-W-W-H");
+    /// assert_eq!(format!("{}", NATURAL_MINOR), "W-H-W-W-H-W-W");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_step_string())
+    }
+}
+
+impl std::fmt::Debug for Scale {
+    /// Formats the scale with both its step pattern and its raw bit pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use muzze_std::MAJOR;
+    /// assert_eq!(
+    ///     format!("{:?}", MAJOR),
+    ///     "Scale { steps: \"W-W-H-W-W-W-H\", bits: 0x0d5a }"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scale")
+            .field("steps", &self.to_step_string())
+            .field("bits", &format_args!("{:#06x}", self.0.bits()))
+            .finish()
+    }
+}
+
+impl PartialOrd for Scale {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Scale {
+    /// Orders scales by number of tones
+    ///
+    /// Scales with fewer tones sort first. Scales with the same number of
+    /// tones are ordered by the numeric value of their bit pattern.
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, NATURAL_MINOR, PENTATONIC_MAJOR};
+    /// assert!(PENTATONIC_MAJOR < NATURAL_MINOR);
+    /// assert!(NATURAL_MINOR < MAJOR);
+    /// ```
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.degree_count()
+            .cmp(&other.degree_count())
+            .then_with(|| self.to_u16().cmp(&other.to_u16()))
+    }
+}
+
+impl Default for Scale {
+    /// Returns the major scale, the conventional default tonality
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Scale, MAJOR};
+    /// assert_eq!(Scale::default(), MAJOR);
+    /// ```
+    fn default() -> Self {
+        MAJOR
+    }
+}
+
+impl From<Scale> for BitVec16 {
+    /// Converts a Scale to its underlying BitVec16 bit pattern
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// use muzze_std::MAJOR;
+    /// let bits = BitVec16::from(MAJOR);
+    /// assert_eq!(bits.inner(), 0b0000_1101_0101_1010);
+    /// ```
+    #[inline]
+    fn from(scale: Scale) -> Self {
+        scale.0
+    }
+}
+
+impl From<BitVec16> for Scale {
+    /// Converts a BitVec16 bit pattern to a Scale
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_bitflags::BitVec16;
+    /// use muzze_std::{Scale, MAJOR};
+    /// let scale = Scale::from(BitVec16::from_u16(0b0000_1101_0101_1010));
+    /// assert_eq!(scale, MAJOR);
+    /// ```
+    #[inline]
+    fn from(bits: BitVec16) -> Self {
+        Self(bits)
+    }
+}
+
+impl FromIterator<u8> for Scale {
+    /// Collects semitone intervals into a Scale
+    ///
+    /// Each value is the number of semitones above the root (1-16), as used
+    /// by `ScaleBuilder::set_interval`. Duplicate values are ignored.
+    ///
+    /// # Panics
+    /// This method will panic if any value is 0 or greater than 16
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{Scale, MAJOR};
+    /// let scale: Scale = vec![2u8, 4, 5, 7, 9, 11, 12].into_iter().collect();
+    /// assert_eq!(scale, MAJOR);
+    /// ```
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        iter.into_iter().collect::<ScaleBuilder>().build()
+    }
+}
+
+/// Major scale: Whole-Whole-Half-Whole-Whole-Whole-Half
+///
+/// The major scale is one of the most fundamental scales in Western music.
+/// It follows the pattern: W-W-H-W-W-W-H (where W = whole step, H = half step).
+/// This creates a bright, happy sound characteristic of major keys.
+///
+/// # Musical Theory
+/// The major scale contains the intervals: 2nd, 3rd, 4th, 5th, 6th, 7th, octave
+/// from the root note, following the step pattern [WHOLE, WHOLE, HALF, WHOLE, WHOLE, WHOLE, HALF].
+///
+/// # Bit Pattern
+/// The bit pattern `0b0000_1101_0101_1010` represents the semitone intervals
+/// that are part of the major scale structure.
+pub const MAJOR: Scale = Scale::from_u16(0b0000_1101_0101_1010);
+
+/// Natural minor scale: Whole-Half-Whole-Whole-Half-Whole-Whole
+///
+/// The natural minor scale is the relative minor of the major scale.
+/// It follows the pattern: W-H-W-W-H-W-W, creating a darker, more somber sound.
+///
+/// # Musical Theory
+/// The natural minor scale contains the intervals: 2nd, 3rd, 4th, 5th, 6th, 7th, octave
+/// from the root note, following the step pattern [WHOLE, HALF, WHOLE, WHOLE, HALF, WHOLE, WHOLE].
+///
+/// # Bit Pattern
+/// The bit pattern `0b0000_1010_1101_0110` represents the semitone intervals
+/// that are part of the natural minor scale structure.
+pub const NATURAL_MINOR: Scale = Scale::from_u16(0b0000_1010_1101_0110);
+
+/// Harmonic minor scale: Whole-Half-Whole-Whole-Half, WholeHalf, Half
+///
+/// The harmonic minor scale is a variation of the natural minor scale
+/// with a raised 7th degree. This creates a stronger leading tone resolution.
+///
+/// # Musical Theory
+/// The harmonic minor scale follows the step pattern [WHOLE, HALF, WHOLE, WHOLE, HALF, WHOLE_HALF, HALF],
+/// where the 6th to 7th degree is an augmented 2nd (3 semitones).
+///
+/// # Bit Pattern
+/// The bit pattern `0b0000_1100_1101_0110` represents the semitone intervals
+/// that are part of the harmonic minor scale structure.
+pub const HARMONIC_MINOR: Scale = Scale::from_u16(0b0000_1100_1101_0110);
+
+/// Melodic minor scale: Whole-Half-Whole-Whole-Whole-Whole-Half
+///
+/// The melodic minor scale has different ascending and descending forms.
+/// The ascending form raises both the 6th and 7th degrees, while the
+/// descending form is identical to the natural minor scale.
+///
+/// # Musical Theory
+/// The ascending melodic minor follows the step pattern [WHOLE, HALF, WHOLE, WHOLE, WHOLE, WHOLE, HALF],
+/// creating a smooth melodic line without the augmented 2nd of the harmonic minor.
+///
+/// # Bit Pattern
+/// The bit pattern `0b0000_1101_0101_0110` represents the semitone intervals
+/// that are part of the melodic minor scale structure.
+pub const MELODIC_MINOR: Scale = Scale::from_u16(0b0000_1101_0101_0110);
+
+/// Pentatonic major scale: Whole-Whole-WholeHalf-Whole
+pub const PENTATONIC_MAJOR: Scale = Scale::from_u16(0b0000_0001_0100_1010);
+
+/// Pentatonic minor scale: WholeHalft-Whole-Whole-WholeHalf
+pub const PENTATONIC_MINOR: Scale = Scale::from_u16(0b0000_0010_0101_0100);
+
+/// Blues major scale: Whole-Half-Half-WholeHalf-Whole
+pub const BLUES_MAJOR: Scale = Scale::from_u16(0b0000_0001_0100_1110);
+
+/// Blues minor scale: WholeHalf-Whole-Half-Half-WholeHalf-Whole
+pub const BLUES_MINOR: Scale = Scale::from_u16(0b0000_1010_0111_0100);
+
+/// Jazz whole tone scale: Whole-Whole-Whole-Whole-Whole
+pub const JAZZ_WHOLE_TONE: Scale = Scale::from_u16(0b0000_0010_1010_1010);
+
+/// Jazz whole half diminished scale: Whole-Half-Whole-Half-Whole-Half-Whole
+pub const JAZZ_WHOLEHALF_DIMINISHED: Scale = Scale::from_u16(0b0000_0101_1011_0110);
+
+/// Chromatic scale: twelve half steps, every semitone of the octave
+pub const CHROMATIC: Scale = Scale::from_u16(0b0000_1111_1111_1111);
+
+/// Bibop major scale: Whole-Whole-Half-Whole-Half-Half-Whole-Half
+pub const BIBOP_MAJOR: Scale = Scale::from_u16(0b0000_1101_1101_1010);
+
+/// Bibop minor scale: Whole-Half-Half-Half-Whole-Whole-Half-Whole
+pub const BIBOP_MINOR: Scale = Scale::from_u16(0b0000_1011_0101_1110);
+
+/// Bibop dominant scale: Whole-Whole-Half-Whole-Whole-Half-Half-Half
+pub const BIBOP_DOMINANT: Scale = Scale::from_u16(0b0000_1111_0101_1010);
+
+/// Ionian mode: Whole-Whole-Half-Whole-Whole-Whole-Half (the major scale)
+pub const IONIAN: Scale = MAJOR;
+
+/// Dorian mode: Whole-Half-Whole-Whole-Whole-Half-Whole
+pub const DORIAN: Scale = Scale::from_u16(0b0000_1011_0101_0110);
+
+/// Phrygian mode: Half-Whole-Whole-Whole-Half-Whole-Whole
+pub const PHRYGIAN: Scale = Scale::from_u16(0b0000_1010_1101_0101);
+
+/// Lydian mode: Whole-Whole-Whole-Half-Whole-Whole-Half
+pub const LYDIAN: Scale = Scale::from_u16(0b0000_1101_0110_1010);
+
+/// Mixolydian mode: Whole-Whole-Half-Whole-Whole-Half-Whole
+pub const MIXOLYDIAN: Scale = Scale::from_u16(0b0000_1011_0101_1010);
+
+/// Aeolian mode: Whole-Half-Whole-Whole-Half-Whole-Whole (the natural minor scale)
+pub const AEOLIAN: Scale = NATURAL_MINOR;
+
+/// Locrian mode: Half-Whole-Whole-Half-Whole-Whole-Whole
+pub const LOCRIAN: Scale = Scale::from_u16(0b0000_1010_1011_0101);
+
+/// Names of the church modes, sorted by bit pattern
+const CHURCH_MODE_NAMES: [(u16, &str); 7] = [
+    (LOCRIAN.to_u16(), "Locrian"),
+    (PHRYGIAN.to_u16(), "Phrygian"),
+    (AEOLIAN.to_u16(), "Aeolian"),
+    (DORIAN.to_u16(), "Dorian"),
+    (MIXOLYDIAN.to_u16(), "Mixolydian"),
+    (IONIAN.to_u16(), "Ionian"),
+    (LYDIAN.to_u16(), "Lydian"),
+];
+
+/// Names of the predefined scales, sorted by bit pattern
+const COMMON_NAMES: [(u16, &str); 19] = [
+    (PENTATONIC_MAJOR.to_u16(), "Pentatonic Major"),
+    (BLUES_MAJOR.to_u16(), "Blues Major"),
+    (PENTATONIC_MINOR.to_u16(), "Pentatonic Minor"),
+    (JAZZ_WHOLE_TONE.to_u16(), "Whole Tone"),
+    (JAZZ_WHOLEHALF_DIMINISHED.to_u16(), "Whole-Half Diminished"),
+    (BLUES_MINOR.to_u16(), "Blues Minor"),
+    (LOCRIAN.to_u16(), "Locrian"),
+    (PHRYGIAN.to_u16(), "Phrygian"),
+    (NATURAL_MINOR.to_u16(), "Natural Minor"),
+    (DORIAN.to_u16(), "Dorian"),
+    (MIXOLYDIAN.to_u16(), "Mixolydian"),
+    (BIBOP_MINOR.to_u16(), "Bebop Minor"),
+    (HARMONIC_MINOR.to_u16(), "Harmonic Minor"),
+    (MELODIC_MINOR.to_u16(), "Melodic Minor"),
+    (MAJOR.to_u16(), "Major"),
+    (LYDIAN.to_u16(), "Lydian"),
+    (BIBOP_MAJOR.to_u16(), "Bebop Major"),
+    (BIBOP_DOMINANT.to_u16(), "Bebop Dominant"),
+    (CHROMATIC.to_u16(), "Chromatic"),
+];
+
+/// Catalog of the predefined scales returned by `Scale::all_predefined`
+const PREDEFINED_SCALES: [(&str, Scale); 21] = [
+    ("Major", MAJOR),
+    ("Natural Minor", NATURAL_MINOR),
+    ("Harmonic Minor", HARMONIC_MINOR),
+    ("Melodic Minor", MELODIC_MINOR),
+    ("Pentatonic Major", PENTATONIC_MAJOR),
+    ("Pentatonic Minor", PENTATONIC_MINOR),
+    ("Blues Major", BLUES_MAJOR),
+    ("Blues Minor", BLUES_MINOR),
+    ("Whole Tone", JAZZ_WHOLE_TONE),
+    ("Whole-Half Diminished", JAZZ_WHOLEHALF_DIMINISHED),
+    ("Bebop Major", BIBOP_MAJOR),
+    ("Bebop Minor", BIBOP_MINOR),
+    ("Bebop Dominant", BIBOP_DOMINANT),
+    ("Chromatic", CHROMATIC),
+    ("Ionian", IONIAN),
+    ("Dorian", DORIAN),
+    ("Phrygian", PHRYGIAN),
+    ("Lydian", LYDIAN),
+    ("Mixolydian", MIXOLYDIAN),
+    ("Aeolian", AEOLIAN),
+    ("Locrian", LOCRIAN),
+];
+
+/// Lowercase names of the predefined scales, sorted by name
+const SCALE_NAMES: [(&str, Scale); 24] = [
+    ("aeolian", AEOLIAN),
+    ("bebop dominant", BIBOP_DOMINANT),
+    ("bebop major", BIBOP_MAJOR),
+    ("bebop minor", BIBOP_MINOR),
+    ("blues major", BLUES_MAJOR),
+    ("blues minor", BLUES_MINOR),
+    ("chromatic", CHROMATIC),
+    ("diminished", JAZZ_WHOLEHALF_DIMINISHED),
+    ("dorian", DORIAN),
+    ("harmonic minor", HARMONIC_MINOR),
+    ("ionian", IONIAN),
+    ("jazz whole tone", JAZZ_WHOLE_TONE),
+    ("locrian", LOCRIAN),
+    ("lydian", LYDIAN),
+    ("major", MAJOR),
+    ("melodic minor", MELODIC_MINOR),
+    ("minor", NATURAL_MINOR),
+    ("mixolydian", MIXOLYDIAN),
+    ("natural minor", NATURAL_MINOR),
+    ("pentatonic major", PENTATONIC_MAJOR),
+    ("pentatonic minor", PENTATONIC_MINOR),
+    ("phrygian", PHRYGIAN),
+    ("whole tone", JAZZ_WHOLE_TONE),
+    ("whole-half diminished", JAZZ_WHOLEHALF_DIMINISHED),
+];
+
+/// Represents a scale whose ascending and descending forms differ
+///
+/// Some scales, such as the classical melodic minor, are played with one set
+/// of intervals on the way up and another one on the way down.
+///
+/// # Examples
+/// ```
+/// use muzze_std::{BiDirectionalScale, MAJOR, MELODIC_MINOR_BI};
+///
+/// let descending: Vec<u8> = MELODIC_MINOR_BI.descending().collect();
+/// assert_eq!(descending, vec![12, 10, 8, 7, 5, 3, 2]);
+///
+/// // A scale played the same way in both directions
+/// let major = BiDirectionalScale::new(MAJOR, MAJOR);
+/// assert_eq!(major.descending_scale(), MAJOR);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BiDirectionalScale {
+    ascending: Scale,
+    descending: Scale,
+}
+
+impl BiDirectionalScale {
+    /// Creates a new BiDirectionalScale from its ascending and descending forms
+    ///
+    /// # Arguments
+    /// * `ascending` - The scale played on the way up
+    /// * `descending` - The scale played on the way down
+    #[inline]
+    pub const fn new(ascending: Scale, descending: Scale) -> Self {
+        Self {
+            ascending,
+            descending,
+        }
+    }
+
+    /// Returns the scale played on the way up
+    #[inline]
+    pub const fn ascending_scale(&self) -> Scale {
+        self.ascending
+    }
+
+    /// Returns the scale played on the way down
+    #[inline]
+    pub const fn descending_scale(&self) -> Scale {
+        self.descending
+    }
+
+    /// Returns the semitone intervals played on the way up, in ascending order
+    #[inline]
+    pub fn ascending(&self) -> impl Iterator<Item = u8> {
+        self.ascending.ascending()
+    }
+
+    /// Returns the semitone intervals played on the way down, in descending order
+    #[inline]
+    pub fn descending(&self) -> impl Iterator<Item = u8> {
+        self.descending.descending()
+    }
+}
+
+/// Classical melodic minor scale: the melodic minor ascending and the natural
+/// minor descending
+pub const MELODIC_MINOR_BI: BiDirectionalScale =
+    BiDirectionalScale::new(MELODIC_MINOR, NATURAL_MINOR);
+
+/// Builder for constructing Scale instances
+///
+/// The ScaleBuilder provides a fluent interface for constructing Scale
+/// instances by setting individual semitone intervals. This is useful when
+/// you need to build a scale programmatically or create custom scales
+/// that aren't predefined as constants.
+///
+/// # Examples
+/// ```
+/// use muzze_std::{ScaleBuilder, Interval, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH, MAJOR_SEVENTH, OCTAVE};
+/// let scale = ScaleBuilder::default()
+///     .set_interval(MAJOR_SECOND)  // Major 2nd
+///     .set_interval(MAJOR_THIRD)  // Major 3rd
+///     .set_interval(PERFECT_FOURTH)  // Perfect 4th
+///     .set_interval(PERFECT_FIFTH)  // Perfect 5th
+///     .set_interval(MAJOR_SIXTH)  // Major 6th
+///     .set_interval(MAJOR_SEVENTH) // Major 7th
+///     .set_interval(OCTAVE) // Octave
+///     .build();
+/// // This creates a major scale
+/// ```
+pub struct ScaleBuilder {
+    /// The underlying BitVec16Builder used for bit manipulation
+    vec_builder: BitVec16Builder,
+}
+
+impl ScaleBuilder {
+    /// Creates a new ScaleBuilder with no intervals initially set
+    ///
+    /// This method initializes the builder with an empty scale (no intervals set).
+    /// The builder can then be used to add specific semitone intervals using
+    /// the `set_interval` method.
+    ///
+    /// # Returns
+    /// A new ScaleBuilder instance ready for configuration
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::ScaleBuilder;
+    /// let builder = ScaleBuilder::default();
+    /// let scale = builder.build();
+    /// assert_eq!(scale.intervals().count(), 0);
+    /// ```
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            vec_builder: BitVec16Builder::new(),
+        }
+    }
+
+    /// Adds a semitone interval to the scale being constructed
+    ///
+    /// This method adds the specified semitone interval to the scale.
+    /// The interval parameter represents the number of semitones from the root note.
+    /// For example, interval 2 represents a major 2nd, interval 12 represents an octave.
+    ///
+    /// # Arguments
+    /// * `interval` - The semitone interval to add (1-16)
+    ///
+    /// # Returns
+    /// A new ScaleBuilder with the specified interval added
+    ///
+    /// # Panics
+    /// This method will panic if the interval is out of bounds (< 1 or > 16)
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{ScaleBuilder, Interval, MAJOR_SECOND, PERFECT_FIFTH, OCTAVE};
+    /// let scale = ScaleBuilder::default()
+    ///     .set_interval(MAJOR_SECOND)  // Add major 2nd
+    ///     .set_interval(PERFECT_FIFTH)  // Add perfect 5th
+    ///     .set_interval(OCTAVE) // Add octave
+    ///     .build();
+    /// let intervals: Vec<u8> = scale.intervals().map(|i| i.inner()).collect();
+    /// assert_eq!(intervals, vec![2, 7, 12]);
+    /// ```
+    #[inline]
+    pub const fn set_interval(self, interval: Interval) -> Self {
+        let vec_builder = self.vec_builder.set_index(interval.inner() - 1);
+        Self { vec_builder }
+    }
+
+    /// Finalizes the builder and returns the constructed Scale
+    ///
+    /// This method consumes the builder and returns the final Scale
+    /// instance with all the intervals that were added during construction.
+    ///
+    /// # Returns
+    /// The constructed Scale instance
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{ScaleBuilder, Interval, MAJOR,MAJOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH, MAJOR_SEVENTH, OCTAVE};
+    /// let scale = ScaleBuilder::default()
+    ///     .set_interval(MAJOR_SECOND)  // Major 2nd
+    ///     .set_interval(MAJOR_THIRD)  // Major 3rd
+    ///     .set_interval(PERFECT_FOURTH)  // Perfect 4th
+    ///     .set_interval(PERFECT_FIFTH)  // Perfect 5th
+    ///     .set_interval(MAJOR_SIXTH)  // Major 6th
+    ///     .set_interval(MAJOR_SEVENTH) // Major 7th
+    ///     .set_interval(OCTAVE) // Octave
+    ///     .build();
+    /// // This creates a major scale with intervals [2, 4, 5, 7, 9, 11, 12]
+    /// assert_eq!(scale, MAJOR);
+    /// ```
+    #[inline]
+    pub const fn build(self) -> Scale {
+        Scale::from_u16(self.vec_builder.build().inner())
+    }
+}
+
+impl Extend<u8> for ScaleBuilder {
+    /// Sets every semitone interval of the iterator in the scale being built
+    ///
+    /// Each value is the number of semitones above the root (1-16), as used
+    /// by `set_interval`.
+    ///
+    /// # Panics
+    /// This method will panic if any value is 0 or greater than 16
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{ScaleBuilder, PENTATONIC_MAJOR};
+    /// let mut builder = ScaleBuilder::default();
+    /// builder.extend([2, 4, 7, 9]);
+    /// assert_eq!(builder.build(), PENTATONIC_MAJOR);
+    /// ```
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        for interval in iter {
+            assert!(
+                (1..=16).contains(&interval),
+                "Scale intervals must be between 1 and 16 semitones"
+            );
+            *self = std::mem::take(self).set_interval(Interval::from(interval));
+        }
+    }
+}
+
+impl FromIterator<u8> for ScaleBuilder {
+    /// Collects semitone intervals into a `ScaleBuilder`
+    ///
+    /// # Panics
+    /// This method will panic if any value is 0 or greater than 16
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{ScaleBuilder, MAJOR};
+    /// let builder: ScaleBuilder = [2u8, 4, 5, 7, 9, 11, 12].into_iter().collect();
+    /// assert_eq!(builder.build(), MAJOR);
+    /// ```
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        let mut builder = ScaleBuilder::default();
+        builder.extend(iter);
+        builder
+    }
+}
+
+impl Default for ScaleBuilder {
+    /// Creates a default ScaleBuilder instance
+    ///
+    /// This implementation provides a convenient way to create a new builder
+    /// using the `Default` trait, which is equivalent to calling `ScaleBuilder::new()`.
+    ///
+    /// # Returns
+    /// A new ScaleBuilder instance with no intervals initially set
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for constructing Scale instances using step intervals
+///
+/// The ScaleStepBuilder provides a fluent interface for constructing Scale
+/// instances by adding consecutive step intervals. This is useful when you
+/// want to build a scale by specifying the step pattern (e.g., whole steps,
+/// half steps) rather than absolute semitone intervals.
+///
+/// # Examples
+/// ```
+/// use muzze_std::{MAJOR, ScaleStepBuilder, WHOLE, HALF};
+/// let scale = ScaleStepBuilder::default()
+///     .add_step(WHOLE)  // Whole step
+///     .add_step(WHOLE)  // Whole step
+///     .add_step(HALF)   // Half step
+///     .add_step(WHOLE)  // Whole step
+///     .add_step(WHOLE)  // Whole step
+///     .add_step(WHOLE)  // Whole step
+///     .add_step(HALF)   // Half step
+///     .build();
+/// // This creates a major scale with step pattern [2, 2, 1, 2, 2, 2, 1]
+/// assert_eq!(scale, MAJOR);
+/// ```
+pub struct ScaleStepBuilder {
+    /// The underlying ScaleBuilder used for bit manipulation
+    scale_builder: ScaleBuilder,
+    /// The last interval that was added to track cumulative position
+    last_interval: Interval,
+    /// The number of steps added so far
+    step_count: usize,
+}
+
+impl ScaleStepBuilder {
+    /// Creates a new ScaleStepBuilder with no intervals initially set
+    ///
+    /// This method initializes the builder with an empty scale (no intervals set)
+    /// and resets the interval tracking. The builder can then be used to add
+    /// step intervals using the `add_step` method.
+    ///
+    /// # Returns
+    /// A new ScaleStepBuilder instance ready for configuration
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            scale_builder: ScaleBuilder::new(),
+            last_interval: UNISON,
+            step_count: 0,
+        }
+    }
+
+    /// Adds a step interval to the scale being constructed
+    ///
+    /// This method adds a step interval to the scale, where the step represents
+    /// the number of semitones to move from the last added interval. This allows
+    /// building scales by specifying step patterns (e.g., whole steps, half steps)
+    /// rather than absolute semitone positions.
+    ///
+    /// # Arguments
+    /// * `step` - The step interval to add from the last interval
+    ///
+    /// # Returns
+    /// A new ScaleStepBuilder with the specified step interval added
+    ///
+    /// # Panics
+    /// This method will panic if the resulting interval exceeds 16 semitones
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{ScaleStepBuilder, WHOLE, HALF};
+    /// let scale = ScaleStepBuilder::default()
+    ///     .add_step(WHOLE)  // Add whole step (major 2nd)
+    ///     .add_step(WHOLE)  // Add whole step (major 3rd)
+    ///     .add_step(HALF)   // Add half step (perfect 4th)
+    ///     .build();
+    /// let intervals: Vec<u8> = scale.intervals().map(|i| i.inner()).collect();
+    /// assert_eq!(intervals, vec![2, 4, 5]);
+    /// ```
+    pub const fn add_step(self, step: Step) -> Self {
+        let last_interval = self.last_interval.add_step(step);
+        Self {
+            scale_builder: self.scale_builder.set_interval(last_interval),
+            last_interval,
+            step_count: self.step_count + 1,
+        }
+    }
+
+    /// Returns the number of steps added so far
+    ///
+    /// # Returns
+    /// The number of `add_step` calls made on the builder
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{ScaleStepBuilder, WHOLE, HALF};
+    /// let builder = ScaleStepBuilder::default().add_step(WHOLE).add_step(HALF);
+    /// assert_eq!(builder.step_count(), 2);
+    /// ```
+    #[inline]
+    pub const fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    /// Returns the cumulative interval reached by the added steps
+    ///
+    /// # Returns
+    /// The number of semitones from the root to the last added interval,
+    /// 0 when no step has been added yet
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{ScaleStepBuilder, WHOLE, HALF};
+    /// let builder = ScaleStepBuilder::default().add_step(WHOLE).add_step(HALF);
+    /// assert_eq!(builder.current_interval(), 3);
+    /// ```
+    #[inline]
+    pub const fn current_interval(&self) -> u8 {
+        self.last_interval.inner()
+    }
+
+    /// Returns the number of semitones left before reaching the octave
+    ///
+    /// # Returns
+    /// `12 - current_interval()`, or 0 once the octave has been reached
+    /// or passed
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{ScaleStepBuilder, WHOLE, HALF};
+    /// let builder = ScaleStepBuilder::default().add_step(WHOLE).add_step(HALF);
+    /// assert_eq!(builder.remaining_to_octave(), 9);
+    /// ```
+    #[inline]
+    pub const fn remaining_to_octave(&self) -> u8 {
+        12u8.saturating_sub(self.current_interval())
+    }
+
+    /// Finalizes the builder and returns the constructed Scale
+    ///
+    /// This method consumes the builder and returns the final Scale
+    /// instance with all the step intervals that were added during construction.
+    ///
+    /// # Returns
+    /// The constructed Scale instance
+    ///
+    /// # Example
+    /// ```
+    /// use muzze_std::{MAJOR, ScaleStepBuilder, WHOLE, HALF};
+    /// let scale = ScaleStepBuilder::default()
+    ///     .add_step(WHOLE)
+    ///     .add_step(WHOLE)
+    ///     .add_step(HALF)
+    ///     .add_step(WHOLE)
+    ///     .add_step(WHOLE)
+    ///     .add_step(WHOLE)
+    ///     .add_step(HALF)
+    ///     .build();
+    /// // This creates a major scale with intervals [2, 4, 5, 7, 9, 11, 12]
+    /// assert_eq!(scale, MAJOR);
+    /// ```
+    #[inline]
+    pub const fn build(self) -> Scale {
+        self.scale_builder.build()
+    }
+}
+
+impl Default for ScaleStepBuilder {
+    /// Creates a default ScaleStepBuilder instance
+    ///
+    /// This implementation provides a convenient way to create a new builder
+    /// using the `Default` trait, which is equivalent to calling `ScaleStepBuilder::new()`.
+    ///
+    /// # Returns
+    /// A new ScaleStepBuilder instance with no intervals initially set
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        HALF, MAJOR_SECOND, MAJOR_SEVENTH, MAJOR_SIXTH, MAJOR_THIRD, OCTAVE, PERFECT_FIFTH,
+        PERFECT_FOURTH, WHOLE,
+    };
+
+    use super::*;
+
+    const C: u8 = 60;
+    // const CS: u8 = 61;
+    const D: u8 = 62;
+    // const DS: u8 = 63;
+    const EF: u8 = 63;
+    const E: u8 = 64;
+    const F: u8 = 65;
+    const FS: u8 = 66;
+    // const FS: u8 = 66;
+    const GF: u8 = 66;
+    const G: u8 = 67;
+    const GS: u8 = 68;
+    const AF: u8 = 68;
+    const A: u8 = 69;
+    const AS: u8 = 70;
+    const BF: u8 = 70;
+    const B: u8 = 71;
+    const C1: u8 = 72;
+
+    /// Tests that the major scale produces the correct intervals and step pattern
+    ///
+    /// This test verifies that the major scale constant correctly represents
+    /// the standard major scale with intervals [2, 4, 5, 7, 9, 11, 12] semitones
+    /// and step pattern [2, 2, 1, 2, 2, 2, 1].
+    #[test]
+    fn test_major_scale() {
+        assert_eq!(
+            MAJOR.intervals().map(|i| i.inner()).collect::<Vec<u8>>(),
+            vec![2, 4, 5, 7, 9, 11, 12]
+        );
+        assert_eq!(
+            MAJOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
+            vec![2, 2, 1, 2, 2, 2, 1]
+        );
+
+        assert_eq!(
+            MAJOR.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, E, F, G, A, B, C1]
+        );
+    }
+
+    /// Tests that the natural minor scale produces the correct intervals and step pattern
+    ///
+    /// This test verifies that the natural minor scale constant correctly represents
+    /// the standard natural minor scale with intervals [2, 3, 5, 7, 8, 10, 12] semitones
+    /// and step pattern [2, 1, 2, 2, 1, 2, 2].
+    #[test]
+    fn test_natural_minor_scale() {
+        assert_eq!(
+            NATURAL_MINOR
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![2, 3, 5, 7, 8, 10, 12]
+        );
+        assert_eq!(
+            NATURAL_MINOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
+            vec![2, 1, 2, 2, 1, 2, 2]
+        );
+
+        assert_eq!(
+            NATURAL_MINOR.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, EF, F, G, AF, BF, C1]
+        );
+    }
+
+    /// Tests that the harmonic minor scale produces the correct intervals and step pattern
+    ///
+    /// This test verifies that the harmonic minor scale constant correctly represents
+    /// the standard harmonic minor scale with intervals [2, 3, 5, 7, 8, 11, 12] semitones
+    /// and step pattern [2, 1, 2, 2, 1, 3, 1], including the characteristic augmented 2nd.
+    #[test]
+    fn test_harmonic_minor_scale() {
+        assert_eq!(
+            HARMONIC_MINOR
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![2, 3, 5, 7, 8, 11, 12]
+        );
+        assert_eq!(
+            HARMONIC_MINOR
+                .steps()
+                .map(|s| s.into())
+                .collect::<Vec<u8>>(),
+            vec![2, 1, 2, 2, 1, 3, 1]
+        );
+
+        assert_eq!(
+            HARMONIC_MINOR.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, EF, F, G, AF, B, C1]
+        );
+    }
+
+    /// Tests that the melodic minor scale produces the correct intervals and step pattern
+    ///
+    /// This test verifies that the melodic minor scale constant correctly represents
+    /// the ascending melodic minor scale with intervals [2, 3, 5, 7, 9, 11, 12] semitones
+    /// and step pattern [2, 1, 2, 2, 2, 2, 1], avoiding the augmented 2nd.
+    #[test]
+    fn test_melodic_minor_scale() {
+        assert_eq!(
+            MELODIC_MINOR
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![2, 3, 5, 7, 9, 11, 12]
+        );
+        assert_eq!(
+            MELODIC_MINOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
+            vec![2, 1, 2, 2, 2, 2, 1]
+        );
+
+        assert_eq!(
+            MELODIC_MINOR.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, EF, F, G, A, B, C1]
+        );
+    }
+
+    #[test]
+    fn test_pentatonic_major_scale() {
+        assert_eq!(
+            PENTATONIC_MAJOR
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![2, 4, 7, 9]
+        );
+        assert_eq!(
+            PENTATONIC_MAJOR
+                .steps()
+                .map(|s| s.into())
+                .collect::<Vec<u8>>(),
+            vec![2, 2, 3, 2]
+        );
+
+        assert_eq!(
+            PENTATONIC_MAJOR.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, E, G, A]
+        );
+    }
+
+    #[test]
+    fn test_pentatonic_minor_scale() {
+        assert_eq!(
+            PENTATONIC_MINOR
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![3, 5, 7, 10]
+        );
+        assert_eq!(
+            PENTATONIC_MINOR
+                .steps()
+                .map(|s| s.into())
+                .collect::<Vec<u8>>(),
+            vec![3, 2, 2, 3]
+        );
+
+        assert_eq!(
+            PENTATONIC_MINOR.apply(C).collect::<Vec<u8>>(),
+            vec![C, EF, F, G, BF]
+        );
+    }
+
+    #[test]
+    fn test_blues_minor_scale() {
+        assert_eq!(
+            BLUES_MINOR
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![3, 5, 6, 7, 10, 12]
+        );
+        assert_eq!(
+            BLUES_MINOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
+            vec![3, 2, 1, 1, 3, 2]
+        );
+
+        assert_eq!(
+            BLUES_MINOR.apply(C).collect::<Vec<u8>>(),
+            vec![C, EF, F, GF, G, BF, C1]
+        );
+    }
+
+    #[test]
+    fn test_blues_major_scale() {
+        assert_eq!(
+            BLUES_MAJOR
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![2, 3, 4, 7, 9]
+        );
+        assert_eq!(
+            BLUES_MAJOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
+            vec![2, 1, 1, 3, 2]
+        );
+        assert_eq!(
+            BLUES_MAJOR.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, EF, E, G, A]
+        );
+    }
+
+    #[test]
+    fn test_jazz_whole_tone_scale() {
+        assert_eq!(
+            JAZZ_WHOLE_TONE
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![2, 4, 6, 8, 10]
+        );
+        assert_eq!(
+            JAZZ_WHOLE_TONE
+                .steps()
+                .map(|s| s.into())
+                .collect::<Vec<u8>>(),
+            vec![2, 2, 2, 2, 2]
+        );
+        assert_eq!(
+            JAZZ_WHOLE_TONE.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, E, FS, GS, AS]
+        );
+    }
+
+    #[test]
+    fn test_jazz_wholehalf_diminished_scale() {
+        assert_eq!(
+            JAZZ_WHOLEHALF_DIMINISHED
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![2, 3, 5, 6, 8, 9, 11]
+        );
+        assert_eq!(
+            JAZZ_WHOLEHALF_DIMINISHED
+                .steps()
+                .map(|s| s.into())
+                .collect::<Vec<u8>>(),
+            vec![2, 1, 2, 1, 2, 1, 2]
+        );
+        assert_eq!(
+            JAZZ_WHOLEHALF_DIMINISHED.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, EF, F, GF, AF, A, B]
+        );
+    }
+
+    #[test]
+    fn test_bibop_major_scale() {
+        assert_eq!(
+            BIBOP_MAJOR
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![2, 4, 5, 7, 8, 9, 11, 12]
+        );
+        assert_eq!(
+            BIBOP_MAJOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
+            vec![2, 2, 1, 2, 1, 1, 2, 1]
+        );
+        assert_eq!(
+            BIBOP_MAJOR.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, E, F, G, AF, A, B, C1]
+        );
+    }
+
+    #[test]
+    fn test_bibop_minor_scale() {
+        assert_eq!(
+            BIBOP_MINOR
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![2, 3, 4, 5, 7, 9, 10, 12]
+        );
+        assert_eq!(
+            BIBOP_MINOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
+            vec![2, 1, 1, 1, 2, 2, 1, 2]
+        );
+        assert_eq!(
+            BIBOP_MINOR.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, EF, E, F, G, A, BF, C1]
+        );
+    }
+
+    #[test]
+    fn test_bibop_dominant_scale() {
+        assert_eq!(
+            BIBOP_DOMINANT
+                .intervals()
+                .map(|i| i.inner())
+                .collect::<Vec<u8>>(),
+            vec![2, 4, 5, 7, 9, 10, 11, 12]
+        );
+        assert_eq!(
+            BIBOP_DOMINANT
+                .steps()
+                .map(|s| s.into())
+                .collect::<Vec<u8>>(),
+            vec![2, 2, 1, 2, 2, 1, 1, 1]
+        );
+        assert_eq!(
+            BIBOP_DOMINANT.apply(C).collect::<Vec<u8>>(),
+            vec![C, D, E, F, G, A, BF, B, C1]
+        );
+    }
+
+    /// Tests that the ScaleBuilder correctly constructs Scale instances
+    ///
+    /// This test verifies that the builder pattern works correctly by setting
+    /// multiple semitone intervals and ensuring the final result matches the
+    /// expected major scale intervals.
+    #[test]
+    fn test_scale_builder() {
+        let scale = ScaleBuilder::default()
+            .set_interval(MAJOR_SECOND)
+            .set_interval(MAJOR_THIRD)
+            .set_interval(PERFECT_FOURTH)
+            .set_interval(PERFECT_FIFTH)
+            .set_interval(MAJOR_SIXTH)
+            .set_interval(MAJOR_SEVENTH)
+            .set_interval(OCTAVE)
+            .build();
+        assert_eq!(
+            scale.intervals().map(|i| i.inner()).collect::<Vec<u8>>(),
+            vec![2, 4, 5, 7, 9, 11, 12]
+        );
+        assert_eq!(scale, MAJOR);
+    }
+
+    #[test]
+    fn test_scale_step_builder_progress() {
+        let builder = ScaleStepBuilder::default();
+        assert_eq!(builder.step_count(), 0);
+        assert_eq!(builder.current_interval(), 0);
+        assert_eq!(builder.remaining_to_octave(), 12);
+
+        let builder = builder.add_step(WHOLE).add_step(WHOLE).add_step(HALF);
+        assert_eq!(builder.step_count(), 3);
+        assert_eq!(builder.current_interval(), 5);
+        assert_eq!(builder.remaining_to_octave(), 7);
+
+        let builder = builder
+            .add_step(WHOLE)
+            .add_step(WHOLE)
+            .add_step(WHOLE)
+            .add_step(HALF);
+        assert_eq!(builder.step_count(), 7);
+        assert_eq!(builder.remaining_to_octave(), 0);
+        assert_eq!(builder.add_step(WHOLE).remaining_to_octave(), 0);
+    }
+
+    #[test]
+    fn test_scale_step_builder() {
+        let scale = ScaleStepBuilder::default()
+            .add_step(WHOLE)
+            .add_step(WHOLE)
+            .add_step(HALF)
+            .add_step(WHOLE)
+            .add_step(WHOLE)
+            .add_step(WHOLE)
+            .add_step(HALF)
+            .build();
+
+        assert_eq!(
+            scale.intervals().map(|i| i.inner()).collect::<Vec<u8>>(),
+            vec![2, 4, 5, 7, 9, 11, 12]
+        );
+        assert_eq!(scale, MAJOR);
+    }
+
+    #[test]
+    fn test_get_interval_at() {
+        let tones = (0..7)
+            .map(|i| MAJOR.get_interval_at(i).unwrap().inner())
+            .collect::<Vec<u8>>();
+        assert_eq!(tones, vec![0, 2, 4, 5, 7, 9, 11]);
+        assert_eq!(MAJOR.get_interval_at(7), None);
+
+        // Scales without the octave bit still start from the implicit root
+        let tones = (0..5)
+            .map(|i| PENTATONIC_MINOR.get_interval_at(i).unwrap().inner())
+            .collect::<Vec<u8>>();
+        assert_eq!(tones, vec![0, 3, 5, 7, 10]);
+        assert_eq!(PENTATONIC_MINOR.get_interval_at(5), None);
+    }
+
+    #[test]
+    fn test_chroma() {
+        assert_eq!(MAJOR.chroma(), 0b1010_1011_0101);
+        assert_eq!(PENTATONIC_MAJOR.chroma(), 0b0010_1001_0101);
+        assert_eq!(Scale::from_u16(0).chroma(), 1);
+        // Intervals above the octave fold back onto the lower pitch classes
+        assert_eq!(Scale::from_u16(0b1000_0000_0000_0000).chroma(), 0b1_0001);
+    }
+
+    #[test]
+    fn test_degree_count() {
+        assert_eq!(MAJOR.degree_count(), 7);
+        assert_eq!(HARMONIC_MINOR.degree_count(), 7);
+        assert_eq!(PENTATONIC_MAJOR.degree_count(), 5);
+        assert_eq!(BLUES_MINOR.degree_count(), 6);
+        assert_eq!(JAZZ_WHOLE_TONE.degree_count(), 6);
+        assert_eq!(JAZZ_WHOLEHALF_DIMINISHED.degree_count(), 8);
+        assert_eq!(BIBOP_DOMINANT.degree_count(), 8);
+        assert_eq!(Scale::from_u16(0).degree_count(), 1);
+    }
+
+    #[test]
+    fn test_scale_subset_superset() {
+        assert!(PENTATONIC_MAJOR.is_subset_of(MAJOR));
+        assert!(MAJOR.is_superset_of(PENTATONIC_MAJOR));
+        assert!(PENTATONIC_MINOR.is_subset_of(NATURAL_MINOR));
+        assert!(MAJOR.is_subset_of(BIBOP_MAJOR));
+        assert!(MAJOR.is_subset_of(MAJOR));
+        assert!(!MAJOR.is_subset_of(NATURAL_MINOR));
+        assert!(!NATURAL_MINOR.is_superset_of(MAJOR));
+    }
+
+    #[test]
+    fn test_scale_intersection() {
+        // The common tones of C major and C minor are C, D, F and G
+        let common = MAJOR.intersection(NATURAL_MINOR);
+        assert_eq!(common.degree_count(), 4);
+        assert!(common.is_subset_of(MAJOR));
+        assert!(common.is_subset_of(NATURAL_MINOR));
+
+        assert_eq!(MAJOR.intersection(PENTATONIC_MAJOR), PENTATONIC_MAJOR);
+    }
+
+    #[test]
+    fn test_scale_union() {
+        let all = MAJOR.union(NATURAL_MINOR);
+        assert!(all.degree_count() > MAJOR.degree_count());
+        assert!(all.degree_count() > NATURAL_MINOR.degree_count());
+        assert!(all.is_superset_of(MAJOR));
+        assert!(all.is_superset_of(NATURAL_MINOR));
+
+        assert_eq!(MAJOR.union(PENTATONIC_MAJOR), MAJOR);
+    }
+
+    #[test]
+    fn test_scale_bitvec16_conversions() {
+        let bits: BitVec16 = MAJOR.into();
+        assert_eq!(bits.inner(), 0b0000_1101_0101_1010);
+
+        let scale: Scale = bits.into();
+        assert_eq!(scale, MAJOR);
+    }
+
+    #[test]
+    fn test_scale_from_iterator() {
+        let scale = vec![2u8, 4, 5, 7, 9, 11, 12].into_iter().collect::<Scale>();
+        assert_eq!(scale, MAJOR);
+
+        let scale = [3u8, 5, 7, 10, 3].into_iter().collect::<Scale>();
+        assert_eq!(scale, PENTATONIC_MINOR);
+
+        let scale = std::iter::empty::<u8>().collect::<Scale>();
+        assert_eq!(scale, Scale::from_u16(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Scale intervals must be between 1 and 16 semitones")]
+    fn test_scale_from_iterator_zero() {
+        let _ = [0u8, 2, 4].into_iter().collect::<Scale>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Scale intervals must be between 1 and 16 semitones")]
+    fn test_scale_from_iterator_too_large() {
+        let _ = [2u8, 17].into_iter().collect::<Scale>();
+    }
+
+    #[test]
+    fn test_from_steps_slice() {
+        assert_eq!(Scale::from_steps_slice(&[2, 2, 1, 2, 2, 2, 1]), Ok(MAJOR));
+        assert_eq!(
+            Scale::from_steps_slice(&[2, 1, 2, 2, 1, 2, 2]),
+            Ok(NATURAL_MINOR)
+        );
+        assert_eq!(Scale::from_steps_slice(&[2, 2, 3, 2]), Ok(PENTATONIC_MAJOR));
+    }
+
+    #[test]
+    fn test_from_steps_slice_errors() {
+        assert_eq!(Scale::from_steps_slice(&[]), Err(ScaleError::EmptySteps));
+        assert_eq!(
+            Scale::from_steps_slice(&[2, 2, 1, 2, 2, 2, 2]),
+            Err(ScaleError::StepOverflow { total: 13 })
+        );
+        assert!(matches!(
+            Scale::from_steps_slice(&[2, 2, 0, 2]),
+            Err(ScaleError::ZeroStep { index: 2 })
+        ));
+        assert_eq!(
+            Scale::from_steps_slice(&[200, 200]),
+            Err(ScaleError::StepOverflow { total: 255 })
+        );
+    }
+
+    #[test]
+    fn test_scale_error_display() {
+        assert_eq!(
+            ScaleError::EmptySteps.to_string(),
+            "a scale needs at least one step"
+        );
+        assert_eq!(
+            ScaleError::StepOverflow { total: 13 }.to_string(),
+            "the steps add up to 13 semitones, more than an octave"
+        );
+        assert_eq!(
+            ScaleError::ZeroStep { index: 3 }.to_string(),
+            "the step at index 3 is zero"
+        );
+    }
+
+    #[test]
+    fn test_prime_form() {
+        // Major and natural minor belong to set class 7-35
+        assert_eq!(MAJOR.prime_form(), NATURAL_MINOR.prime_form());
+        assert_eq!(MAJOR.prime_form().chroma(), 0b0101_0110_1011);
+
+        // Major and minor pentatonic belong to set class 5-35
+        assert_eq!(PENTATONIC_MAJOR.prime_form(), PENTATONIC_MINOR.prime_form());
+        assert_eq!(PENTATONIC_MAJOR.prime_form().chroma(), 0b0010_1001_0101);
+
+        // Harmonic minor is not a mode of the major scale
+        assert_ne!(HARMONIC_MINOR.prime_form(), MAJOR.prime_form());
+
+        // The prime form is its own prime form
+        assert_eq!(MAJOR.prime_form().prime_form(), MAJOR.prime_form());
+    }
+
+    #[test]
+    fn test_prime_form_symmetric_scales() {
+        assert_eq!(JAZZ_WHOLE_TONE.prime_form().chroma(), 0b0101_0101_0101);
+        assert_eq!(
+            JAZZ_WHOLEHALF_DIMINISHED.prime_form().chroma(),
+            0b0110_1101_1011
+        );
+        assert_eq!(Scale::from_u16(0).prime_form().chroma(), 1);
+    }
+
+    #[test]
+    fn test_chroma_round_trip() {
+        assert_eq!(Scale::from_chroma(MAJOR.chroma()), MAJOR);
+        assert_eq!(Scale::rotate_chroma(0b0000_1000_0001, 5), 0b0000_0010_0001);
+        assert_eq!(Scale::rotate_chroma(0b1000_0000_0000, 1), 1);
+    }
+
+    #[test]
+    fn test_scale_display() {
+        assert_eq!(format!("{}", MAJOR), "W-W-H-W-W-W-H");
+        assert_eq!(MAJOR.to_step_string(), MAJOR.to_string());
+        assert_eq!(format!("{}", HARMONIC_MINOR), "W-H-W-W-H-WH-H");
+        assert_eq!(format!("{}", Scale::from_u16(0)), "");
+    }
+
+    #[test]
+    fn test_scale_debug() {
+        let debug = format!("{:?}", MAJOR);
+        assert!(debug.contains("W-W-H-W-W-W-H"));
+        assert!(debug.contains("0x0d5a"));
+    }
+
+    #[test]
+    fn test_scale_symmetric_difference() {
+        let differ = MAJOR.symmetric_difference(MELODIC_MINOR);
+        assert_eq!(BitVec16::from(differ).count_ones(), 2);
+        assert_eq!(
+            differ.intervals().map(|i| i.inner()).collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+        assert_eq!(MAJOR.xor_with(MELODIC_MINOR), differ);
+        assert_eq!(MAJOR.symmetric_difference(MAJOR), Scale::from_u16(0));
+    }
+
+    #[test]
+    fn test_scale_subtract() {
+        let avoid = MAJOR.subtract(PENTATONIC_MAJOR);
+        assert_eq!(
+            avoid.intervals().map(|i| i.inner()).collect::<Vec<_>>(),
+            vec![5, 11, 12]
+        );
+        assert!(avoid.intersection(PENTATONIC_MAJOR) == Scale::from_u16(0));
+        assert_eq!(PENTATONIC_MAJOR.subtract(MAJOR), Scale::from_u16(0));
+        assert_eq!(avoid.union(PENTATONIC_MAJOR), MAJOR);
+    }
+
+    #[test]
+    fn test_scale_degree_names() {
+        use PitchClass::*;
+
+        let names = MAJOR.degree_names(C).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                (1, C),
+                (2, D),
+                (3, E),
+                (4, F),
+                (5, G),
+                (6, A),
+                (7, B),
+                (8, C)
+            ]
+        );
+
+        let names = HARMONIC_MINOR
+            .degree_names(E)
+            .map(|(_, pc)| pc)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![E, Fs, G, A, B, C, Ds, E]);
+
+        let names = Scale::from_u16(0).degree_names(G).collect::<Vec<_>>();
+        assert_eq!(names, vec![(1, G)]);
+    }
+
+    #[test]
+    fn test_scale_u16_round_trip() {
+        let scales = [
+            MAJOR,
+            NATURAL_MINOR,
+            HARMONIC_MINOR,
+            MELODIC_MINOR,
+            PENTATONIC_MAJOR,
+            PENTATONIC_MINOR,
+            BLUES_MAJOR,
+            BLUES_MINOR,
+            JAZZ_WHOLE_TONE,
+            JAZZ_WHOLEHALF_DIMINISHED,
+            BIBOP_MAJOR,
+            BIBOP_MINOR,
+            BIBOP_DOMINANT,
+            Scale::from_u16(0),
+        ];
+        for scale in scales {
+            assert_eq!(Scale::from_u16(scale.to_u16()), scale);
+        }
+    }
+
+    #[test]
+    fn test_scale_builder_extend() {
+        let mut builder = ScaleBuilder::default();
+        builder.extend([2, 4, 5]);
+        builder.extend(vec![7, 9, 11, 12]);
+        assert_eq!(builder.build(), MAJOR);
+
+        let builder: ScaleBuilder = [3u8, 5, 7, 10, 12].into_iter().collect();
+        assert_eq!(builder.build(), Scale::from_u16(0b0000_1010_0101_0100));
+    }
+
+    #[test]
+    #[should_panic(expected = "Scale intervals must be between 1 and 16 semitones")]
+    fn test_scale_builder_extend_invalid() {
+        let mut builder = ScaleBuilder::default();
+        builder.extend([0]);
+    }
+
+    #[test]
+    fn test_get_chord_on_degree_triads() {
+        use crate::{AUGMENTED_TRIAD, DIMINISHED_TRIAD, MAJOR_TRIAD, MINOR_TRIAD};
+
+        let triads = (0..7)
+            .map(|degree| MAJOR.get_chord_on_degree(degree, ChordType::Triad))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            triads,
+            vec![
+                Some(MAJOR_TRIAD),
+                Some(MINOR_TRIAD),
+                Some(MINOR_TRIAD),
+                Some(MAJOR_TRIAD),
+                Some(MAJOR_TRIAD),
+                Some(MINOR_TRIAD),
+                Some(DIMINISHED_TRIAD),
+            ]
+        );
+
+        assert_eq!(
+            HARMONIC_MINOR.get_chord_on_degree(2, ChordType::Triad),
+            Some(AUGMENTED_TRIAD)
+        );
+        assert_eq!(MAJOR.get_chord_on_degree(7, ChordType::Triad), None);
+    }
+
+    #[test]
+    fn test_get_chord_on_degree_extended() {
+        use crate::{
+            DIMINISHED_SEVENTH, DOMINANT_NINTH, FIFTH_CHORD, HALF_DIMINISHED_SEVENTH, MAJOR_NINTH,
+            MAJOR_SEVENTH_CHORD,
+        };
+
+        assert_eq!(
+            MAJOR.get_chord_on_degree(0, ChordType::Seventh),
+            Some(MAJOR_SEVENTH_CHORD)
+        );
+        assert_eq!(
+            MAJOR.get_chord_on_degree(6, ChordType::Seventh),
+            Some(HALF_DIMINISHED_SEVENTH)
+        );
+        assert_eq!(
+            HARMONIC_MINOR.get_chord_on_degree(6, ChordType::Seventh),
+            Some(DIMINISHED_SEVENTH)
+        );
+        assert_eq!(
+            MAJOR.get_chord_on_degree(0, ChordType::Ninth),
+            Some(MAJOR_NINTH)
+        );
+        assert_eq!(
+            MAJOR.get_chord_on_degree(4, ChordType::Ninth),
+            Some(DOMINANT_NINTH)
+        );
+        assert_eq!(
+            MAJOR.get_chord_on_degree(3, ChordType::OpenFifth),
+            Some(FIFTH_CHORD)
+        );
+    }
+
+    #[test]
+    fn test_get_chord_on_degree_small_scales() {
+        // Five tones are too few to stack a seventh chord
+        assert_eq!(
+            PENTATONIC_MAJOR.get_chord_on_degree(0, ChordType::Seventh),
+            None
+        );
+        // The whole tone scale stacks augmented triads
+        assert_eq!(
+            JAZZ_WHOLE_TONE.get_chord_on_degree(1, ChordType::Triad),
+            Some(crate::AUGMENTED_TRIAD)
+        );
     }
-}
 
-impl Default for ScaleStepBuilder {
-    /// Creates a default ScaleStepBuilder instance
-    ///
-    /// This implementation provides a convenient way to create a new builder
-    /// using the `Default` trait, which is equivalent to calling `ScaleStepBuilder::new()`.
-    ///
-    /// # Returns
-    /// A new ScaleStepBuilder instance with no intervals initially set
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    fn test_scale_ascending_descending() {
+        assert_eq!(
+            MAJOR.ascending().collect::<Vec<_>>(),
+            vec![2, 4, 5, 7, 9, 11, 12]
+        );
+        assert_eq!(
+            MAJOR.descending().collect::<Vec<_>>(),
+            vec![12, 11, 9, 7, 5, 4, 2]
+        );
+        assert_eq!(Scale::from_u16(0).descending().count(), 0);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        HALF, MAJOR_SECOND, MAJOR_SEVENTH, MAJOR_SIXTH, MAJOR_THIRD, OCTAVE, PERFECT_FIFTH,
-        PERFECT_FOURTH, WHOLE,
-    };
+    #[test]
+    fn test_melodic_minor_bidirectional() {
+        let mut expected = NATURAL_MINOR.ascending().collect::<Vec<_>>();
+        expected.reverse();
+        assert_eq!(MELODIC_MINOR_BI.descending().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            MELODIC_MINOR_BI.ascending().collect::<Vec<_>>(),
+            MELODIC_MINOR.ascending().collect::<Vec<_>>()
+        );
+        assert_eq!(MELODIC_MINOR_BI.ascending_scale(), MELODIC_MINOR);
+        assert_eq!(MELODIC_MINOR_BI.descending_scale(), NATURAL_MINOR);
+    }
 
-    use super::*;
+    #[test]
+    fn test_major_half_steps_sliding_window() {
+        // Two adjacent intervals a semitone apart: E-F and B-C
+        let half_steps = BitVec16::from(MAJOR)
+            .sliding_window(2)
+            .filter(|window| *window == 0b11)
+            .count();
+        assert_eq!(half_steps, 2);
+    }
 
-    const C: u8 = 60;
-    // const CS: u8 = 61;
-    const D: u8 = 62;
-    // const DS: u8 = 63;
-    const EF: u8 = 63;
-    const E: u8 = 64;
-    const F: u8 = 65;
-    const FS: u8 = 66;
-    // const FS: u8 = 66;
-    const GF: u8 = 66;
-    const G: u8 = 67;
-    const GS: u8 = 68;
-    const AF: u8 = 68;
-    const A: u8 = 69;
-    const AS: u8 = 70;
-    const BF: u8 = 70;
-    const B: u8 = 71;
-    const C1: u8 = 72;
+    #[test]
+    fn test_find_chord() {
+        use crate::{
+            AUGMENTED_TRIAD, DIMINISHED_TRIAD, HALF_DIMINISHED_SEVENTH, MAJOR_TRIAD, MINOR_TRIAD,
+        };
+
+        assert_eq!(MAJOR.find_chord(&MAJOR_TRIAD), Some(0));
+        assert_eq!(MAJOR.find_chord(&MINOR_TRIAD), Some(1));
+        assert_eq!(MAJOR.find_chord(&DIMINISHED_TRIAD), Some(6));
+        assert_eq!(MAJOR.find_chord(&HALF_DIMINISHED_SEVENTH), Some(6));
+        assert_eq!(MAJOR.find_chord(&AUGMENTED_TRIAD), None);
+        assert_eq!(HARMONIC_MINOR.find_chord(&AUGMENTED_TRIAD), Some(2));
+    }
 
-    /// Tests that the major scale produces the correct intervals and step pattern
-    ///
-    /// This test verifies that the major scale constant correctly represents
-    /// the standard major scale with intervals [2, 4, 5, 7, 9, 11, 12] semitones
-    /// and step pattern [2, 2, 1, 2, 2, 2, 1].
     #[test]
-    fn test_major_scale() {
+    fn test_find_chord_inverts_get_chord_on_degree() {
+        for degree in 0..7 {
+            for chord_type in [ChordType::Triad, ChordType::Seventh] {
+                let chord = MAJOR.get_chord_on_degree(degree, chord_type).unwrap();
+                // The first matching degree never comes after the one it was built on
+                let found = MAJOR.find_chord(&chord).unwrap();
+                assert!(found <= degree);
+                assert_eq!(MAJOR.get_chord_on_degree(found, chord_type), Some(chord));
+            }
+        }
+    }
+
+    #[test]
+    fn test_as_interval_array() {
+        const MAJOR_INTERVALS: [u8; 12] = MAJOR.as_interval_array();
+        assert_eq!(MAJOR_INTERVALS, [2, 4, 5, 7, 9, 11, 12, 0, 0, 0, 0, 0]);
+
+        for scale in [MAJOR, BLUES_MINOR, BIBOP_DOMINANT, JAZZ_WHOLE_TONE] {
+            let array = scale.as_interval_array();
+            let intervals = scale.ascending().collect::<Vec<_>>();
+            assert_eq!(&array[..intervals.len()], intervals.as_slice());
+            assert!(array[intervals.len()..].iter().all(|i| *i == 0));
+        }
+
+        assert_eq!(Scale::from_u16(0).as_interval_array(), [0; 12]);
         assert_eq!(
-            MAJOR.intervals().map(|i| i.inner()).collect::<Vec<u8>>(),
-            vec![2, 4, 5, 7, 9, 11, 12]
+            Scale::from_u16(u16::MAX).as_interval_array(),
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]
         );
+    }
+
+    #[test]
+    fn test_mode_constants() {
+        assert_eq!(DORIAN.to_step_string(), "W-H-W-W-W-H-W");
+        assert_eq!(PHRYGIAN.to_step_string(), "H-W-W-W-H-W-W");
+        assert_eq!(LYDIAN.to_step_string(), "W-W-W-H-W-W-H");
+        assert_eq!(MIXOLYDIAN.to_step_string(), "W-W-H-W-W-H-W");
+        assert_eq!(LOCRIAN.to_step_string(), "H-W-W-H-W-W-W");
+        assert_eq!(IONIAN, MAJOR);
+        assert_eq!(AEOLIAN, NATURAL_MINOR);
+    }
+
+    #[test]
+    fn test_brightness_relative_to() {
+        assert_eq!(LYDIAN.brightness_relative_to(MAJOR), 1);
+        assert_eq!(MIXOLYDIAN.brightness_relative_to(MAJOR), -1);
+        assert_eq!(MAJOR.brightness_relative_to(MAJOR), 0);
+        assert_eq!(LYDIAN.brightness_relative_to(LOCRIAN), 6);
+        assert_eq!(LOCRIAN.brightness_relative_to(LYDIAN), -6);
+
+        // Scales with a different number of tones cannot be compared
+        assert_eq!(MAJOR.brightness_relative_to(PENTATONIC_MAJOR), 0);
+        assert_eq!(BIBOP_MAJOR.brightness_relative_to(MAJOR), 0);
+    }
+
+    #[test]
+    fn test_brightness_ranking_of_diatonic_modes() {
+        let ranking = [
+            LYDIAN, IONIAN, MIXOLYDIAN, DORIAN, AEOLIAN, PHRYGIAN, LOCRIAN,
+        ];
+        for (i, brighter) in ranking.iter().enumerate() {
+            for darker in &ranking[i + 1..] {
+                assert!(brighter.brightness_relative_to(*darker) > 0);
+                assert!(darker.brightness_relative_to(*brighter) < 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_modes() {
+        let modes: Vec<_> = MAJOR.modes().collect();
         assert_eq!(
-            MAJOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
-            vec![2, 2, 1, 2, 2, 2, 1]
+            modes,
+            vec![IONIAN, DORIAN, PHRYGIAN, LYDIAN, MIXOLYDIAN, AEOLIAN, LOCRIAN]
         );
 
+        // Scales without the octave bit are normalised to include it
+        let first = PENTATONIC_MAJOR.modes().next().unwrap();
+        assert_eq!(first.chroma(), PENTATONIC_MAJOR.chroma());
+        assert_eq!(PENTATONIC_MAJOR.modes().count(), 5);
+        assert_eq!(JAZZ_WHOLE_TONE.modes().count(), 6);
+    }
+
+    #[test]
+    fn test_modal_family() {
+        assert_eq!(MAJOR.modal_family().len(), 7);
+        assert_eq!(PENTATONIC_MAJOR.modal_family().len(), 5);
+        assert_eq!(HARMONIC_MINOR.modal_family().len(), 7);
+
+        // Every mode of the whole tone scale is the whole tone scale again
+        let whole_tone = JAZZ_WHOLE_TONE.modal_family();
+        assert_eq!(whole_tone.len(), 1);
+        assert_eq!(whole_tone[0].chroma(), JAZZ_WHOLE_TONE.chroma());
+
+        // The diminished scale alternates between whole-half and half-whole
+        let diminished = JAZZ_WHOLEHALF_DIMINISHED.modal_family();
+        assert_eq!(diminished.len(), 2);
+        assert_eq!(diminished[1].to_step_string(), "H-W-H-W-H-W-H-W");
+
+        // All the diatonic modes share a modal family
+        let family = DORIAN.modal_family();
+        for mode in [IONIAN, PHRYGIAN, LYDIAN, MIXOLYDIAN, AEOLIAN, LOCRIAN] {
+            assert!(family.contains(&mode));
+        }
+    }
+
+    #[test]
+    fn test_modal_family_without_octave() {
+        // The pentatonic scale is stored without its octave, so the first
+        // member of its family only matches it once the octave is added
+        let family = PENTATONIC_MAJOR.modal_family();
+        assert_ne!(family[0], PENTATONIC_MAJOR);
+        assert_eq!(family[0].chroma(), PENTATONIC_MAJOR.chroma());
         assert_eq!(
-            MAJOR.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, E, F, G, A, B, C1]
+            family[0],
+            Scale::from_u16(PENTATONIC_MAJOR.to_u16() | 1 << 11)
         );
+
+        // A scale stored with its octave starts its own family
+        assert_eq!(MAJOR.modal_family()[0], MAJOR);
     }
 
-    /// Tests that the natural minor scale produces the correct intervals and step pattern
-    ///
-    /// This test verifies that the natural minor scale constant correctly represents
-    /// the standard natural minor scale with intervals [2, 3, 5, 7, 8, 10, 12] semitones
-    /// and step pattern [2, 1, 2, 2, 1, 2, 2].
     #[test]
-    fn test_natural_minor_scale() {
+    fn test_apply_chord_on_tonic() {
+        use crate::{MAJOR_SEVENTH_CHORD, MAJOR_TRIAD};
+
+        let expected = MAJOR_TRIAD
+            .degrees()
+            .map(|degree| Interval::from_degree(&degree).unwrap().inner())
+            .collect::<Vec<_>>();
+        assert_eq!(MAJOR.apply_chord(&MAJOR_TRIAD, 0), Some(expected));
         assert_eq!(
-            NATURAL_MINOR
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![2, 3, 5, 7, 8, 10, 12]
+            MAJOR.apply_chord(&MAJOR_SEVENTH_CHORD, 0),
+            Some(vec![0, 4, 7, 11])
         );
         assert_eq!(
-            NATURAL_MINOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
-            vec![2, 1, 2, 2, 1, 2, 2]
+            NATURAL_MINOR.apply_chord(&MAJOR_TRIAD, 0),
+            Some(vec![0, 3, 7])
         );
+    }
 
+    #[test]
+    fn test_apply_chord_uses_scale_tones() {
+        use crate::{MAJOR_NINTH, MAJOR_SEVENTH_CHORD, MAJOR_TRIAD};
+
+        // The leading tone triad takes the scale's diminished fifth
+        let voices = MAJOR.apply_chord(&MAJOR_TRIAD, 6).unwrap();
+        assert_eq!(voices, vec![11, 14, 17]);
+        assert_eq!(voices[2] - voices[0], 6);
+
+        // The dominant seventh falls out of the major scale on the fifth degree
         assert_eq!(
-            NATURAL_MINOR.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, EF, F, G, AF, BF, C1]
+            MAJOR.apply_chord(&MAJOR_SEVENTH_CHORD, 4),
+            Some(vec![7, 11, 14, 17])
+        );
+
+        // Tensions continue into the next octave
+        assert_eq!(
+            MAJOR.apply_chord(&MAJOR_NINTH, 0),
+            Some(vec![0, 4, 7, 11, 14])
         );
     }
 
-    /// Tests that the harmonic minor scale produces the correct intervals and step pattern
-    ///
-    /// This test verifies that the harmonic minor scale constant correctly represents
-    /// the standard harmonic minor scale with intervals [2, 3, 5, 7, 8, 11, 12] semitones
-    /// and step pattern [2, 1, 2, 2, 1, 3, 1], including the characteristic augmented 2nd.
     #[test]
-    fn test_harmonic_minor_scale() {
+    fn test_apply_chord_out_of_range() {
+        use crate::MAJOR_TRIAD;
+
+        assert_eq!(MAJOR.apply_chord(&MAJOR_TRIAD, 7), None);
+        assert_eq!(PENTATONIC_MINOR.apply_chord(&MAJOR_TRIAD, 5), None);
+        assert!(PENTATONIC_MINOR.apply_chord(&MAJOR_TRIAD, 4).is_some());
+    }
+
+    #[test]
+    fn test_symmetric() {
+        assert!(JAZZ_WHOLE_TONE.symmetric());
+        assert!(JAZZ_WHOLEHALF_DIMINISHED.symmetric());
+        assert!(DORIAN.symmetric());
+        assert!(!MAJOR.symmetric());
+        assert!(!NATURAL_MINOR.symmetric());
+        assert!(!HARMONIC_MINOR.symmetric());
+        assert!(Scale::from_u16(0).symmetric());
+    }
+
+    #[test]
+    fn test_transpose_up() {
+        assert_eq!(MAJOR.transpose_up(0), MAJOR);
+        assert_eq!(MAJOR.transpose_up(12), MAJOR);
+        assert_eq!(MAJOR.transpose_up(5).transpose_up(7), MAJOR);
         assert_eq!(
-            HARMONIC_MINOR
+            MAJOR
+                .transpose_up(1)
                 .intervals()
                 .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![2, 3, 5, 7, 8, 11, 12]
+                .collect::<Vec<_>>(),
+            vec![1, 3, 5, 6, 8, 10, 12]
+        );
+    }
+
+    #[test]
+    fn test_has_tritone_symmetry() {
+        assert!(JAZZ_WHOLE_TONE.has_tritone_symmetry());
+        assert!(JAZZ_WHOLEHALF_DIMINISHED.has_tritone_symmetry());
+        assert!(!MAJOR.has_tritone_symmetry());
+        assert!(!PENTATONIC_MAJOR.has_tritone_symmetry());
+        assert!(!BLUES_MINOR.has_tritone_symmetry());
+    }
+
+    #[test]
+    fn test_name_tables_are_sorted() {
+        assert!(CHURCH_MODE_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(COMMON_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_church_mode_name() {
+        assert_eq!(MAJOR.church_mode_name(), Some("Ionian"));
+        assert_eq!(DORIAN.church_mode_name(), Some("Dorian"));
+        assert_eq!(PHRYGIAN.church_mode_name(), Some("Phrygian"));
+        assert_eq!(LYDIAN.church_mode_name(), Some("Lydian"));
+        assert_eq!(MIXOLYDIAN.church_mode_name(), Some("Mixolydian"));
+        assert_eq!(NATURAL_MINOR.church_mode_name(), Some("Aeolian"));
+        assert_eq!(LOCRIAN.church_mode_name(), Some("Locrian"));
+
+        for scale in [
+            HARMONIC_MINOR,
+            MELODIC_MINOR,
+            PENTATONIC_MAJOR,
+            PENTATONIC_MINOR,
+            BLUES_MAJOR,
+            BLUES_MINOR,
+            JAZZ_WHOLE_TONE,
+            BIBOP_DOMINANT,
+        ] {
+            assert_eq!(scale.church_mode_name(), None);
+        }
+    }
+
+    #[test]
+    fn test_common_name() {
+        let scales = [
+            MAJOR,
+            NATURAL_MINOR,
+            HARMONIC_MINOR,
+            MELODIC_MINOR,
+            PENTATONIC_MAJOR,
+            PENTATONIC_MINOR,
+            BLUES_MAJOR,
+            BLUES_MINOR,
+            JAZZ_WHOLE_TONE,
+            JAZZ_WHOLEHALF_DIMINISHED,
+            BIBOP_MAJOR,
+            BIBOP_MINOR,
+            BIBOP_DOMINANT,
+            IONIAN,
+            DORIAN,
+            PHRYGIAN,
+            LYDIAN,
+            MIXOLYDIAN,
+            AEOLIAN,
+            LOCRIAN,
+        ];
+        for scale in scales {
+            assert!(scale.common_name().is_some(), "{scale:?} has no name");
+        }
+
+        assert_eq!(MAJOR.common_name(), Some("Major"));
+        assert_eq!(AEOLIAN.common_name(), Some("Natural Minor"));
+        assert_eq!(BIBOP_DOMINANT.common_name(), Some("Bebop Dominant"));
+        assert_eq!(Scale::from_u16(0).common_name(), None);
+        assert_eq!(Scale::from_u16(u16::MAX).common_name(), None);
+    }
+
+    #[test]
+    fn test_diatonic_chords() {
+        use crate::{
+            DOMINANT_SEVENTH, HALF_DIMINISHED_SEVENTH, MAJOR_SEVENTH_CHORD, MINOR_SEVENTH_CHORD,
+        };
+
+        assert_eq!(MAJOR.diatonic_triads().len(), 7);
+        assert_eq!(
+            MAJOR.diatonic_seventh_chords(),
+            vec![
+                MAJOR_SEVENTH_CHORD,
+                MINOR_SEVENTH_CHORD,
+                MINOR_SEVENTH_CHORD,
+                MAJOR_SEVENTH_CHORD,
+                DOMINANT_SEVENTH,
+                MINOR_SEVENTH_CHORD,
+                HALF_DIMINISHED_SEVENTH,
+            ]
         );
+        assert!(Scale::from_u16(0).diatonic_triads().is_empty());
+    }
+
+    #[test]
+    fn test_closest_chord() {
+        use crate::{AUGMENTED_TRIAD, DOMINANT_SEVENTH, MAJOR_TRIAD, MINOR_TRIAD};
+
+        // The augmented fifth is one semitone away from the tonic's fifth
         assert_eq!(
-            HARMONIC_MINOR
-                .steps()
-                .map(|s| s.into())
-                .collect::<Vec<u8>>(),
-            vec![2, 1, 2, 2, 1, 3, 1]
+            MAJOR.closest_chord(&AUGMENTED_TRIAD),
+            Some((0, MAJOR_TRIAD))
         );
+        assert_eq!(AUGMENTED_TRIAD.voice_leading_distance(&MAJOR_TRIAD), 1);
 
+        // Ties are broken in favour of the lower degree: I over IV and V,
+        // ii over iii and vi
+        assert_eq!(MAJOR.closest_chord(&MAJOR_TRIAD), Some((0, MAJOR_TRIAD)));
+        assert_eq!(MAJOR.closest_chord(&MINOR_TRIAD), Some((1, MINOR_TRIAD)));
         assert_eq!(
-            HARMONIC_MINOR.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, EF, F, G, AF, B, C1]
+            MAJOR.closest_chord(&DOMINANT_SEVENTH),
+            Some((4, DOMINANT_SEVENTH))
+        );
+        // In A natural minor the first major triad is III
+        assert_eq!(
+            NATURAL_MINOR.closest_chord(&MAJOR_TRIAD),
+            Some((2, MAJOR_TRIAD))
         );
     }
 
-    /// Tests that the melodic minor scale produces the correct intervals and step pattern
-    ///
-    /// This test verifies that the melodic minor scale constant correctly represents
-    /// the ascending melodic minor scale with intervals [2, 3, 5, 7, 9, 11, 12] semitones
-    /// and step pattern [2, 1, 2, 2, 2, 2, 1], avoiding the augmented 2nd.
     #[test]
-    fn test_melodic_minor_scale() {
+    fn test_closest_chord_without_chords() {
+        use crate::{MAJOR_SEVENTH_CHORD, MAJOR_TRIAD};
+
+        assert_eq!(Scale::from_u16(0).closest_chord(&MAJOR_TRIAD), None);
+        assert_eq!(Scale::from_u16(0).closest_chord(&MAJOR_SEVENTH_CHORD), None);
+    }
+
+    #[test]
+    fn test_scale_default() {
+        assert_eq!(Scale::default(), MAJOR);
+        assert_eq!(Scale::from_steps_slice(&[]).unwrap_or_default(), MAJOR);
+    }
+
+    #[test]
+    fn test_pentatonic_subset() {
+        assert_eq!(MAJOR.pentatonic_subset(), Some(PENTATONIC_MAJOR));
+        assert_eq!(NATURAL_MINOR.pentatonic_subset(), Some(PENTATONIC_MINOR));
+        assert_eq!(LYDIAN.pentatonic_subset(), Some(PENTATONIC_MAJOR));
+        assert_eq!(MIXOLYDIAN.pentatonic_subset(), Some(PENTATONIC_MAJOR));
+        assert_eq!(DORIAN.pentatonic_subset(), Some(PENTATONIC_MINOR));
+        assert_eq!(PHRYGIAN.pentatonic_subset(), Some(PENTATONIC_MINOR));
+
+        // Locrian only contains a rotation of the pentatonic scale
+        let locrian = LOCRIAN.pentatonic_subset().unwrap();
+        assert_eq!(locrian.degree_count(), 5);
+        assert!(locrian.is_subset_of(LOCRIAN));
+        assert_eq!(locrian.prime_form(), PENTATONIC_MAJOR.prime_form());
+    }
+
+    #[test]
+    fn test_pentatonic_subset_none() {
+        // The harmonic minor has three half steps, too many to remove
+        assert_eq!(HARMONIC_MINOR.pentatonic_subset(), None);
+        assert_eq!(PENTATONIC_MAJOR.pentatonic_subset(), None);
+        assert_eq!(BIBOP_MAJOR.pentatonic_subset(), None);
+        assert_eq!(JAZZ_WHOLE_TONE.pentatonic_subset(), None);
+    }
+
+    #[test]
+    fn test_closely_related_keys_of_c_major() {
+        use crate::PitchClass::*;
+
+        let related = MAJOR.closely_related_keys(C);
         assert_eq!(
-            MELODIC_MINOR
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![2, 3, 5, 7, 9, 11, 12]
+            related,
+            vec![
+                (A, NATURAL_MINOR),
+                (D, NATURAL_MINOR),
+                (E, NATURAL_MINOR),
+                (F, MAJOR),
+                (G, MAJOR),
+            ]
+        );
+
+        // The parallel minor differs by three accidentals
+        assert!(!related.contains(&(C, NATURAL_MINOR)));
+        assert!(!related.contains(&(C, MAJOR)));
+    }
+
+    #[test]
+    fn test_closely_related_keys_of_a_minor() {
+        use crate::PitchClass::*;
+
+        let related = NATURAL_MINOR.closely_related_keys(A);
+        assert_eq!(related.len(), 5);
+        assert_eq!(related[0], (C, MAJOR));
+        for key in [
+            (D, NATURAL_MINOR),
+            (E, NATURAL_MINOR),
+            (F, MAJOR),
+            (G, MAJOR),
+        ] {
+            assert!(related.contains(&key));
+        }
+    }
+
+    #[test]
+    fn test_closely_related_keys_of_other_scales() {
+        use crate::PitchClass::*;
+
+        // D dorian has the pitch classes of C major
+        let related = DORIAN.closely_related_keys(D);
+        assert_eq!(related[0..2], [(C, MAJOR), (A, NATURAL_MINOR)]);
+        assert!(JAZZ_WHOLE_TONE.closely_related_keys(C).is_empty());
+    }
+
+    #[test]
+    fn test_tetrachords() {
+        let (lower, upper) = MAJOR.tetrachords().unwrap();
+        assert_eq!(lower, upper);
+        assert_eq!(lower.to_step_string(), "W-W-H");
+
+        let (lower, upper) = NATURAL_MINOR.tetrachords().unwrap();
+        assert_eq!(lower.to_step_string(), "W-H-W");
+        assert_eq!(upper.to_step_string(), "H-W-W");
+
+        let (lower, upper) = HARMONIC_MINOR.tetrachords().unwrap();
+        assert_eq!(lower.to_step_string(), "W-H-W");
+        assert_eq!(upper.to_step_string(), "H-WH-H");
+
+        // Dorian is made of two identical minor tetrachords
+        let (lower, upper) = DORIAN.tetrachords().unwrap();
+        assert_eq!(lower, upper);
+        assert_eq!(lower.to_step_string(), "W-H-W");
+    }
+
+    #[test]
+    fn test_tetrachords_none() {
+        assert_eq!(PENTATONIC_MINOR.tetrachords(), None);
+        assert_eq!(BIBOP_DOMINANT.tetrachords(), None);
+        assert_eq!(JAZZ_WHOLE_TONE.tetrachords(), None);
+    }
+
+    #[test]
+    fn test_count_semitone_clusters() {
+        assert_eq!(MAJOR.count_semitone_clusters(), 0);
+        assert_eq!(NATURAL_MINOR.count_semitone_clusters(), 0);
+        assert_eq!(BLUES_MINOR.count_semitone_clusters(), 1);
+        assert_eq!(BIBOP_DOMINANT.count_semitone_clusters(), 1);
+        assert_eq!(Scale::from_u16(0xFFFF).count_semitone_clusters(), 1);
+        assert_eq!(Scale::from_u16(0).count_semitone_clusters(), 0);
+        assert_eq!(
+            Scale::from_u16(0b0000_0111_0001_1100).count_semitone_clusters(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_max_cluster_size() {
+        assert_eq!(MAJOR.max_cluster_size(), 0);
+        assert_eq!(BLUES_MINOR.max_cluster_size(), 3);
+        assert_eq!(BIBOP_DOMINANT.max_cluster_size(), 4);
+        assert_eq!(Scale::from_u16(0xFFFF).max_cluster_size(), 16);
+        assert_eq!(Scale::from_u16(0).max_cluster_size(), 0);
+    }
+
+    #[test]
+    fn test_scale_ord() {
+        let mut scales = vec![BLUES_MINOR, MAJOR, PENTATONIC_MAJOR, NATURAL_MINOR];
+        scales.sort();
+        assert_eq!(
+            scales,
+            vec![PENTATONIC_MAJOR, BLUES_MINOR, NATURAL_MINOR, MAJOR]
+        );
+        assert!(NATURAL_MINOR.to_u16() < MAJOR.to_u16());
+        assert_eq!(MAJOR.cmp(&MAJOR), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_scale_ord_collections() {
+        use std::collections::{BTreeMap, BTreeSet};
+
+        let set = [MAJOR, DORIAN, MAJOR, PENTATONIC_MINOR]
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            vec![PENTATONIC_MINOR, DORIAN, MAJOR]
+        );
+
+        let names = [(MAJOR, "major"), (BLUES_MAJOR, "blues")]
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+        assert_eq!(names.keys().next(), Some(&BLUES_MAJOR));
+        assert_eq!(names[&MAJOR], "major");
+    }
+
+    #[test]
+    fn test_from_intervals_checked() {
+        assert_eq!(
+            Scale::from_intervals_checked(&[2, 4, 5, 7, 9, 11, 12]),
+            Ok(MAJOR)
+        );
+        assert_eq!(
+            Scale::from_intervals_checked(&[3, 5, 7, 10]),
+            Ok(PENTATONIC_MINOR)
+        );
+        assert_eq!(
+            Scale::from_intervals_checked(&[1]).map(|scale| scale.to_u16()),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn test_from_intervals_checked_errors() {
+        assert_eq!(
+            Scale::from_intervals_checked(&[]),
+            Err(ScaleError::EmptyIntervals)
+        );
+        assert_eq!(
+            Scale::from_intervals_checked(&[0, 2, 4]),
+            Err(ScaleError::IntervalOutOfRange(0))
         );
         assert_eq!(
-            MELODIC_MINOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
-            vec![2, 1, 2, 2, 2, 2, 1]
+            Scale::from_intervals_checked(&[2, 4, 13]),
+            Err(ScaleError::IntervalOutOfRange(13))
         );
-
         assert_eq!(
-            MELODIC_MINOR.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, EF, F, G, A, B, C1]
+            Scale::from_intervals_checked(&[2, 5, 4]),
+            Err(ScaleError::IntervalsNotAscending)
+        );
+        assert_eq!(
+            Scale::from_intervals_checked(&[2, 4, 4, 7]),
+            Err(ScaleError::DuplicateInterval(4))
         );
     }
 
     #[test]
-    fn test_pentatonic_major_scale() {
+    fn test_scale_error_display_for_intervals() {
         assert_eq!(
-            PENTATONIC_MAJOR
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![2, 4, 7, 9]
+            ScaleError::EmptyIntervals.to_string(),
+            "a scale needs at least one interval"
         );
         assert_eq!(
-            PENTATONIC_MAJOR
-                .steps()
-                .map(|s| s.into())
-                .collect::<Vec<u8>>(),
-            vec![2, 2, 3, 2]
+            ScaleError::IntervalOutOfRange(13).to_string(),
+            "the interval 13 is not between 1 and 12 semitones"
         );
-
         assert_eq!(
-            PENTATONIC_MAJOR.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, E, G, A]
+            ScaleError::IntervalsNotAscending.to_string(),
+            "the intervals are not in ascending order"
+        );
+        assert_eq!(
+            ScaleError::DuplicateInterval(4).to_string(),
+            "the interval 4 is given more than once"
         );
     }
 
     #[test]
-    fn test_pentatonic_minor_scale() {
+    fn test_scale_apply_with_octave() {
+        assert!(MAJOR.apply_with_octave(120).all(|note| note <= 127));
         assert_eq!(
-            PENTATONIC_MINOR
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![3, 5, 7, 10]
+            MAJOR.apply_with_octave(120).collect::<Vec<_>>(),
+            vec![120, 122, 124, 125, 127]
         );
         assert_eq!(
-            PENTATONIC_MINOR
-                .steps()
-                .map(|s| s.into())
-                .collect::<Vec<u8>>(),
-            vec![3, 2, 2, 3]
+            MAJOR.apply_with_octave(60).collect::<Vec<_>>(),
+            MAJOR.apply(60).collect::<Vec<_>>()
         );
+        assert_eq!(MAJOR.apply_with_octave(255).count(), 0);
+    }
 
-        assert_eq!(
-            PENTATONIC_MINOR.apply(C).collect::<Vec<u8>>(),
-            vec![C, EF, F, G, BF]
-        );
+    #[test]
+    fn test_scale_apply_multi_octave() {
+        let notes: Vec<u8> = MAJOR.apply_multi_octave(60, 2).collect();
+        assert_eq!(notes.len(), 16);
+        assert_eq!(&notes[..8], &[60, 62, 64, 65, 67, 69, 71, 72]);
+        assert_eq!(&notes[8..], &[72, 74, 76, 77, 79, 81, 83, 84]);
+        assert_eq!(MAJOR.apply_multi_octave(60, 0).count(), 0);
+        assert!(MAJOR.apply_multi_octave(100, 5).all(|note| note <= 127));
+        assert_eq!(MAJOR.apply_multi_octave(120, 3).count(), 5);
     }
 
     #[test]
-    fn test_blues_minor_scale() {
-        assert_eq!(
-            BLUES_MINOR
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![3, 5, 6, 7, 10, 12]
-        );
+    fn test_scale_from_bitvec_set_operations() {
+        let shared = Scale::from(MAJOR.0.intersection(NATURAL_MINOR.0));
+        let merged = Scale::from(MAJOR.0.union(NATURAL_MINOR.0));
         assert_eq!(
-            BLUES_MINOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
-            vec![3, 2, 1, 1, 3, 2]
+            merged.degree_count(),
+            MAJOR.degree_count() + NATURAL_MINOR.degree_count() - shared.degree_count()
         );
+        assert_eq!(merged.degree_count(), 10);
 
+        let only_major = Scale::from(MAJOR.0.difference(NATURAL_MINOR.0));
         assert_eq!(
-            BLUES_MINOR.apply(C).collect::<Vec<u8>>(),
-            vec![C, EF, F, GF, G, BF, C1]
+            only_major.as_interval_array(),
+            [4, 9, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0]
         );
     }
 
     #[test]
-    fn test_blues_major_scale() {
+    fn test_scale_harmonize() {
+        use crate::{MAJOR_TRIAD, MINOR_TRIAD};
+
+        // The third of C major is in I, iii and vi: the tonic wins
+        assert_eq!(MAJOR.harmonize(&[4]), vec![Some((0, MAJOR_TRIAD))]);
+
+        let triads = MAJOR.diatonic_triads();
+        let chords = MAJOR
+            .harmonize(&[0, 2, 4, 5, 7, 9, 11, 12])
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        let degrees = chords.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+        // C, E, G and the octave fall in I, F and A in IV, D and B in V
+        assert_eq!(degrees, vec![0, 4, 0, 3, 0, 3, 4, 0]);
+        for (index, chord) in chords {
+            assert_eq!(chord, triads[index as usize]);
+        }
+
+        // In A natural minor the tonic, subdominant and dominant are minor:
+        // C falls in i, D in iv, and B in v rather than in ii or VII
         assert_eq!(
-            BLUES_MAJOR
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![2, 3, 4, 7, 9]
+            NATURAL_MINOR.harmonize(&[3, 5, 2]),
+            vec![
+                Some((0, MINOR_TRIAD)),
+                Some((3, MINOR_TRIAD)),
+                Some((4, MINOR_TRIAD))
+            ]
         );
+    }
+
+    #[test]
+    fn test_scale_harmonize_outside_scale() {
+        use crate::MAJOR_TRIAD;
+
+        // C# is a semitone from C (I) and D (ii and V): the tonic wins
+        assert_eq!(MAJOR.harmonize(&[1]), vec![Some((0, MAJOR_TRIAD))]);
+        // F# is a semitone from F (IV) and G (I and V): the tonic wins
+        assert_eq!(MAJOR.harmonize(&[6]), vec![Some((0, MAJOR_TRIAD))]);
+        // A# is a semitone from A (IV and vi) and B (iii, V and vii°):
+        // the subdominant wins over the dominant
+        assert_eq!(MAJOR.harmonize(&[10]), vec![Some((3, MAJOR_TRIAD))]);
+
+        assert!(MAJOR.harmonize(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_scale_harmonize_without_triads() {
+        // Neither scale has a fifth that can be spelled on any of its degrees
+        assert!(CHROMATIC.diatonic_triads().is_empty());
+        assert_eq!(CHROMATIC.harmonize(&[0, 7, 13]), vec![None; 3]);
+        assert_eq!(Scale::from_u16(0).harmonize(&[0]), vec![None]);
+        assert!(CHROMATIC.harmonize(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_scale_to_chromatic_set() {
+        let set = MAJOR.to_chromatic_set(PitchClass::C);
         assert_eq!(
-            BLUES_MAJOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
-            vec![2, 1, 1, 3, 2]
+            set.indeces_on().collect::<Vec<_>>(),
+            vec![0, 2, 4, 5, 7, 9, 11]
         );
+
+        let a_minor = NATURAL_MINOR.to_chromatic_set(PitchClass::A);
+        assert_eq!(a_minor, set);
+
+        let f_sharp = PENTATONIC_MAJOR.to_chromatic_set(PitchClass::Fs);
         assert_eq!(
-            BLUES_MAJOR.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, EF, E, G, A]
+            f_sharp.indeces_on().collect::<Vec<_>>(),
+            vec![1, 3, 6, 8, 10]
         );
     }
 
     #[test]
-    fn test_jazz_whole_tone_scale() {
+    fn test_scale_transpose_from_root() {
         assert_eq!(
-            JAZZ_WHOLE_TONE
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![2, 4, 6, 8, 10]
+            Scale::transpose_from_root(0, MAJOR),
+            vec![0, 2, 4, 5, 7, 9, 11]
         );
         assert_eq!(
-            JAZZ_WHOLE_TONE
-                .steps()
-                .map(|s| s.into())
-                .collect::<Vec<u8>>(),
-            vec![2, 2, 2, 2, 2]
+            Scale::transpose_from_root(60, MAJOR),
+            vec![0, 2, 4, 5, 7, 9, 11]
         );
         assert_eq!(
-            JAZZ_WHOLE_TONE.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, E, FS, GS, AS]
+            Scale::transpose_from_root(9, PENTATONIC_MINOR),
+            vec![0, 2, 4, 7, 9]
         );
+        assert_eq!(Scale::transpose_from_root(1, JAZZ_WHOLE_TONE).len(), 6);
     }
 
     #[test]
-    fn test_jazz_wholehalf_diminished_scale() {
-        assert_eq!(
-            JAZZ_WHOLEHALF_DIMINISHED
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![2, 3, 5, 6, 8, 9, 11]
-        );
+    fn test_scale_from_name() {
+        assert_eq!(Scale::from_name("Harmonic Minor"), Some(HARMONIC_MINOR));
+        assert_eq!(Scale::from_name("MAJOR"), Some(MAJOR));
+        assert_eq!(Scale::from_name("minor"), Some(NATURAL_MINOR));
+        assert_eq!(Scale::from_name(" Dorian\t"), Some(DORIAN));
         assert_eq!(
-            JAZZ_WHOLEHALF_DIMINISHED
-                .steps()
-                .map(|s| s.into())
-                .collect::<Vec<u8>>(),
-            vec![2, 1, 2, 1, 2, 1, 2]
+            Scale::from_name("diminished"),
+            Some(JAZZ_WHOLEHALF_DIMINISHED)
         );
+        assert_eq!(Scale::from_name("unknown scale"), None);
+        assert_eq!(Scale::from_name(""), None);
+    }
+
+    #[test]
+    fn test_scale_from_name_covers_constants() {
+        assert!(SCALE_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+        for (_, name) in COMMON_NAMES.iter().chain(CHURCH_MODE_NAMES.iter()) {
+            let scale = Scale::from_name(name).unwrap();
+            assert!(scale.common_name() == Some(name) || scale.church_mode_name() == Some(name));
+        }
+        for (bits, _) in COMMON_NAMES {
+            assert!(SCALE_NAMES.iter().any(|(_, scale)| scale.to_u16() == bits));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_scale_random() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let scale = Scale::random(&mut rng);
+            assert_eq!(scale.degree_count(), 7);
+            assert!(scale.0.bit(11));
+            assert_eq!(scale.to_u16() & 0xF000, 0);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_scale_random_pentatonic_and_hexatonic() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let pentatonic = Scale::random_pentatonic(&mut rng);
+            assert_eq!(pentatonic.degree_count(), 5);
+            assert!(pentatonic.0.bit(11));
+
+            let hexatonic = Scale::random_hexatonic(&mut rng);
+            assert_eq!(hexatonic.degree_count(), 6);
+            assert!(hexatonic.0.bit(11));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_scale_random_is_seeded() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let first = Scale::random(&mut StdRng::seed_from_u64(1));
+        let second = Scale::random(&mut StdRng::seed_from_u64(1));
+        assert_eq!(first, second);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_scale_generate_melody() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for scale in [MAJOR, HARMONIC_MINOR, PENTATONIC_MINOR, BLUES_MINOR] {
+            let notes = scale.apply(60).collect::<Vec<_>>();
+            for length in [0, 1, 2, 50] {
+                let melody = scale.generate_melody(60, length, &mut rng);
+                assert_eq!(melody.len(), length);
+                assert!(melody.iter().all(|note| notes.contains(note)));
+                assert!(melody.iter().all(|note| (60..=72).contains(note)));
+                assert!(melody.windows(3).all(|w| w[0] != w[1] || w[1] != w[2]));
+                if length > 0 {
+                    assert_eq!(melody[0], 60);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_scale_generate_melody_edge_cases() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        // Notes above the MIDI range are left out
+        let melody = MAJOR.generate_melody(122, 20, &mut rng);
+        assert!(melody.iter().all(|note| *note <= 127));
+
+        // A scale made of the root alone can only repeat it
+        let melody = Scale::from_u16(0).generate_melody(60, 5, &mut rng);
+        assert_eq!(melody, vec![60; 5]);
+    }
+
+    #[test]
+    fn test_scale_count_steps() {
+        assert_eq!(MAJOR.count_whole_steps(), 5);
+        assert_eq!(MAJOR.count_half_steps(), 2);
+        assert_eq!(MAJOR.count_augmented_steps(), 0);
+
+        assert_eq!(HARMONIC_MINOR.count_whole_steps(), 3);
+        assert_eq!(HARMONIC_MINOR.count_half_steps(), 3);
+        assert_eq!(HARMONIC_MINOR.count_augmented_steps(), 1);
+
+        assert_eq!(JAZZ_WHOLE_TONE.count_whole_steps(), 5);
+        assert_eq!(JAZZ_WHOLE_TONE.count_half_steps(), 0);
+
+        // The minor third steps of the pentatonic scale are augmented seconds
+        assert_eq!(PENTATONIC_MINOR.count_augmented_steps(), 2);
+        assert_eq!(Scale::from_u16(0).count_whole_steps(), 0);
+
+        for mode in MAJOR.modes() {
+            assert_eq!(mode.count_whole_steps(), 5);
+            assert_eq!(mode.count_half_steps(), 2);
+        }
+    }
+
+    #[test]
+    fn test_scale_all_predefined() {
+        let catalog = Scale::all_predefined();
+        assert_eq!(catalog.len(), 21);
+        assert!(catalog.iter().all(|(name, _)| !name.is_empty()));
+        for (name, scale) in catalog {
+            assert_eq!(Scale::from_name(name), Some(*scale));
+        }
+        for (bits, _) in COMMON_NAMES {
+            assert!(catalog.iter().any(|(_, scale)| scale.to_u16() == bits));
+        }
+    }
+
+    #[test]
+    fn test_scale_pitch_classes() {
+        let pitch_classes = MAJOR.pitch_classes(PitchClass::C);
+        for index in [0, 2, 4, 5, 7, 9, 11] {
+            assert_eq!(pitch_classes[index], Some(PitchClass::from_u8(index as u8)));
+        }
+        for index in [1, 3, 6, 8, 10] {
+            assert_eq!(pitch_classes[index], None);
+        }
+
+        let a_minor = PENTATONIC_MINOR.pitch_classes(PitchClass::A);
         assert_eq!(
-            JAZZ_WHOLEHALF_DIMINISHED.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, EF, F, GF, AF, A, B]
+            a_minor.iter().flatten().copied().collect::<Vec<_>>(),
+            PENTATONIC_MINOR
+                .degree_names(PitchClass::A)
+                .map(|(_, pc)| pc)
+                .collect::<Vec<_>>()
         );
+
+        const EMPTY: [Option<PitchClass>; 12] = Scale::from_u16(0).pitch_classes(PitchClass::D);
+        assert_eq!(EMPTY.iter().flatten().count(), 1);
+        assert_eq!(EMPTY[0], Some(PitchClass::D));
     }
 
     #[test]
-    fn test_bibop_major_scale() {
+    fn test_scale_to_lilypond_key() {
+        assert_eq!(MAJOR.to_lilypond_key(PitchClass::C), "\\key c \\major");
+        assert_eq!(IONIAN.to_lilypond_key(PitchClass::G), "\\key g \\major");
         assert_eq!(
-            BIBOP_MAJOR
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![2, 4, 5, 7, 8, 9, 11, 12]
+            NATURAL_MINOR.to_lilypond_key(PitchClass::A),
+            "\\key a \\minor"
         );
+        assert_eq!(LYDIAN.to_lilypond_key(PitchClass::F), "\\key f \\lydian");
+        assert_eq!(LOCRIAN.to_lilypond_key(PitchClass::B), "\\key b \\locrian");
         assert_eq!(
-            BIBOP_MAJOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
-            vec![2, 2, 1, 2, 1, 1, 2, 1]
+            MELODIC_MINOR.to_lilypond_key(PitchClass::Cs),
+            "\\key cis \\minor"
         );
         assert_eq!(
-            BIBOP_MAJOR.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, E, F, G, AF, A, B, C1]
+            PENTATONIC_MAJOR.to_lilypond_key(PitchClass::D),
+            "\\key d \\major"
         );
     }
 
     #[test]
-    fn test_bibop_minor_scale() {
+    fn test_scale_get_note_function() {
+        assert_eq!(MAJOR.get_note_function(0), ScaleDegreeFunction::Root);
+        assert_eq!(MAJOR.get_note_function(4), ScaleDegreeFunction::Third);
+        assert_eq!(MAJOR.get_note_function(3), ScaleDegreeFunction::NonDiatonic);
+        assert_eq!(MAJOR.get_note_function(11), ScaleDegreeFunction::Seventh);
+        assert_eq!(MAJOR.get_note_function(12), ScaleDegreeFunction::Root);
         assert_eq!(
-            BIBOP_MINOR
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![2, 3, 4, 5, 7, 9, 10, 12]
+            NATURAL_MINOR.get_note_function(3),
+            ScaleDegreeFunction::Third
         );
         assert_eq!(
-            BIBOP_MINOR.steps().map(|s| s.into()).collect::<Vec<u8>>(),
-            vec![2, 1, 1, 1, 2, 2, 1, 2]
+            NATURAL_MINOR.get_note_function(8),
+            ScaleDegreeFunction::Sixth
         );
+        assert_eq!(LYDIAN.get_note_function(6), ScaleDegreeFunction::Fourth);
+        assert_eq!(LOCRIAN.get_note_function(6), ScaleDegreeFunction::Fifth);
         assert_eq!(
-            BIBOP_MINOR.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, EF, E, F, G, A, BF, C1]
+            LOCRIAN.get_note_function(7),
+            ScaleDegreeFunction::NonDiatonic
         );
     }
 
     #[test]
-    fn test_bibop_dominant_scale() {
+    fn test_scale_get_note_function_non_heptatonic() {
         assert_eq!(
-            BIBOP_DOMINANT
-                .intervals()
-                .map(|i| i.inner())
-                .collect::<Vec<u8>>(),
-            vec![2, 4, 5, 7, 9, 10, 11, 12]
+            PENTATONIC_MAJOR.get_note_function(7),
+            ScaleDegreeFunction::Fifth
         );
         assert_eq!(
-            BIBOP_DOMINANT
-                .steps()
-                .map(|s| s.into())
-                .collect::<Vec<u8>>(),
-            vec![2, 2, 1, 2, 2, 1, 1, 1]
+            PENTATONIC_MAJOR.get_note_function(9),
+            ScaleDegreeFunction::Sixth
         );
         assert_eq!(
-            BIBOP_DOMINANT.apply(C).collect::<Vec<u8>>(),
-            vec![C, D, E, F, G, A, BF, B, C1]
+            PENTATONIC_MINOR.get_note_function(10),
+            ScaleDegreeFunction::Seventh
+        );
+        assert_eq!(
+            PENTATONIC_MINOR.get_note_function(4),
+            ScaleDegreeFunction::NonDiatonic
         );
     }
 
-    /// Tests that the ScaleBuilder correctly constructs Scale instances
-    ///
-    /// This test verifies that the builder pattern works correctly by setting
-    /// multiple semitone intervals and ensuring the final result matches the
-    /// expected major scale intervals.
     #[test]
-    fn test_scale_builder() {
-        let scale = ScaleBuilder::default()
-            .set_interval(MAJOR_SECOND)
-            .set_interval(MAJOR_THIRD)
-            .set_interval(PERFECT_FOURTH)
-            .set_interval(PERFECT_FIFTH)
-            .set_interval(MAJOR_SIXTH)
-            .set_interval(MAJOR_SEVENTH)
-            .set_interval(OCTAVE)
-            .build();
+    fn test_scale_to_ascii_art() {
+        let marked = |art: &str, line: usize| art.lines().nth(line).unwrap().matches('*').count();
+
+        let major = MAJOR.to_ascii_art();
+        assert_eq!(major.lines().count(), 4);
+        assert_eq!(marked(&major, 1), 0);
+        assert_eq!(marked(&major, 2), 7);
+        assert_eq!(major.lines().nth(2), Some("|*||*||*||*||*||*||*|"));
+
+        let pentatonic = PENTATONIC_MINOR.to_ascii_art();
+        assert_eq!(marked(&pentatonic, 1), 2);
+        assert_eq!(marked(&pentatonic, 2), 3);
+
+        let chromatic = CHROMATIC.to_ascii_art();
+        assert_eq!(marked(&chromatic, 1), 5);
+        assert_eq!(marked(&chromatic, 2), 7);
+        assert_eq!(chromatic.lines().nth(1), Some("  [*][*]   [*][*][*]"));
+    }
+
+    #[test]
+    fn test_scale_chromatic() {
+        assert_eq!(CHROMATIC.degree_count(), 12);
+        assert_eq!(CHROMATIC.count_half_steps(), 12);
+        assert_eq!(CHROMATIC.common_name(), Some("Chromatic"));
+        assert_eq!(Scale::from_name("chromatic"), Some(CHROMATIC));
+        assert!(CHROMATIC.is_superset_of(MAJOR));
+    }
+
+    #[test]
+    fn test_scale_transpositions() {
+        let transpositions = MAJOR.transpositions();
+        assert_eq!(transpositions[0], MAJOR);
+        assert_eq!(transpositions.iter().filter(|s| **s == MAJOR).count(), 1);
+        for (semitones, scale) in transpositions.iter().enumerate() {
+            assert_eq!(scale.to_u16().count_ones(), 7);
+            assert_eq!(*scale, MAJOR.transpose_up(semitones as u8));
+        }
+
+        // The whole tone scale only has two distinct transpositions
+        let whole_tone = JAZZ_WHOLE_TONE.transpositions();
+        assert_eq!(whole_tone[0], whole_tone[2]);
+        assert_ne!(whole_tone[0], whole_tone[1]);
+        assert_eq!(CHROMATIC.transpositions(), [CHROMATIC; 12]);
+    }
+
+    #[test]
+    fn test_scale_is_transpositionally_equivalent_to() {
+        // Modes share the pitch-class set of their parent scale
+        assert!(MAJOR.is_transpositionally_equivalent_to(DORIAN));
+        assert!(DORIAN.is_transpositionally_equivalent_to(MAJOR));
+        for mode in MAJOR.modes() {
+            assert!(MAJOR.is_transpositionally_equivalent_to(mode));
+        }
+        assert!(PENTATONIC_MAJOR.is_transpositionally_equivalent_to(PENTATONIC_MINOR));
+        assert!(MAJOR.is_transpositionally_equivalent_to(MAJOR));
+
+        assert!(!MAJOR.is_transpositionally_equivalent_to(HARMONIC_MINOR));
+        assert!(!MAJOR.is_transpositionally_equivalent_to(MELODIC_MINOR));
+        assert!(!PENTATONIC_MAJOR.is_transpositionally_equivalent_to(MAJOR));
+    }
+
+    #[test]
+    fn test_scale_euclidean() {
+        assert_eq!(Scale::euclidean(7, 12), NATURAL_MINOR);
+        assert!(Scale::euclidean(7, 12).is_transpositionally_equivalent_to(MAJOR));
+        assert!(Scale::euclidean(5, 12).is_transpositionally_equivalent_to(PENTATONIC_MAJOR));
+        assert!(Scale::euclidean(6, 12).is_transpositionally_equivalent_to(JAZZ_WHOLE_TONE));
+        assert_eq!(Scale::euclidean(12, 12), CHROMATIC);
+        for k in 1..=12 {
+            assert_eq!(Scale::euclidean(k, 12).degree_count(), k as usize);
+        }
+    }
+
+    #[test]
+    fn test_scale_euclidean_bounds() {
+        assert_eq!(Scale::euclidean(0, 12), Scale::from_u16(0));
+        assert_eq!(Scale::euclidean(3, 0), Scale::from_u16(0));
         assert_eq!(
-            scale.intervals().map(|i| i.inner()).collect::<Vec<u8>>(),
-            vec![2, 4, 5, 7, 9, 11, 12]
+            Scale::euclidean(1, 12),
+            Scale::from_u16(0b0000_1000_0000_0000)
         );
-        assert_eq!(scale, MAJOR);
+        // k is clamped to n, and n to 16
+        assert_eq!(Scale::euclidean(20, 12), CHROMATIC);
+        assert_eq!(Scale::euclidean(16, 20), Scale::from_u16(u16::MAX));
+        // The tresillo: three onsets over eight positions
+        let tresillo = Scale::euclidean(3, 8);
+        let intervals: Vec<u8> = tresillo.intervals().map(|i| i.inner()).collect();
+        assert_eq!(intervals, vec![3, 6, 8]);
     }
 
     #[test]
-    fn test_scale_step_builder() {
-        let scale = ScaleStepBuilder::default()
-            .add_step(WHOLE)
-            .add_step(WHOLE)
-            .add_step(HALF)
-            .add_step(WHOLE)
-            .add_step(WHOLE)
-            .add_step(WHOLE)
-            .add_step(HALF)
+    fn test_scale_contains_chord() {
+        use crate::{
+            AUGMENTED_TRIAD, DIMINISHED_TRIAD, DOMINANT_SEVENTH, MAJOR_TRIAD, MINOR_TRIAD,
+        };
+
+        assert!(MAJOR.contains_chord(MAJOR_TRIAD));
+        assert!(MAJOR.contains_chord(MINOR_TRIAD));
+        assert!(MAJOR.contains_chord(DIMINISHED_TRIAD));
+        assert!(MAJOR.contains_chord(DOMINANT_SEVENTH));
+        assert!(!MAJOR.contains_chord(AUGMENTED_TRIAD));
+        assert!(HARMONIC_MINOR.contains_chord(AUGMENTED_TRIAD));
+
+        // Every chord that fits on the tonic is contained in the scale
+        for (_, chord) in Chord::all_predefined() {
+            if chord.fits_scale(MAJOR) {
+                assert!(MAJOR.contains_chord(*chord));
+            }
+        }
+
+        // Three consecutive semitones never fit in the major scale
+        let cluster = ChordBuilder::with_root("")
+            .set_degree(Degree::new(2, DegreeAccidental::Flat))
+            .set_degree(Degree::new(3, DegreeAccidental::DoubleFlat))
             .build();
+        assert!(!MAJOR.contains_chord(cluster));
+        let altered = DOMINANT_SEVENTH.add_tension(Degree::new(9, DegreeAccidental::Flat));
+        assert!(!MAJOR.contains_chord(altered));
+        assert!(HARMONIC_MINOR.contains_chord(altered));
+    }
 
-        assert_eq!(
-            scale.intervals().map(|i| i.inner()).collect::<Vec<u8>>(),
-            vec![2, 4, 5, 7, 9, 11, 12]
-        );
-        assert_eq!(scale, MAJOR);
+    #[test]
+    fn test_note_count_predicates() {
+        assert_eq!(MAJOR.note_count(), 7);
+        assert!(MAJOR.is_heptatonic());
+        assert!(!MAJOR.is_pentatonic());
+        assert!(PENTATONIC_MAJOR.is_pentatonic());
+        assert!(PENTATONIC_MINOR.is_pentatonic());
+        assert!(BLUES_MINOR.is_hexatonic());
+        assert!(!BLUES_MINOR.is_heptatonic());
+        for scale in [BIBOP_MAJOR, BIBOP_MINOR, BIBOP_DOMINANT] {
+            assert_eq!(scale.note_count(), 8);
+            assert!(scale.is_octatonic());
+        }
+
+        // The empty scale only holds its root
+        let empty = Scale::from_u16(0);
+        assert_eq!(empty.note_count(), 1);
+        assert!(!empty.is_pentatonic());
+        assert!(!empty.is_hexatonic());
+        assert!(!empty.is_heptatonic());
+        assert!(!empty.is_octatonic());
     }
 }
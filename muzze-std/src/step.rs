@@ -98,6 +98,19 @@ impl Step {
     }
 }
 
+impl Default for Step {
+    /// Returns a whole step (2 semitones)
+    ///
+    /// # Example
+    /// ```rust
+    /// use muzze_std::{Step, WHOLE};
+    /// assert_eq!(Step::default(), WHOLE);
+    /// ```
+    fn default() -> Self {
+        WHOLE
+    }
+}
+
 impl From<Step> for u8 {
     /// Converts a `Step` to its corresponding `u8` value
     ///
@@ -351,4 +364,10 @@ mod tests {
             assert_eq!(u8::from(*step), *expected);
         }
     }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Step::default(), WHOLE);
+        assert_eq!(Step::default().inner(), 2);
+    }
 }
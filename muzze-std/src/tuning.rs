@@ -0,0 +1,175 @@
+//! Tuning Systems
+//!
+//! This module provides the `Tuning` enum and the `frequency` function for
+//! converting MIDI note numbers to frequencies under different tuning systems.
+
+/// The frequency of A4 (MIDI note 69) used as the reference pitch
+const A4_FREQUENCY: f64 = 440.0;
+
+/// The MIDI note number of A4
+const A4_NOTE: u8 = 69;
+
+/// The MIDI note number of middle C (C4)
+const MIDDLE_C_NOTE: u8 = 60;
+
+/// Just intonation ratios (5-limit, with the 7/5 septimal tritone) of the
+/// twelve pitch classes above the tonic
+const JUST_RATIOS: [f64; 12] = [
+    1.0,
+    16.0 / 15.0,
+    9.0 / 8.0,
+    6.0 / 5.0,
+    5.0 / 4.0,
+    4.0 / 3.0,
+    7.0 / 5.0,
+    3.0 / 2.0,
+    8.0 / 5.0,
+    5.0 / 3.0,
+    9.0 / 5.0,
+    15.0 / 8.0,
+];
+
+/// Position of each pitch class on the chain of fifths from C, spanning E♭ to G♯
+const MEANTONE_FIFTHS: [i32; 12] = [0, 7, 2, -3, 4, -1, 6, 1, 8, 3, -2, 5];
+
+/// Represents a system for tuning the twelve notes of the octave
+///
+/// # Examples
+///
+/// ```rust
+/// use muzze_std::{frequency, Tuning};
+///
+/// assert_eq!(frequency(69, Tuning::EqualTemperament), 440.0);
+/// let tuning = Tuning::JustIntonation { fundamental: 264.0 };
+/// assert_eq!(frequency(64, tuning), 330.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tuning {
+    /// Twelve-tone equal temperament with A4 tuned to 440 Hz
+    ///
+    /// Every semitone has the same ratio, the twelfth root of two.
+    EqualTemperament,
+
+    /// Just intonation (5-limit, with the 7/5 septimal tritone) built on C
+    ///
+    /// Each pitch class is tuned to a small whole-number ratio above C
+    /// (5/4 for the major third, 3/2 for the perfect fifth, and so on).
+    JustIntonation {
+        /// The frequency of middle C (MIDI note 60) in Hz
+        fundamental: f64,
+    },
+
+    /// Quarter-comma meantone built on C, with A4 tuned to 440 Hz
+    ///
+    /// The fifths are narrowed by a quarter of a syntonic comma so that the
+    /// major thirds are pure. The twelve notes span the chain of fifths from
+    /// E♭ to G♯.
+    Meantone,
+}
+
+/// Returns the frequency of a MIDI note under a tuning system
+///
+/// # Arguments
+/// * `note` - The MIDI note number
+/// * `tuning` - The tuning system to use
+///
+/// # Returns
+/// The frequency of the note in Hz
+///
+/// # Example
+/// ```rust
+/// use muzze_std::{frequency, Tuning};
+///
+/// assert_eq!(frequency(69, Tuning::EqualTemperament), 440.0);
+/// assert_eq!(frequency(57, Tuning::EqualTemperament), 220.0);
+/// assert!((frequency(76, Tuning::EqualTemperament) - 659.26).abs() < 0.01);
+/// ```
+pub fn frequency(note: u8, tuning: Tuning) -> f64 {
+    let pitch_class = (note % 12) as usize;
+    let octaves = (note / 12) as i32 - (MIDDLE_C_NOTE / 12) as i32;
+
+    match tuning {
+        Tuning::EqualTemperament => A4_FREQUENCY * 2f64.powf((note as f64 - A4_NOTE as f64) / 12.0),
+        Tuning::JustIntonation { fundamental } => {
+            fundamental * JUST_RATIOS[pitch_class] * 2f64.powi(octaves)
+        }
+        Tuning::Meantone => {
+            let a4_ratio = meantone_ratio((A4_NOTE % 12) as usize);
+            A4_FREQUENCY * meantone_ratio(pitch_class) / a4_ratio * 2f64.powi(octaves)
+        }
+    }
+}
+
+/// Returns the quarter-comma meantone ratio of a pitch class above C
+fn meantone_ratio(pitch_class: usize) -> f64 {
+    // A quarter-comma meantone fifth is the fourth root of 5
+    let mut ratio = 5f64.powf(MEANTONE_FIFTHS[pitch_class] as f64 / 4.0);
+    while ratio >= 2.0 {
+        ratio /= 2.0;
+    }
+    while ratio < 1.0 {
+        ratio *= 2.0;
+    }
+    ratio
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_equal_temperament() {
+        assert_eq!(frequency(69, Tuning::EqualTemperament), 440.0);
+        assert_eq!(frequency(81, Tuning::EqualTemperament), 880.0);
+        assert!((frequency(69 + 7, Tuning::EqualTemperament) - 659.26).abs() < 0.01);
+        assert!((frequency(60, Tuning::EqualTemperament) - 261.63).abs() < 0.01);
+
+        let semitone = frequency(70, Tuning::EqualTemperament) / 440.0;
+        assert_close(semitone, 2f64.powf(1.0 / 12.0));
+    }
+
+    #[test]
+    fn test_just_intonation_c_major_chord() {
+        let tuning = Tuning::JustIntonation { fundamental: 261.0 };
+        let c = frequency(60, tuning);
+        let e = frequency(64, tuning);
+        let g = frequency(67, tuning);
+
+        assert_close(c, 261.0);
+        assert_close(e / c, 5.0 / 4.0);
+        assert_close(g / c, 3.0 / 2.0);
+        assert_close(g / e, 6.0 / 5.0);
+        assert_close(frequency(72, tuning), 522.0);
+        assert_close(frequency(48, tuning), 130.5);
+    }
+
+    #[test]
+    fn test_meantone() {
+        assert_close(frequency(69, Tuning::Meantone), 440.0);
+        assert_close(frequency(81, Tuning::Meantone), 880.0);
+
+        // Major thirds are pure, fifths are a quarter comma narrow
+        let c = frequency(60, Tuning::Meantone);
+        assert_close(frequency(64, Tuning::Meantone) / c, 5.0 / 4.0);
+        assert_close(frequency(67, Tuning::Meantone) / c, 5f64.powf(0.25));
+        assert!(frequency(67, Tuning::Meantone) / c < 1.5);
+    }
+
+    #[test]
+    fn test_meantone_ratios_stay_in_the_octave() {
+        for pitch_class in 0..12 {
+            let ratio = meantone_ratio(pitch_class);
+            assert!((1.0..2.0).contains(&ratio));
+        }
+        for note in 1..128 {
+            assert!(frequency(note, Tuning::Meantone) > frequency(note - 1, Tuning::Meantone));
+        }
+    }
+}